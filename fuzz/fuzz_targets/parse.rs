@@ -0,0 +1,10 @@
+#![no_main]
+
+use frame_rate::FrameRate;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+  if let Err(error) = input.parse::<FrameRate>() {
+    let _ = error.fragment(input);
+  }
+});
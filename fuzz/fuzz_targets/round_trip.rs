@@ -0,0 +1,13 @@
+#![no_main]
+
+use frame_rate::{FrameRate, Ratio};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|frame_rate: FrameRate| {
+  assert_eq!(FrameRate::from(Ratio::from(frame_rate)), frame_rate);
+
+  let value = serde_json::to_value(frame_rate).unwrap();
+  assert_eq!(serde_json::from_value::<FrameRate>(value).unwrap(), frame_rate);
+
+  assert_eq!(frame_rate.to_string().parse(), Ok(frame_rate));
+});
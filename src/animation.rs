@@ -0,0 +1,62 @@
+use crate::{FrameRate, Rounding};
+use std::ops::Range;
+
+impl FrameRate {
+  /// Frames starting within `t0..t1` seconds of animation time.
+  ///
+  /// A frame starting exactly at `t0` is included, one starting exactly at
+  /// `t1` is not, so consecutive intervals never share a frame. Negative or
+  /// invalid times count as 0.
+  pub fn frames_between(&self, t0: f64, t1: f64) -> Range<u64> {
+    let fps = f64::from(self);
+    let first_frame_from = |time: f64| {
+      Rounding::Ceil
+        .round_frames(time.max(0.0) * fps)
+        .unwrap_or(0)
+    };
+    let start = first_frame_from(t0);
+    start..first_frame_from(t1).max(start)
+  }
+
+  /// Normalized animation time, from 0 to 1, at the start of `frame` out of
+  /// `total_frames`. Inverse of [`FrameRate::frames_between`] over an
+  /// animation lasting `total_frames` frames.
+  pub fn progress_at_frame(frame: u64, total_frames: u64) -> f64 {
+    if total_frames == 0 {
+      return 0.0;
+    }
+    (frame as f64 / total_frames as f64).min(1.0)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn frames_between() {
+    assert_eq!(FrameRate::_25_00.frames_between(0.0, 1.0), 0..25);
+    assert_eq!(FrameRate::_25_00.frames_between(0.08, 0.2), 2..5);
+    assert_eq!(FrameRate::_25_00.frames_between(0.01, 0.03), 1..1);
+    assert_eq!(FrameRate::_29_97.frames_between(1.0, 2.0), 30..60);
+    assert_eq!(FrameRate::_25_00.frames_between(-1.0, f64::NAN), 0..0);
+    assert_eq!(FrameRate::_25_00.frames_between(1.0, 0.5), 25..25);
+  }
+
+  #[test]
+  fn progress_at_frame() {
+    assert_eq!(FrameRate::progress_at_frame(0, 50), 0.0);
+    assert_eq!(FrameRate::progress_at_frame(25, 50), 0.5);
+    assert_eq!(FrameRate::progress_at_frame(50, 50), 1.0);
+    assert_eq!(FrameRate::progress_at_frame(60, 50), 1.0);
+    assert_eq!(FrameRate::progress_at_frame(3, 0), 0.0);
+
+    let frame_rate = FrameRate::_24_00;
+    let total_frames = 48;
+    let duration = 2.0;
+    for frame in 0..total_frames {
+      let time = FrameRate::progress_at_frame(frame, total_frames) * duration;
+      assert_eq!(frame_rate.frames_between(time, duration).start, frame);
+    }
+  }
+}
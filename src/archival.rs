@@ -0,0 +1,58 @@
+use crate::{FrameRate, Ratio};
+
+impl FrameRate {
+  /// 16 fps, common silent film camera speed.
+  pub const FILM_16: Self = Self::FrCustom(Ratio::new_raw(16, 1));
+  /// 18 fps, silent film and Super 8 camera speed.
+  pub const FILM_18: Self = Self::FrCustom(Ratio::new_raw(18, 1));
+  /// 20 fps, late silent film camera speed.
+  pub const FILM_20: Self = Self::FrCustom(Ratio::new_raw(20, 1));
+  /// 22 fps, late silent film projection speed.
+  pub const FILM_22: Self = Self::FrCustom(Ratio::new_raw(22, 1));
+
+  /// Archival film scanning rates, in ascending order.
+  pub const ARCHIVAL_RATES: [Self; 4] =
+    [Self::FILM_16, Self::FILM_18, Self::FILM_20, Self::FILM_22];
+
+  /// Speed factor of footage shot at this rate and played back at `playback`,
+  /// e.g. 3/2 for 16 fps film played at 24 fps. `None` at 0 fps.
+  pub fn playback_speed(&self, playback: FrameRate) -> Option<Ratio<u64>> {
+    let shot = Ratio::<u32>::from(*self);
+    let playback = Ratio::<u32>::from(playback);
+    (*shot.numer() != 0).then(|| {
+      Ratio::new(
+        u64::from(*playback.numer()) * u64::from(*shot.denom()),
+        u64::from(*playback.denom()) * u64::from(*shot.numer()),
+      )
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn constants() {
+    assert_eq!(FrameRate::FILM_16, FrameRate::new(16, 1));
+    assert_eq!(FrameRate::FILM_22, FrameRate::new(44, 2));
+    assert_eq!(FrameRate::FILM_18.to_string(), "18/1");
+  }
+
+  #[test]
+  fn playback_speed() {
+    assert_eq!(
+      FrameRate::FILM_16.playback_speed(FrameRate::_24_00),
+      Some(Ratio::new(3, 2))
+    );
+    assert_eq!(
+      FrameRate::FILM_18.playback_speed(FrameRate::_25_00),
+      Some(Ratio::new(25, 18))
+    );
+    assert_eq!(
+      FrameRate::FILM_20.playback_speed(FrameRate::_23_97),
+      Some(Ratio::new(1200, 1001))
+    );
+    assert_eq!(FrameRate::new(0, 1).playback_speed(FrameRate::_24_00), None);
+  }
+}
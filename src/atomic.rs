@@ -0,0 +1,102 @@
+use crate::{FrameRate, Ratio};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// A frame rate cell that can be shared between threads without locking.
+///
+/// The numerator and denominator are packed into a single `AtomicU64`.
+#[derive(Debug)]
+pub struct AtomicFrameRate(AtomicU64);
+
+impl AtomicFrameRate {
+  pub fn new(frame_rate: FrameRate) -> Self {
+    Self(AtomicU64::new(pack(frame_rate)))
+  }
+
+  pub fn load(&self, order: Ordering) -> FrameRate {
+    unpack(self.0.load(order))
+  }
+
+  pub fn store(&self, frame_rate: FrameRate, order: Ordering) {
+    self.0.store(pack(frame_rate), order)
+  }
+
+  pub fn swap(&self, frame_rate: FrameRate, order: Ordering) -> FrameRate {
+    unpack(self.0.swap(pack(frame_rate), order))
+  }
+
+  pub fn compare_exchange(
+    &self,
+    current: FrameRate,
+    new: FrameRate,
+    success: Ordering,
+    failure: Ordering,
+  ) -> Result<FrameRate, FrameRate> {
+    self
+      .0
+      .compare_exchange(pack(current), pack(new), success, failure)
+      .map(unpack)
+      .map_err(unpack)
+  }
+
+  pub fn into_inner(self) -> FrameRate {
+    unpack(self.0.into_inner())
+  }
+}
+
+impl From<FrameRate> for AtomicFrameRate {
+  fn from(frame_rate: FrameRate) -> Self {
+    Self::new(frame_rate)
+  }
+}
+
+fn pack(frame_rate: FrameRate) -> u64 {
+  let ratio = Ratio::<u32>::from(frame_rate);
+  (u64::from(*ratio.numer()) << 32) | u64::from(*ratio.denom())
+}
+
+fn unpack(value: u64) -> FrameRate {
+  // Packed values always come from a reduced ratio, so no need to reduce again.
+  FrameRate::from(Ratio::new_raw((value >> 32) as u32, value as u32))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn load_store() {
+    let atomic = AtomicFrameRate::new(FrameRate::_25_00);
+    assert_eq!(atomic.load(Ordering::Relaxed), FrameRate::_25_00);
+
+    atomic.store(FrameRate::_29_97, Ordering::Relaxed);
+    assert_eq!(atomic.load(Ordering::Relaxed), FrameRate::_29_97);
+
+    let custom = FrameRate::new(12, 5);
+    assert_eq!(atomic.swap(custom, Ordering::Relaxed), FrameRate::_29_97);
+    assert_eq!(atomic.into_inner(), custom);
+  }
+
+  #[test]
+  fn compare_exchange() {
+    let atomic = AtomicFrameRate::from(FrameRate::_50_00);
+    assert_eq!(
+      atomic.compare_exchange(
+        FrameRate::_50_00,
+        FrameRate::_59_94,
+        Ordering::SeqCst,
+        Ordering::SeqCst
+      ),
+      Ok(FrameRate::_50_00)
+    );
+    assert_eq!(
+      atomic.compare_exchange(
+        FrameRate::_50_00,
+        FrameRate::_24_00,
+        Ordering::SeqCst,
+        Ordering::SeqCst
+      ),
+      Err(FrameRate::_59_94)
+    );
+    assert_eq!(atomic.load(Ordering::SeqCst), FrameRate::_59_94);
+  }
+}
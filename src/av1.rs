@@ -0,0 +1,90 @@
+use crate::{Error, FrameRate, Ratio};
+
+/// `timing_info` fields of an AV1 sequence header.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Av1TimingInfo {
+  pub num_units_in_display_tick: u32,
+  pub time_scale: u32,
+  pub equal_picture_interval: bool,
+  /// Only meaningful when `equal_picture_interval` is set.
+  pub num_ticks_per_picture_minus_1: u32,
+}
+
+impl Av1TimingInfo {
+  /// Frame rate signalled by these fields. Fails when pictures are not at
+  /// equal intervals, as the stream then has no constant rate.
+  #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret))]
+  pub fn frame_rate(&self) -> Result<FrameRate, Error> {
+    if !self.equal_picture_interval {
+      return Err(Error::InvalidAv1TimingInfo(
+        "pictures are not at equal intervals",
+      ));
+    }
+    if self.num_units_in_display_tick == 0 || self.time_scale == 0 {
+      return Err(Error::InvalidAv1TimingInfo(
+        "num_units_in_display_tick and time_scale must not be zero",
+      ));
+    }
+    FrameRate::new_u64(
+      u64::from(self.time_scale),
+      u64::from(self.num_units_in_display_tick)
+        * (u64::from(self.num_ticks_per_picture_minus_1) + 1),
+    )
+  }
+
+  /// Fields signalling `frame_rate` with one tick per picture.
+  pub fn from_frame_rate(frame_rate: FrameRate) -> Self {
+    let ratio = Ratio::<u32>::from(frame_rate);
+    Self {
+      num_units_in_display_tick: *ratio.denom(),
+      time_scale: *ratio.numer(),
+      equal_picture_interval: true,
+      num_ticks_per_picture_minus_1: 0,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn frame_rate() {
+    let timing_info = Av1TimingInfo {
+      num_units_in_display_tick: 1001,
+      time_scale: 60000,
+      equal_picture_interval: true,
+      num_ticks_per_picture_minus_1: 1,
+    };
+    assert_eq!(timing_info.frame_rate(), Ok(FrameRate::_29_97));
+
+    assert_eq!(
+      Av1TimingInfo {
+        equal_picture_interval: false,
+        ..timing_info
+      }
+      .frame_rate(),
+      Err(Error::InvalidAv1TimingInfo(
+        "pictures are not at equal intervals"
+      ))
+    );
+    assert!(
+      Av1TimingInfo {
+        time_scale: 0,
+        ..timing_info
+      }
+      .frame_rate()
+      .is_err()
+    );
+  }
+
+  #[test]
+  fn round_trip() {
+    for frame_rate in FrameRate::standard_rates() {
+      assert_eq!(
+        Av1TimingInfo::from_frame_rate(frame_rate).frame_rate(),
+        Ok(frame_rate)
+      );
+    }
+  }
+}
@@ -0,0 +1,118 @@
+use crate::{Error, FrameRate, Rounding, Timecode};
+use std::time::Duration;
+
+impl FrameRate {
+  /// Start time of each of `frames`, rounded to the nearest nanosecond.
+  /// Runs in parallel with the `rayon` feature.
+  pub fn times_of_frames(&self, frames: &[u64]) -> Vec<Duration> {
+    map(frames, |frame| self.duration_of(*frame))
+  }
+
+  /// Frame boundary nearest to each of `times`, e.g. to snap subtitle cues.
+  /// Runs in parallel with the `rayon` feature.
+  pub fn frames_at_times(&self, times: &[Duration]) -> Vec<u64> {
+    map(times, |time| {
+      self.duration_to_frames(*time, Rounding::Nearest)
+    })
+  }
+
+  /// Frame at `frame_rate` nearest to the start of each of `frames` at this
+  /// rate. Runs in parallel with the `rayon` feature.
+  pub fn convert_frames(&self, frames: &[u64], frame_rate: FrameRate) -> Vec<u64> {
+    map(frames, |frame| {
+      self.convert_frame(*frame, frame_rate, Rounding::Nearest)
+    })
+  }
+
+  /// Timecode of each of `frames`. Runs in parallel with the `rayon`
+  /// feature.
+  pub fn timecodes_of_frames(
+    &self,
+    frames: &[u64],
+    drop_frame: bool,
+  ) -> Result<Vec<Timecode>, Error> {
+    map(frames, |frame| {
+      Timecode::with_drop_frame(*self, *frame, drop_frame)
+    })
+    .into_iter()
+    .collect()
+  }
+
+  /// Frame labelled by each of `timecodes`, see [`Timecode::parse`]. Runs in
+  /// parallel with the `rayon` feature.
+  pub fn frames_of_timecodes<S: AsRef<str> + Sync>(
+    &self,
+    timecodes: &[S],
+  ) -> Result<Vec<u64>, Error> {
+    map(timecodes, |timecode| {
+      Timecode::parse(*self, timecode.as_ref()).map(|timecode| timecode.to_frames())
+    })
+    .into_iter()
+    .collect()
+  }
+}
+
+#[cfg(feature = "rayon")]
+fn map<T: Sync, U: Send>(items: &[T], f: impl Fn(&T) -> U + Send + Sync) -> Vec<U> {
+  use rayon::prelude::*;
+
+  items.par_iter().map(f).collect()
+}
+
+#[cfg(not(feature = "rayon"))]
+fn map<T, U>(items: &[T], f: impl Fn(&T) -> U) -> Vec<U> {
+  items.iter().map(f).collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn round_trip() {
+    let frames: Vec<u64> = (0..100_000).step_by(7).collect();
+    let times = FrameRate::_29_97.times_of_frames(&frames);
+    assert_eq!(times[1], Duration::from_nanos(233_566_667));
+    assert_eq!(FrameRate::_29_97.frames_at_times(&times), frames);
+  }
+
+  #[test]
+  fn frames_at_times() {
+    let times = [0, 19, 20, 39, 41].map(Duration::from_millis);
+    assert_eq!(FrameRate::_25_00.frames_at_times(&times), [0, 0, 1, 1, 1]);
+  }
+
+  #[test]
+  fn convert_frames() {
+    assert_eq!(
+      FrameRate::_25_00.convert_frames(&[0, 1, 2, 25, 50], FrameRate::_30_00),
+      [0, 1, 2, 30, 60]
+    );
+    assert_eq!(
+      FrameRate::_59_94.convert_frames(&[0, 1, 2, 3, 60_000], FrameRate::_29_97),
+      [0, 1, 1, 2, 30_000]
+    );
+  }
+
+  #[test]
+  fn timecodes() {
+    let timecodes = FrameRate::_29_97
+      .timecodes_of_frames(&[0, 1800, 17_982], true)
+      .unwrap();
+    let labels: Vec<_> = timecodes.iter().map(Timecode::to_string).collect();
+    assert_eq!(labels, ["00:00:00;00", "00:01:00;02", "00:10:00;00"]);
+    assert_eq!(
+      FrameRate::_29_97.frames_of_timecodes(&labels),
+      Ok(vec![0, 1800, 17_982])
+    );
+    assert_eq!(
+      FrameRate::_25_00.timecodes_of_frames(&[0], true),
+      Err(Error::NoDropFrame(FrameRate::_25_00))
+    );
+    assert!(
+      FrameRate::_25_00
+        .frames_of_timecodes(&["00:00:00:00", "00:00:00:25"])
+        .is_err()
+    );
+  }
+}
@@ -0,0 +1,79 @@
+use crate::{FrameRate, Ratio};
+
+impl FrameRate {
+  /// Exact number of bits available per frame at `bitrate` bits per second.
+  ///
+  /// # Panics
+  ///
+  /// Panics on a zero frame rate or if the reduced result does not fit in a
+  /// `Ratio<u64>`.
+  pub fn bits_per_frame(&self, bitrate: u64) -> Ratio<u64> {
+    self.bits_for_frames(bitrate, 1)
+  }
+
+  /// Exact number of bits available for a group of pictures of `gop_frames`
+  /// frames at `bitrate` bits per second.
+  ///
+  /// # Panics
+  ///
+  /// Panics on a zero frame rate or if the reduced result does not fit in a
+  /// `Ratio<u64>`.
+  pub fn bits_per_gop(&self, bitrate: u64, gop_frames: u64) -> Ratio<u64> {
+    self.bits_for_frames(bitrate, gop_frames)
+  }
+
+  fn bits_for_frames(&self, bitrate: u64, frames: u64) -> Ratio<u64> {
+    let ratio = Ratio::<u32>::from(*self);
+    let bits = Ratio::new(
+      u128::from(bitrate) * u128::from(frames) * u128::from(*ratio.denom()),
+      u128::from(*ratio.numer()),
+    );
+    Ratio::new(
+      u64::try_from(*bits.numer()).expect("bit budget numerator overflow"),
+      u64::try_from(*bits.denom()).expect("bit budget denominator overflow"),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn bits_per_frame() {
+    assert_eq!(
+      FrameRate::_25_00.bits_per_frame(10_000_000),
+      Ratio::from_integer(400_000)
+    );
+    assert_eq!(
+      FrameRate::_29_97.bits_per_frame(10_000_000),
+      Ratio::new(1_001_000, 3)
+    );
+    assert_eq!(
+      FrameRate::_23_97.bits_per_frame(8_000_000),
+      Ratio::new(1_001_000, 3)
+    );
+  }
+
+  #[test]
+  fn bits_per_gop() {
+    assert_eq!(
+      FrameRate::_29_97.bits_per_gop(10_000_000, 15),
+      Ratio::from_integer(5_005_000)
+    );
+    assert_eq!(
+      FrameRate::_59_94.bits_per_gop(20_000_000, 60),
+      Ratio::from_integer(20_020_000)
+    );
+    assert_eq!(
+      FrameRate::_29_97.bits_per_gop(10_000_000, 15),
+      FrameRate::_29_97.bits_per_frame(10_000_000) * 15
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "bit budget numerator overflow")]
+  fn overflow() {
+    let _ = FrameRate::new(1, u32::MAX).bits_per_gop(u64::MAX, 1);
+  }
+}
@@ -0,0 +1,129 @@
+use crate::{FrameRate, Ratio, SerializeRational};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+/// A frame rate that is known to be strictly positive and at most `MAX_FPS`.
+///
+/// Limits are enforced at deserialization time, so it can be used directly in
+/// request payloads.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BoundedFrameRate<const MAX_FPS: u32>(FrameRate);
+
+impl<const MAX_FPS: u32> BoundedFrameRate<MAX_FPS> {
+  pub fn new(frame_rate: FrameRate) -> Option<Self> {
+    let ratio = Ratio::<u32>::from(frame_rate);
+    Self::is_within_bounds(*ratio.numer(), *ratio.denom()).then_some(Self(frame_rate))
+  }
+
+  pub fn frame_rate(&self) -> FrameRate {
+    self.0
+  }
+
+  fn is_within_bounds(num: u32, den: u32) -> bool {
+    num != 0 && den != 0 && u64::from(num) <= u64::from(MAX_FPS) * u64::from(den)
+  }
+}
+
+impl<const MAX_FPS: u32> From<BoundedFrameRate<MAX_FPS>> for FrameRate {
+  fn from(bounded: BoundedFrameRate<MAX_FPS>) -> Self {
+    bounded.0
+  }
+}
+
+impl<const MAX_FPS: u32> utoipa::ToSchema for BoundedFrameRate<MAX_FPS> {
+  fn name() -> std::borrow::Cow<'static, str> {
+    FrameRate::name()
+  }
+}
+
+impl<const MAX_FPS: u32> utoipa::PartialSchema for BoundedFrameRate<MAX_FPS> {
+  fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+    FrameRate::schema()
+  }
+}
+
+impl<const MAX_FPS: u32> Serialize for BoundedFrameRate<MAX_FPS> {
+  fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+  where
+    S: Serializer,
+  {
+    self.0.serialize(serializer)
+  }
+}
+
+impl<'de, const MAX_FPS: u32> Deserialize<'de> for BoundedFrameRate<MAX_FPS> {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let SerializeRational { num, den } = SerializeRational::deserialize(deserializer)?;
+
+    if !Self::is_within_bounds(num, den) {
+      return Err(de::Error::custom(format!(
+        "frame rate {num}/{den} is not within ]0, {MAX_FPS}] fps"
+      )));
+    }
+
+    Ok(Self(FrameRate::new(num, den)))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn new() {
+    assert_eq!(
+      BoundedFrameRate::<60>::new(FrameRate::_59_94).map(FrameRate::from),
+      Some(FrameRate::_59_94)
+    );
+    assert!(BoundedFrameRate::<60>::new(FrameRate::_60_00).is_some());
+    assert!(BoundedFrameRate::<60>::new(FrameRate::_120_00).is_none());
+    assert!(BoundedFrameRate::<60>::new(FrameRate::new(0, 1)).is_none());
+  }
+
+  #[test]
+  fn deserialize() {
+    assert_eq!(
+      serde_json::from_value::<BoundedFrameRate<120>>(serde_json::json!({
+        "num": 30000,
+        "den": 1001
+      }))
+      .unwrap()
+      .frame_rate(),
+      FrameRate::_29_97
+    );
+    assert!(
+      serde_json::from_value::<BoundedFrameRate<120>>(serde_json::json!({
+        "num": 4294967295u32,
+        "den": 1
+      }))
+      .is_err()
+    );
+    assert!(
+      serde_json::from_value::<BoundedFrameRate<120>>(serde_json::json!({
+        "num": 25,
+        "den": 0
+      }))
+      .is_err()
+    );
+    assert!(
+      serde_json::from_value::<BoundedFrameRate<120>>(serde_json::json!({
+        "num": 0,
+        "den": 1
+      }))
+      .is_err()
+    );
+  }
+
+  #[test]
+  fn serialize() {
+    assert_eq!(
+      serde_json::to_value(BoundedFrameRate::<60>::new(FrameRate::_25_00).unwrap()).unwrap(),
+      serde_json::json!({
+        "num": 25,
+        "den": 1
+      })
+    );
+  }
+}
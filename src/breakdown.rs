@@ -0,0 +1,235 @@
+use crate::{FrameRate, Ratio};
+
+/// Frame count split into the fields of a timecode label. Hours are not
+/// wrapped at 24.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+pub struct Breakdown {
+  pub hours: u64,
+  pub minutes: u8,
+  pub seconds: u8,
+  pub frames: u32,
+}
+
+impl FrameRate {
+  /// Frames labelled per timecode second: the rate rounded up, e.g. 30 at
+  /// 29.97, and 1 below 1 fps.
+  pub fn nominal_fps(&self) -> u32 {
+    let ratio = Ratio::<u32>::from(*self);
+    ratio.numer().div_ceil(*ratio.denom()).max(1)
+  }
+
+  /// Timecode fields labelling frame `frames`, counted from `00:00:00:00`.
+  ///
+  /// Drop-frame skips the first 2 labels of every minute at 29.97 (4 at
+  /// 59.94) except every tenth minute, and is `None` at rates without
+  /// drop-frame timecode. Below 1 fps, frames are labelled by the whole
+  /// second they start in.
+  ///
+  /// ```
+  /// use frame_rate::{Breakdown, FrameRate};
+  ///
+  /// assert_eq!(
+  ///   FrameRate::_29_97.breakdown(1800, true),
+  ///   Some(Breakdown { hours: 0, minutes: 1, seconds: 0, frames: 2 })
+  /// );
+  /// ```
+  pub fn breakdown(&self, frames: u64, drop_frame: bool) -> Option<Breakdown> {
+    let nominal = u64::from(self.nominal_fps());
+    let ratio = Ratio::<u32>::from(*self);
+    if ratio.numer() < ratio.denom() {
+      if drop_frame {
+        return None;
+      }
+      // At 0 fps, only the first frame ever starts.
+      let seconds = match *ratio.numer() {
+        0 => (frames == 0).then_some(0)?,
+        numer => u128::from(frames) * u128::from(*ratio.denom()) / u128::from(numer),
+      };
+      return Some(split(u64::try_from(seconds).ok()?, 0));
+    }
+
+    let labels = if drop_frame {
+      self.metadata().filter(|metadata| metadata.drop_frame)?;
+      let dropped = nominal / 15;
+      let per_minute = 60 * nominal - dropped;
+      let per_ten_minutes = 10 * per_minute + dropped;
+      let (tens, rest) = (frames / per_ten_minutes, frames % per_ten_minutes);
+      let skipped = 9 * dropped * tens + rest.saturating_sub(dropped) / per_minute * dropped;
+      frames.checked_add(skipped)?
+    } else {
+      frames
+    };
+    Some(split(labels / nominal, labels % nominal))
+  }
+
+  /// Frame labelled by `breakdown`, the inverse of [`FrameRate::breakdown`].
+  /// `None` for out of range fields and labels skipped by drop-frame.
+  pub fn from_breakdown(&self, breakdown: Breakdown, drop_frame: bool) -> Option<u64> {
+    let Breakdown {
+      hours,
+      minutes,
+      seconds,
+      frames,
+    } = breakdown;
+    let nominal = u64::from(self.nominal_fps());
+    if minutes >= 60 || seconds >= 60 || u64::from(frames) >= nominal {
+      return None;
+    }
+    let total_minutes = hours.checked_mul(60)?.checked_add(minutes.into())?;
+    let total_seconds = total_minutes.checked_mul(60)?.checked_add(seconds.into())?;
+
+    let ratio = Ratio::<u32>::from(*self);
+    if ratio.numer() < ratio.denom() {
+      if drop_frame {
+        return None;
+      }
+      let numer = u128::from(*ratio.numer());
+      let denom = u128::from(*ratio.denom());
+      if numer == 0 {
+        return (total_seconds == 0).then_some(0);
+      }
+      // First frame starting within the labelled second, if any.
+      let frame = (u128::from(total_seconds) * numer).div_ceil(denom);
+      return (frame * denom / numer == u128::from(total_seconds))
+        .then(|| u64::try_from(frame).ok())
+        .flatten();
+    }
+
+    let count = total_seconds
+      .checked_mul(nominal)?
+      .checked_add(frames.into())?;
+    if !drop_frame {
+      return Some(count);
+    }
+    self.metadata().filter(|metadata| metadata.drop_frame)?;
+    let dropped = nominal / 15;
+    if seconds == 0 && minutes % 10 != 0 && u64::from(frames) < dropped {
+      return None;
+    }
+    Some(count - dropped * (total_minutes - total_minutes / 10))
+  }
+}
+
+fn split(seconds: u64, frames: u64) -> Breakdown {
+  Breakdown {
+    hours: seconds / 3600,
+    minutes: (seconds / 60 % 60) as u8,
+    seconds: (seconds % 60) as u8,
+    frames: frames as u32,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::STANDARD_FRAME_RATES;
+
+  fn breakdown(hours: u64, minutes: u8, seconds: u8, frames: u32) -> Breakdown {
+    Breakdown {
+      hours,
+      minutes,
+      seconds,
+      frames,
+    }
+  }
+
+  #[test]
+  fn non_drop_frame() {
+    assert_eq!(
+      FrameRate::_25_00.breakdown(137, false),
+      Some(breakdown(0, 0, 5, 12))
+    );
+    assert_eq!(
+      FrameRate::_29_97.breakdown(108_000, false),
+      Some(breakdown(1, 0, 0, 0))
+    );
+    assert_eq!(
+      FrameRate::_25_00.breakdown(25 * 86_400 * 2, false),
+      Some(breakdown(48, 0, 0, 0))
+    );
+    assert_eq!(FrameRate::_25_00.breakdown(0, true), None);
+  }
+
+  #[test]
+  fn drop_frame() {
+    let frame_rate = FrameRate::_29_97;
+    assert_eq!(
+      frame_rate.breakdown(1799, true),
+      Some(breakdown(0, 0, 59, 29))
+    );
+    assert_eq!(
+      frame_rate.breakdown(1800, true),
+      Some(breakdown(0, 1, 0, 2))
+    );
+    assert_eq!(
+      frame_rate.breakdown(17_982, true),
+      Some(breakdown(0, 10, 0, 0))
+    );
+    assert_eq!(
+      frame_rate.breakdown(107_892, true),
+      Some(breakdown(1, 0, 0, 0))
+    );
+    assert_eq!(
+      FrameRate::_59_94.breakdown(3600, true),
+      Some(breakdown(0, 1, 0, 4))
+    );
+    assert_eq!(frame_rate.from_breakdown(breakdown(0, 1, 0, 1), true), None);
+    assert_eq!(
+      frame_rate.from_breakdown(breakdown(0, 10, 0, 0), true),
+      Some(17_982)
+    );
+  }
+
+  #[test]
+  fn round_trip() {
+    for frame_rate in STANDARD_FRAME_RATES {
+      let drop_frame = frame_rate.metadata().unwrap().drop_frame;
+      for frames in (0..200_000).step_by(7).chain([u64::from(u32::MAX)]) {
+        for drop_frame in [false, drop_frame] {
+          let breakdown = frame_rate.breakdown(frames, drop_frame).unwrap();
+          assert_eq!(
+            frame_rate.from_breakdown(breakdown, drop_frame),
+            Some(frames),
+            "{frame_rate:?} {frames} {drop_frame}"
+          );
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn below_one_fps() {
+    let frame_rate = FrameRate::new(1, 10);
+    assert_eq!(frame_rate.nominal_fps(), 1);
+    assert_eq!(frame_rate.breakdown(7, false), Some(breakdown(0, 1, 10, 0)));
+    assert_eq!(frame_rate.breakdown(7, true), None);
+    assert_eq!(
+      frame_rate.from_breakdown(breakdown(0, 1, 10, 0), false),
+      Some(7)
+    );
+    assert_eq!(
+      frame_rate.from_breakdown(breakdown(0, 1, 11, 0), false),
+      None
+    );
+
+    let frame_rate = FrameRate::new(2, 25);
+    assert_eq!(frame_rate.breakdown(1, false), Some(breakdown(0, 0, 12, 0)));
+    assert_eq!(
+      frame_rate.from_breakdown(breakdown(0, 0, 12, 0), false),
+      Some(1)
+    );
+
+    let frame_rate = FrameRate::new(0, 1);
+    assert_eq!(frame_rate.breakdown(0, false), Some(breakdown(0, 0, 0, 0)));
+    assert_eq!(frame_rate.breakdown(1, false), None);
+    assert_eq!(
+      frame_rate.from_breakdown(breakdown(0, 0, 0, 0), false),
+      Some(0)
+    );
+    assert_eq!(
+      frame_rate.from_breakdown(breakdown(0, 0, 1, 0), false),
+      None
+    );
+  }
+}
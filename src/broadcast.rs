@@ -0,0 +1,126 @@
+use crate::{FrameRate, Ratio, Rounding};
+use std::ops::Range;
+use std::time::Duration;
+
+const DAY: Duration = Duration::from_secs(86_400);
+
+/// Frame arithmetic over a 24 hour broadcast day, see
+/// [`FrameRate::broadcast_day`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct BroadcastDay {
+  frame_rate: FrameRate,
+}
+
+impl FrameRate {
+  pub fn broadcast_day(&self) -> BroadcastDay {
+    BroadcastDay { frame_rate: *self }
+  }
+}
+
+impl BroadcastDay {
+  /// Frames starting within 24 hours of wall-clock time, e.g. 2,589,411 at
+  /// 29.97.
+  pub fn frames(&self) -> u64 {
+    self.frame_rate.duration_to_frames(DAY, Rounding::Ceil)
+  }
+
+  /// Frames labelled by a 24 hour timecode day, `00:00:00:00` to
+  /// `23:59:59:29` at 29.97.
+  ///
+  /// Non drop-frame timecode labels every frame of each nominal second, drop
+  /// frame timecode skips 2 labels per minute at 29.97 (4 at 59.94) except
+  /// every tenth minute, e.g. 2,589,408 frames at 29.97. Returns `None` for
+  /// drop-frame at a rate without drop-frame timecode.
+  pub fn timecode_frames(&self, drop_frame: bool) -> Option<u64> {
+    let nominal = u64::from(Ratio::<u32>::from(self.frame_rate).ceil().to_integer());
+    let frames = nominal * DAY.as_secs();
+    if !drop_frame {
+      return Some(frames);
+    }
+    self
+      .frame_rate
+      .metadata()
+      .filter(|metadata| metadata.drop_frame)
+      .map(|_| {
+        let minutes = DAY.as_secs() / 60;
+        frames - nominal / 15 * (minutes - minutes / 10)
+      })
+  }
+
+  /// Splits the day in slots of `slot` wall-clock time, the last one ending at
+  /// midnight. Each slot holds the frames starting within it, so slot lengths
+  /// in frames vary at rates without a whole number of frames per slot.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `slot` is zero.
+  pub fn slots(&self, slot: Duration) -> Vec<Range<u64>> {
+    assert!(!slot.is_zero(), "slot must not be empty");
+    let boundary = |time: Duration| {
+      self
+        .frame_rate
+        .duration_to_frames(time.min(DAY), Rounding::Ceil)
+    };
+
+    let mut slots = Vec::new();
+    let mut start = Duration::ZERO;
+    while start < DAY {
+      let end = start + slot;
+      slots.push(boundary(start)..boundary(end));
+      start = end;
+    }
+    slots
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn frames() {
+    assert_eq!(FrameRate::_25_00.broadcast_day().frames(), 2_160_000);
+    assert_eq!(FrameRate::_29_97.broadcast_day().frames(), 2_589_411);
+    assert_eq!(FrameRate::_59_94.broadcast_day().frames(), 5_178_822);
+  }
+
+  #[test]
+  fn timecode_frames() {
+    let day = FrameRate::_29_97.broadcast_day();
+    assert_eq!(day.timecode_frames(false), Some(2_592_000));
+    assert_eq!(day.timecode_frames(true), Some(2_589_408));
+    assert_eq!(
+      FrameRate::_59_94.broadcast_day().timecode_frames(true),
+      Some(5_178_816)
+    );
+    assert_eq!(
+      FrameRate::_25_00.broadcast_day().timecode_frames(true),
+      None
+    );
+    assert_eq!(
+      FrameRate::_23_97.broadcast_day().timecode_frames(false),
+      Some(2_073_600)
+    );
+  }
+
+  #[test]
+  fn slots() {
+    let slots = FrameRate::_25_00
+      .broadcast_day()
+      .slots(Duration::from_secs(3600));
+    assert_eq!(slots.len(), 24);
+    assert!(slots.iter().all(|slot| slot.end - slot.start == 90_000));
+
+    let day = FrameRate::_29_97.broadcast_day();
+    let slots = day.slots(Duration::from_secs(1800));
+    assert_eq!(slots.len(), 48);
+    assert_eq!(slots[0], 0..53_947);
+    assert_eq!(slots[1], 53_947..107_893);
+    assert!(slots.windows(2).all(|pair| pair[0].end == pair[1].start));
+    assert_eq!(slots[47].end, day.frames());
+
+    let slots = day.slots(Duration::from_secs(7 * 3600));
+    assert_eq!(slots.len(), 4);
+    assert_eq!(slots[3].end, day.frames());
+  }
+}
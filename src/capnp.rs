@@ -0,0 +1,96 @@
+//! Cap'n Proto adapter for the `FrameRate` struct of
+//! `schema/frame_rate.capnp`.
+//!
+//! [`Reader`] and [`Builder`] are the `capnpc-rust` output for the schema,
+//! regenerated with `capnp compile -orust:src/capnp --src-prefix=schema
+//! schema/frame_rate.capnp`.
+//!
+//! ```
+//! use capnp::message;
+//! use frame_rate::FrameRate;
+//! use frame_rate::capnp::{Builder, Reader};
+//!
+//! let mut message = message::Builder::new_default();
+//! message.init_root::<Builder>().set_frame_rate(FrameRate::_29_97);
+//! let reader = message.get_root_as_reader::<Reader>().unwrap();
+//! assert_eq!(FrameRate::try_from(reader), Ok(FrameRate::_29_97));
+//! ```
+
+use crate::{Error, FrameRate, Ratio};
+
+#[rustfmt::skip]
+mod frame_rate_capnp;
+
+pub use frame_rate_capnp::frame_rate::{Builder, Owned, Reader};
+
+/// Source of `schema/frame_rate.capnp`.
+pub const FRAME_RATE_CAPNP: &str = include_str!("../schema/frame_rate.capnp");
+
+impl Builder<'_> {
+  /// Writes the reduced ratio of `frame_rate`.
+  pub fn set_frame_rate(&mut self, frame_rate: FrameRate) {
+    let ratio = Ratio::<u32>::from(frame_rate);
+    self.set_num(*ratio.numer());
+    self.set_den(*ratio.denom());
+  }
+}
+
+/// Fails on a zero denominator, which is also what an unset `den` reads as.
+impl TryFrom<Reader<'_>> for FrameRate {
+  type Error = Error;
+
+  fn try_from(reader: Reader<'_>) -> Result<Self, Error> {
+    Self::try_new(reader.get_num(), reader.get_den())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use ::capnp::message::{self, ReaderOptions};
+  use ::capnp::serialize;
+
+  fn encode(frame_rate: FrameRate) -> Vec<u8> {
+    let mut message = message::Builder::new_default();
+    message.init_root::<Builder>().set_frame_rate(frame_rate);
+    serialize::write_message_to_words(&message)
+  }
+
+  fn decode(bytes: Vec<u8>) -> Result<FrameRate, Error> {
+    let message =
+      serialize::read_message_from_flat_slice(&mut bytes.as_slice(), ReaderOptions::new()).unwrap();
+    FrameRate::try_from(message.get_root::<Reader>().unwrap())
+  }
+
+  #[test]
+  fn wire_format() {
+    assert_eq!(
+      encode(FrameRate::_29_97),
+      [
+        0, 0, 0, 0, 2, 0, 0, 0, // one segment of two words
+        0, 0, 0, 0, 1, 0, 0, 0, // root struct, one data word
+        0x30, 0x75, 0, 0, 0xe9, 0x03, 0, 0, // 30000, 1001
+      ]
+    );
+    assert_eq!(decode(encode(FrameRate::new(50, 2))), Ok(FrameRate::_25_00));
+  }
+
+  #[test]
+  fn zero_denominator() {
+    let message = message::Builder::new_default();
+    let reader = message.get_root_as_reader::<Reader>().unwrap();
+    assert_eq!(FrameRate::try_from(reader), Err(Error::ZeroDenominator));
+  }
+
+  #[cfg(feature = "test-util")]
+  #[test]
+  fn conformance() {
+    crate::conformance::assert_round_trip(encode, decode);
+  }
+
+  #[test]
+  fn schema_file() {
+    assert!(FRAME_RATE_CAPNP.contains("num @0 :UInt32;"));
+    assert!(FRAME_RATE_CAPNP.contains("den @1 :UInt32;"));
+  }
+}
@@ -0,0 +1,159 @@
+use crate::{FrameRate, Rounding};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Switch from one frame rate to another on a continuous frame counter.
+///
+/// Frames before `effective_frame` run at `from`, frames from
+/// `effective_frame` on run at `to`. `effective_time` is the start of
+/// `effective_frame`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize, Deserialize, utoipa::ToSchema)]
+pub struct RateChange {
+  pub from: FrameRate,
+  pub to: FrameRate,
+  pub effective_frame: u64,
+  #[schema(schema_with = duration_schema)]
+  pub effective_time: Duration,
+}
+
+fn duration_schema() -> utoipa::openapi::schema::Object {
+  use utoipa::openapi::{KnownFormat, ObjectBuilder, SchemaFormat, Type};
+
+  let integer = |format: KnownFormat| {
+    ObjectBuilder::new()
+      .schema_type(Type::Integer)
+      .format(Some(SchemaFormat::KnownFormat(format)))
+      .minimum(Some(0))
+  };
+
+  ObjectBuilder::new()
+    .property("secs", integer(KnownFormat::Int64))
+    .required("secs")
+    .property("nanos", integer(KnownFormat::Int32))
+    .required("nanos")
+    .build()
+}
+
+impl RateChange {
+  /// Change happening at `effective_frame`, the counter having run at `from`
+  /// since frame 0.
+  pub fn new(from: FrameRate, to: FrameRate, effective_frame: u64) -> Self {
+    Self {
+      from,
+      to,
+      effective_frame,
+      effective_time: from.duration_of(effective_frame),
+    }
+  }
+
+  /// Start time of `frame` on the counter.
+  pub fn time_of(&self, frame: u64) -> Duration {
+    match frame.checked_sub(self.effective_frame) {
+      Some(frames) => self
+        .effective_time
+        .saturating_add(self.to.duration_of(frames)),
+      None => self.from.duration_of(frame),
+    }
+  }
+
+  /// Frame of the counter at `time`.
+  pub fn frame_at(&self, time: Duration, rounding: Rounding) -> u64 {
+    match time.checked_sub(self.effective_time) {
+      Some(elapsed) => self
+        .effective_frame
+        .saturating_add(self.to.duration_to_frames(elapsed, rounding)),
+      None => self.from.duration_to_frames(time, rounding),
+    }
+  }
+
+  /// Maps a frame counted at `from` as if the rate never changed to the
+  /// counter including the change.
+  #[cfg_attr(feature = "tracing", tracing::instrument(level = "trace", ret))]
+  pub fn remap(&self, frame: u64, rounding: Rounding) -> u64 {
+    match frame.checked_sub(self.effective_frame) {
+      Some(frames) => self
+        .effective_frame
+        .saturating_add(self.from.convert_frame(frames, self.to, rounding)),
+      None => frame,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn remap() {
+    let change = RateChange::new(FrameRate::_50_00, FrameRate::_25_00, 1500);
+    assert_eq!(change.effective_time, Duration::from_secs(30));
+
+    assert_eq!(change.time_of(1000), Duration::from_secs(20));
+    assert_eq!(change.time_of(1525), Duration::from_secs(31));
+    assert_eq!(
+      change.frame_at(Duration::from_secs(20), Rounding::Floor),
+      1000
+    );
+    assert_eq!(
+      change.frame_at(Duration::from_secs(31), Rounding::Floor),
+      1525
+    );
+
+    assert_eq!(change.remap(1000, Rounding::Floor), 1000);
+    assert_eq!(change.remap(1550, Rounding::Floor), 1525);
+    assert_eq!(change.remap(1551, Rounding::Floor), 1525);
+    assert_eq!(change.remap(1551, Rounding::Ceil), 1526);
+  }
+
+  #[test]
+  fn zero_frame_rate() {
+    let still = FrameRate::new(0, 1);
+    let change = RateChange::new(FrameRate::_25_00, still, 50);
+    assert_eq!(change.time_of(50), Duration::from_secs(2));
+    assert_eq!(change.time_of(51), Duration::MAX);
+    assert_eq!(change.frame_at(Duration::MAX, Rounding::Floor), 50);
+    assert_eq!(change.remap(50, Rounding::Floor), 50);
+
+    let change = RateChange::new(still, FrameRate::_25_00, 1);
+    assert_eq!(change.effective_time, Duration::MAX);
+    assert_eq!(change.time_of(u64::MAX), Duration::MAX);
+    assert_eq!(change.frame_at(Duration::MAX, Rounding::Ceil), 1);
+    assert_eq!(change.remap(2, Rounding::Floor), u64::MAX);
+
+    let change = RateChange::new(FrameRate::_25_00, FrameRate::new(u32::MAX, 1), 1);
+    assert_eq!(change.frame_at(Duration::MAX, Rounding::Ceil), u64::MAX);
+  }
+
+  #[test]
+  fn serialize() {
+    let change = RateChange::new(FrameRate::_25_00, FrameRate::_50_00, 10);
+    let value = json!({
+      "from": {"num": 25, "den": 1},
+      "to": {"num": 50, "den": 1},
+      "effective_frame": 10,
+      "effective_time": {"secs": 0, "nanos": 400_000_000}
+    });
+    assert_eq!(serde_json::to_value(change).unwrap(), value);
+    assert_eq!(serde_json::from_value::<RateChange>(value).unwrap(), change);
+  }
+
+  #[test]
+  fn schema() {
+    use utoipa::PartialSchema;
+
+    let schema = serde_json::to_value(RateChange::schema()).unwrap();
+    assert_eq!(
+      schema["properties"]["from"]["$ref"],
+      "#/components/schemas/FrameRate"
+    );
+    assert_eq!(
+      schema["properties"]["effective_time"]["required"],
+      json!(["secs", "nanos"])
+    );
+    assert_eq!(
+      schema["required"],
+      json!(["from", "to", "effective_frame", "effective_time"])
+    );
+  }
+}
@@ -0,0 +1,159 @@
+use crate::{ClipRange, Error, Ratio, Rounding, TimeRange};
+
+/// Frames chunk boundaries may fall on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ChunkAlignment {
+  /// Any frame.
+  Frame,
+  /// First frame starting at or after a whole second, e.g. frame 24 for
+  /// second 1 at 23.976.
+  Second,
+  /// Multiples of a GOP length in frames, counted from frame 0.
+  Gop(u64),
+}
+
+/// A chunk of a [`ClipRange`] to dispatch on its own.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Chunk {
+  pub frames: ClipRange,
+  /// Exact times covered by `frames`.
+  pub time: TimeRange,
+}
+
+impl ClipRange {
+  /// Splits the range into at most `chunks` contiguous chunks of about the
+  /// same length, with inner boundaries moved to the nearest `alignment`
+  /// boundary.
+  ///
+  /// Chunks never overlap and cover the whole range. Fewer chunks are
+  /// returned when aligned boundaries collapse, e.g. more chunks than GOPs.
+  /// Fails when the range has no exact time, e.g. at 0 fps, see
+  /// [`TimeRange::from_frames`].
+  ///
+  /// # Panics
+  ///
+  /// Panics on a zero GOP length.
+  pub fn plan_chunks(&self, chunks: usize, alignment: ChunkAlignment) -> Result<Vec<Chunk>, Error> {
+    // Inner boundaries have an exact time when both ends have one.
+    TimeRange::try_from(*self)?;
+    let (start, end) = (self.start(), self.end());
+    let chunks = chunks.max(1) as u128;
+    let mut boundaries = vec![start];
+    for index in 1..chunks {
+      let ideal = start + (u128::from(self.duration()) * index / chunks) as u64;
+      let boundary = self.align(ideal, alignment).clamp(start, end);
+      if boundary > *boundaries.last().unwrap() && boundary < end {
+        boundaries.push(boundary);
+      }
+    }
+    boundaries.push(end);
+
+    boundaries
+      .windows(2)
+      .map(|pair| {
+        let frames =
+          ClipRange::new(self.frame_rate(), pair[0], pair[1]).expect("increasing boundaries");
+        Ok(Chunk {
+          frames,
+          time: frames.try_into()?,
+        })
+      })
+      .collect()
+  }
+
+  /// Boundary of `alignment` nearest to frame `frame`.
+  fn align(&self, frame: u64, alignment: ChunkAlignment) -> u64 {
+    match alignment {
+      ChunkAlignment::Frame => frame,
+      ChunkAlignment::Second => {
+        let ratio = Ratio::<u32>::from(self.frame_rate());
+        let (num, den) = (u128::from(*ratio.numer()), u128::from(*ratio.denom()));
+        let second = Rounding::Nearest.div(u128::from(frame) * den, num);
+        u64::try_from(Rounding::Ceil.div(second * num, den)).unwrap_or(u64::MAX)
+      }
+      ChunkAlignment::Gop(gop) => {
+        assert_ne!(gop, 0, "GOP length must not be zero");
+        let gops = u64::try_from(Rounding::Nearest.div(frame.into(), gop.into()))
+          .expect("rounded down or up to a frame");
+        gops.saturating_mul(gop)
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::FrameRate;
+
+  fn starts(chunks: &[Chunk]) -> Vec<u64> {
+    chunks.iter().map(|chunk| chunk.frames.start()).collect()
+  }
+
+  #[test]
+  fn frames() {
+    let range = ClipRange::new(FrameRate::_25_00, 0, 100).unwrap();
+    let chunks = range.plan_chunks(3, ChunkAlignment::Frame).unwrap();
+    assert_eq!(starts(&chunks), [0, 33, 66]);
+    assert_eq!(chunks[2].frames.end(), 100);
+    assert_eq!(chunks[1].time.start(), (33, 25).into());
+  }
+
+  #[test]
+  fn seconds_at_23_976() {
+    let range = ClipRange::new(FrameRate::_23_97, 0, 24 * 60).unwrap();
+    let chunks = range.plan_chunks(4, ChunkAlignment::Second).unwrap();
+    // Seconds 15, 30 and 45 start at frames 360, 720 and 1079.
+    assert_eq!(starts(&chunks), [0, 360, 720, 1079]);
+    for pair in chunks.windows(2) {
+      assert_eq!(pair[0].frames.end(), pair[1].frames.start());
+      assert_eq!(pair[0].time.end(), pair[1].time.start());
+    }
+    assert_eq!(
+      chunks
+        .iter()
+        .map(|chunk| chunk.frames.duration())
+        .sum::<u64>(),
+      24 * 60
+    );
+  }
+
+  #[test]
+  fn out_of_range() {
+    let range = ClipRange::new(FrameRate::new(0, 1), 0, 10).unwrap();
+    for alignment in [ChunkAlignment::Frame, ChunkAlignment::Second] {
+      assert_eq!(
+        range.plan_chunks(2, alignment),
+        Err(Error::TimeOutOfRange {
+          frame_rate: FrameRate::new(0, 1),
+          frames: 10
+        })
+      );
+    }
+
+    let range = ClipRange::new(FrameRate::new(u32::MAX, 1), 0, u64::MAX).unwrap();
+    let chunks = range.plan_chunks(2, ChunkAlignment::Second).unwrap();
+    assert_eq!(starts(&chunks), [0, 9_223_372_034_707_292_160]);
+    // The last boundary rounds up to 2 GOPs, past the end.
+    let chunks = range.plan_chunks(8, ChunkAlignment::Gop(1 << 63)).unwrap();
+    assert_eq!(starts(&chunks), [0, 1 << 63]);
+  }
+
+  #[test]
+  fn gops() {
+    let range = ClipRange::new(FrameRate::_29_97, 10, 100).unwrap();
+    let chunks = range.plan_chunks(3, ChunkAlignment::Gop(15)).unwrap();
+    assert_eq!(starts(&chunks), [10, 45, 75]);
+
+    // More chunks than GOPs.
+    let range = ClipRange::new(FrameRate::_29_97, 0, 40).unwrap();
+    assert_eq!(
+      starts(&range.plan_chunks(8, ChunkAlignment::Gop(15)).unwrap()),
+      [0, 15, 30]
+    );
+    assert_eq!(
+      range.plan_chunks(0, ChunkAlignment::Frame).unwrap().len(),
+      1
+    );
+  }
+}
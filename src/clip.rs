@@ -0,0 +1,238 @@
+use crate::{Error, FrameRate, Rounding};
+use std::ops::Range;
+use std::time::Duration;
+
+/// How range boundaries are rounded when mapping a [`ClipRange`] to another
+/// frame rate.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RangeRounding {
+  /// The mapped range covers the whole original range.
+  Outward,
+  /// The mapped range is fully covered by the original range.
+  Inward,
+  /// Each boundary is mapped to the nearest frame.
+  Nearest,
+}
+
+impl RangeRounding {
+  /// Rounding of the start and end boundaries.
+  pub(crate) fn boundaries(self) -> (Rounding, Rounding) {
+    match self {
+      Self::Outward => (Rounding::Floor, Rounding::Ceil),
+      Self::Inward => (Rounding::Ceil, Rounding::Floor),
+      Self::Nearest => (Rounding::Nearest, Rounding::Nearest),
+    }
+  }
+}
+
+/// A range of frames at a given frame rate.
+///
+/// The in point (`start`) is inclusive and the out point (`end`) is exclusive.
+/// Operations combining two ranges only apply to ranges sharing the same frame
+/// rate.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ClipRange {
+  frame_rate: FrameRate,
+  start: u64,
+  end: u64,
+}
+
+impl ClipRange {
+  pub fn new(frame_rate: FrameRate, start: u64, end: u64) -> Result<Self, Error> {
+    if start >= end {
+      return Err(Error::InvalidClipRange { start, end });
+    }
+
+    Ok(Self {
+      frame_rate,
+      start,
+      end,
+    })
+  }
+
+  pub fn frame_rate(&self) -> FrameRate {
+    self.frame_rate
+  }
+
+  pub fn start(&self) -> u64 {
+    self.start
+  }
+
+  pub fn end(&self) -> u64 {
+    self.end
+  }
+
+  /// Number of frames in the range.
+  pub fn duration(&self) -> u64 {
+    self.end - self.start
+  }
+
+  pub fn contains(&self, frame: u64) -> bool {
+    (self.start..self.end).contains(&frame)
+  }
+
+  pub fn overlaps(&self, other: &Self) -> bool {
+    self.frame_rate == other.frame_rate && self.start < other.end && other.start < self.end
+  }
+
+  pub fn intersection(&self, other: &Self) -> Option<Self> {
+    if !self.overlaps(other) {
+      return None;
+    }
+
+    Some(Self {
+      frame_rate: self.frame_rate,
+      start: self.start.max(other.start),
+      end: self.end.min(other.end),
+    })
+  }
+
+  /// Smallest range covering both ranges, if they overlap or are adjacent.
+  pub fn union(&self, other: &Self) -> Option<Self> {
+    if self.frame_rate != other.frame_rate || self.start > other.end || other.start > self.end {
+      return None;
+    }
+
+    Some(Self {
+      frame_rate: self.frame_rate,
+      start: self.start.min(other.start),
+      end: self.end.max(other.end),
+    })
+  }
+
+  pub fn start_time(&self) -> Duration {
+    self.frame_rate.duration_of(self.start)
+  }
+
+  pub fn end_time(&self) -> Duration {
+    self.frame_rate.duration_of(self.end)
+  }
+
+  pub fn time_range(&self) -> Range<Duration> {
+    self.start_time()..self.end_time()
+  }
+
+  /// Map the range to `frame_rate`.
+  ///
+  /// Fails when the mapped range would be empty, which can happen with
+  /// [`RangeRounding::Inward`] or [`RangeRounding::Nearest`].
+  pub fn to_rate(&self, frame_rate: FrameRate, rounding: RangeRounding) -> Result<Self, Error> {
+    let (start_rounding, end_rounding) = rounding.boundaries();
+
+    Self::new(
+      frame_rate,
+      self
+        .frame_rate
+        .convert_frame(self.start, frame_rate, start_rounding),
+      self
+        .frame_rate
+        .convert_frame(self.end, frame_rate, end_rounding),
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn new() {
+    let range = ClipRange::new(FrameRate::_25_00, 10, 35).unwrap();
+    assert_eq!(range.duration(), 25);
+    assert!(range.contains(10));
+    assert!(range.contains(34));
+    assert!(!range.contains(35));
+
+    assert_eq!(
+      ClipRange::new(FrameRate::_25_00, 35, 35),
+      Err(Error::InvalidClipRange { start: 35, end: 35 })
+    );
+    assert_eq!(
+      ClipRange::new(FrameRate::_25_00, 40, 35),
+      Err(Error::InvalidClipRange { start: 40, end: 35 })
+    );
+  }
+
+  #[test]
+  fn intersection_and_union() {
+    let a = ClipRange::new(FrameRate::_24_00, 0, 48).unwrap();
+    let b = ClipRange::new(FrameRate::_24_00, 24, 72).unwrap();
+    let c = ClipRange::new(FrameRate::_24_00, 72, 96).unwrap();
+
+    assert!(a.overlaps(&b));
+    assert!(!b.overlaps(&c));
+    assert_eq!(
+      a.intersection(&b),
+      Some(ClipRange::new(FrameRate::_24_00, 24, 48).unwrap())
+    );
+    assert_eq!(b.intersection(&c), None);
+
+    assert_eq!(
+      a.union(&b),
+      Some(ClipRange::new(FrameRate::_24_00, 0, 72).unwrap())
+    );
+    assert_eq!(
+      b.union(&c),
+      Some(ClipRange::new(FrameRate::_24_00, 24, 96).unwrap())
+    );
+    assert_eq!(a.union(&c), None);
+
+    let other_rate = ClipRange::new(FrameRate::_25_00, 0, 48).unwrap();
+    assert!(!a.overlaps(&other_rate));
+    assert_eq!(a.union(&other_rate), None);
+  }
+
+  #[test]
+  fn to_rate() {
+    let range = ClipRange::new(FrameRate::_25_00, 10, 35).unwrap();
+
+    let outward = range
+      .to_rate(FrameRate::_23_97, RangeRounding::Outward)
+      .unwrap();
+    assert_eq!((outward.start(), outward.end()), (9, 34));
+    assert!(outward.start_time() <= range.start_time());
+    assert!(outward.end_time() >= range.end_time());
+
+    let inward = range
+      .to_rate(FrameRate::_23_97, RangeRounding::Inward)
+      .unwrap();
+    assert_eq!((inward.start(), inward.end()), (10, 33));
+    assert!(inward.start_time() >= range.start_time());
+    assert!(inward.end_time() <= range.end_time());
+
+    let nearest = range
+      .to_rate(FrameRate::_23_97, RangeRounding::Nearest)
+      .unwrap();
+    assert_eq!((nearest.start(), nearest.end()), (10, 34));
+
+    let exact = range
+      .to_rate(FrameRate::_50_00, RangeRounding::Inward)
+      .unwrap();
+    assert_eq!((exact.start(), exact.end()), (20, 70));
+
+    let single = ClipRange::new(FrameRate::_60_00, 1, 2).unwrap();
+    assert_eq!(
+      single.to_rate(FrameRate::_24_00, RangeRounding::Inward),
+      Err(Error::InvalidClipRange { start: 1, end: 0 })
+    );
+    assert_eq!(
+      single
+        .to_rate(FrameRate::_24_00, RangeRounding::Outward)
+        .unwrap(),
+      ClipRange::new(FrameRate::_24_00, 0, 1).unwrap()
+    );
+  }
+
+  #[test]
+  fn time_range() {
+    let range = ClipRange::new(FrameRate::_29_97, 30, 60).unwrap();
+    assert_eq!(
+      range.time_range(),
+      Duration::from_millis(1001)..Duration::from_millis(2002)
+    );
+
+    let range = ClipRange::new(FrameRate::_23_97, 1, 2).unwrap();
+    assert_eq!(range.start_time(), Duration::from_nanos(41_708_333));
+    assert_eq!(range.end_time(), Duration::from_nanos(83_416_667));
+  }
+}
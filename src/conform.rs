@@ -0,0 +1,148 @@
+use crate::FrameRate;
+use std::time::Duration;
+
+/// Statistics of a [`ConformPlan`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ConformStats {
+  /// Output frames repeating the source frame of the previous output frame.
+  pub duplicated: usize,
+  /// Source frames never shown.
+  pub dropped: usize,
+  /// Largest distance between an output frame time and the timestamp of the
+  /// source frame it shows.
+  pub max_error: Duration,
+  /// Average of that distance, the judder introduced by the conform.
+  pub mean_error: Duration,
+}
+
+/// Schedule conforming variable frame rate timestamps to a constant rate, see
+/// [`FrameRate::plan_conform`].
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ConformPlan {
+  /// Index of the source frame shown by each output frame.
+  pub sources: Vec<usize>,
+  pub stats: ConformStats,
+}
+
+impl FrameRate {
+  /// Plans the conform of source frames at `timestamps`, in ascending order,
+  /// to this constant rate.
+  ///
+  /// Output frames start at the first timestamp and cover up to the last one.
+  /// Each output frame shows the source frame with the nearest timestamp, the
+  /// earlier one on ties. At 0 fps, the only output frame shows the first
+  /// source frame.
+  ///
+  /// `None` when `timestamps` are not in ascending order, or when the output
+  /// frames cannot be allocated.
+  pub fn plan_conform(&self, timestamps: &[Duration]) -> Option<ConformPlan> {
+    let (Some(first), Some(last)) = (timestamps.first(), timestamps.last()) else {
+      return Some(ConformPlan::default());
+    };
+    let span = last.checked_sub(*first)?;
+    if timestamps.windows(2).any(|pair| pair[0] > pair[1]) {
+      return None;
+    }
+    let output_frames = self
+      .duration_to_frames(span, crate::Rounding::Floor)
+      .saturating_add(1);
+
+    let mut sources = Vec::new();
+    sources
+      .try_reserve_exact(usize::try_from(output_frames).ok()?)
+      .ok()?;
+    let mut stats = ConformStats::default();
+    let mut total_error = Duration::ZERO;
+    let mut source = 0;
+    let mut shown = 0;
+
+    for frame in 0..output_frames {
+      let time = first.saturating_add(self.duration_of(frame));
+      while source + 1 < timestamps.len()
+        && timestamps[source + 1].abs_diff(time) < timestamps[source].abs_diff(time)
+      {
+        source += 1;
+      }
+
+      if sources.last() == Some(&source) {
+        stats.duplicated += 1;
+      } else {
+        shown += 1;
+      }
+      let error = timestamps[source].abs_diff(time);
+      stats.max_error = stats.max_error.max(error);
+      total_error = total_error.saturating_add(error);
+      sources.push(source);
+    }
+
+    stats.dropped = timestamps.len() - shown;
+    let mean_nanos = total_error.as_nanos() / sources.len() as u128;
+    stats.mean_error = Duration::new(
+      u64::try_from(mean_nanos / 1_000_000_000).expect("mean below the largest error"),
+      (mean_nanos % 1_000_000_000) as u32,
+    );
+    Some(ConformPlan { sources, stats })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn millis(values: &[u64]) -> Vec<Duration> {
+    values.iter().copied().map(Duration::from_millis).collect()
+  }
+
+  #[test]
+  fn constant_source() {
+    let timestamps: Vec<_> = (0..50)
+      .map(|frame| FrameRate::_25_00.duration_of(frame))
+      .collect();
+    let plan = FrameRate::_25_00.plan_conform(&timestamps).unwrap();
+    assert_eq!(plan.sources, (0..50).collect::<Vec<_>>());
+    assert_eq!(plan.stats, ConformStats::default());
+  }
+
+  #[test]
+  fn duplicates_and_drops() {
+    // A screen recording stalling then catching up.
+    let timestamps = millis(&[0, 40, 200, 210, 220, 240]);
+    let plan = FrameRate::_25_00.plan_conform(&timestamps).unwrap();
+
+    assert_eq!(plan.sources, [0, 1, 1, 1, 2, 2, 5]);
+    assert_eq!(plan.stats.duplicated, 3);
+    assert_eq!(plan.stats.dropped, 2);
+    assert_eq!(plan.stats.max_error, Duration::from_millis(80));
+    assert_eq!(plan.stats.mean_error, Duration::from_millis(160) / 7);
+  }
+
+  #[test]
+  fn empty() {
+    assert_eq!(
+      FrameRate::_30_00.plan_conform(&[]),
+      Some(ConformPlan::default())
+    );
+  }
+
+  #[test]
+  fn invalid() {
+    assert_eq!(FrameRate::_25_00.plan_conform(&millis(&[40, 0])), None);
+    assert_eq!(
+      FrameRate::_25_00.plan_conform(&millis(&[0, 80, 40, 120])),
+      None
+    );
+    assert_eq!(
+      FrameRate::_120_00.plan_conform(&[Duration::ZERO, Duration::MAX]),
+      None
+    );
+  }
+
+  #[test]
+  fn zero_frame_rate() {
+    let timestamps = millis(&[1000, 1040, 1080]);
+    let plan = FrameRate::new(0, 1).plan_conform(&timestamps).unwrap();
+    assert_eq!(plan.sources, [0]);
+    assert_eq!(plan.stats.dropped, 2);
+    assert_eq!(plan.stats.max_error, Duration::ZERO);
+  }
+}
@@ -0,0 +1,106 @@
+//! Round-trip checks for third-party frame rate codecs, such as database or
+//! wire encodings, meant to be called from their test suites.
+
+use crate::{FrameRate, Ratio};
+use std::fmt::Debug;
+
+/// Frame rates exercising every standard rate and the edges of custom ones.
+pub fn sample_rates() -> Vec<FrameRate> {
+  FrameRate::standard_rates()
+    .chain([
+      FrameRate::new(1, 1),
+      FrameRate::new(1, 10),
+      FrameRate::new(48, 1),
+      FrameRate::new(48000, 1001),
+      FrameRate::new(15000, 1001),
+      FrameRate::new(25, 2),
+      FrameRate::new(u32::MAX, 1),
+      FrameRate::new(1, u32::MAX),
+      FrameRate::new(u32::MAX, u32::MAX - 1),
+    ])
+    .collect()
+}
+
+/// Asserts that `decode(encode(rate))` gives back every sample rate exactly.
+///
+/// ```
+/// use frame_rate::{FrameRate, conformance};
+///
+/// conformance::assert_round_trip(
+///   |frame_rate: FrameRate| serde_json::to_string(&frame_rate).unwrap(),
+///   |json: String| serde_json::from_str::<FrameRate>(&json),
+/// );
+/// ```
+///
+/// # Panics
+///
+/// Panics on the first rate failing to decode or decoding to another rate.
+pub fn assert_round_trip<T, E: Debug>(
+  encode: impl Fn(FrameRate) -> T,
+  decode: impl Fn(T) -> Result<FrameRate, E>,
+) {
+  for frame_rate in sample_rates() {
+    match decode(encode(frame_rate)) {
+      Ok(decoded) => assert_eq!(
+        Ratio::from(decoded),
+        Ratio::from(frame_rate),
+        "{frame_rate:?} decoded as {decoded:?}"
+      ),
+      Err(error) => panic!("{frame_rate:?} failed to decode: {error:?}"),
+    }
+  }
+}
+
+/// Asserts that `decode(encode(rate))` is within `tolerance` frames per
+/// second of every sample rate, for codecs storing a float.
+///
+/// # Panics
+///
+/// Panics on the first rate failing to decode or decoding too far off.
+pub fn assert_approx_round_trip<T, E: Debug>(
+  encode: impl Fn(FrameRate) -> T,
+  decode: impl Fn(T) -> Result<FrameRate, E>,
+  tolerance: f64,
+) {
+  for frame_rate in sample_rates() {
+    match decode(encode(frame_rate)) {
+      Ok(decoded) => assert!(
+        decoded.approx_eq(f64::from(frame_rate), tolerance),
+        "{frame_rate:?} decoded as {decoded:?}"
+      ),
+      Err(error) => panic!("{frame_rate:?} failed to decode: {error:?}"),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::FrameRateString;
+
+  #[test]
+  fn round_trip() {
+    assert_round_trip(
+      |frame_rate| serde_json::to_value(FrameRateString(frame_rate)).unwrap(),
+      |value| serde_json::from_value::<FrameRateString>(value).map(FrameRate::from),
+    );
+    assert_round_trip(<(u32, u32)>::from, FrameRate::try_from);
+  }
+
+  #[test]
+  fn approx_round_trip() {
+    assert_approx_round_trip(
+      f64::from,
+      |fps| FrameRate::new_u64((fps * 1e3).round() as u64, 1000),
+      1e-3,
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "decoded as")]
+  fn lossy() {
+    assert_round_trip(f64::from, |fps| {
+      FrameRate::new_u64((fps * 1e3).round() as u64, 1000)
+    });
+  }
+}
@@ -0,0 +1,112 @@
+use crate::{ClipRange, FrameRate, Rounding, Timecode};
+
+/// Break between two consecutive segments, see
+/// [`FrameRate::check_continuity`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Discontinuity {
+  /// Frames missing between the end of segment `segment - 1` and the start of
+  /// segment `segment`.
+  Gap { segment: usize, range: ClipRange },
+  /// Frames of segment `segment` already covered by segment `segment - 1`.
+  Overlap { segment: usize, range: ClipRange },
+}
+
+impl FrameRate {
+  /// Checks that `(start_frame, frame_count)` segments at this rate follow each
+  /// other without gaps or overlaps, in the given order.
+  ///
+  /// Returns every discontinuity found, an empty list meaning the segments can
+  /// be stitched as is.
+  pub fn check_continuity(&self, segments: &[(u64, u64)]) -> Vec<Discontinuity> {
+    segments
+      .windows(2)
+      .enumerate()
+      .filter_map(|(index, pair)| {
+        let (previous_start, previous_count) = pair[0];
+        let (start, count) = pair[1];
+        let previous_end = previous_start.saturating_add(previous_count);
+        let segment = index + 1;
+
+        if start > previous_end {
+          let range = ClipRange::new(*self, previous_end, start).ok()?;
+          Some(Discontinuity::Gap { segment, range })
+        } else {
+          let end = previous_end.min(start.saturating_add(count));
+          let range = ClipRange::new(*self, start, end).ok()?;
+          Some(Discontinuity::Overlap { segment, range })
+        }
+      })
+      .collect()
+  }
+
+  /// Like [`FrameRate::check_continuity`], with segments starting at a
+  /// timecode. Timecodes at another rate are mapped to the nearest frame at
+  /// this rate.
+  pub fn check_timecode_continuity(&self, segments: &[(Timecode, u64)]) -> Vec<Discontinuity> {
+    let segments: Vec<_> = segments
+      .iter()
+      .map(|(timecode, count)| {
+        let start =
+          timecode
+            .frame_rate()
+            .convert_frame(timecode.to_frames(), *self, Rounding::Nearest);
+        (start, *count)
+      })
+      .collect();
+    self.check_continuity(&segments)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn continuous() {
+    let segments = [(0, 100), (100, 50), (150, 1)];
+    assert!(FrameRate::_25_00.check_continuity(&segments).is_empty());
+    assert!(FrameRate::_25_00.check_continuity(&[]).is_empty());
+  }
+
+  #[test]
+  fn gaps_and_overlaps() {
+    let frame_rate = FrameRate::_29_97;
+    let segments = [(0, 100), (110, 50), (150, 20), (170, 0), (165, 10)];
+
+    assert_eq!(
+      frame_rate.check_continuity(&segments),
+      vec![
+        Discontinuity::Gap {
+          segment: 1,
+          range: ClipRange::new(frame_rate, 100, 110).unwrap(),
+        },
+        Discontinuity::Overlap {
+          segment: 2,
+          range: ClipRange::new(frame_rate, 150, 160).unwrap(),
+        },
+        Discontinuity::Overlap {
+          segment: 4,
+          range: ClipRange::new(frame_rate, 165, 170).unwrap(),
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn timecodes() {
+    let frame_rate = FrameRate::_25_00;
+    let segments = [
+      (Timecode::parse(frame_rate, "00:00:00:00").unwrap(), 100),
+      (Timecode::parse(frame_rate, "00:00:04:00").unwrap(), 50),
+      // Frame 160 at 25 fps.
+      (Timecode::new(FrameRate::_50_00, 320).unwrap(), 10),
+    ];
+    assert_eq!(
+      frame_rate.check_timecode_continuity(&segments),
+      vec![Discontinuity::Gap {
+        segment: 2,
+        range: ClipRange::new(frame_rate, 150, 160).unwrap(),
+      }]
+    );
+  }
+}
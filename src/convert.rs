@@ -0,0 +1,107 @@
+use crate::{Error, FrameRate, Ratio};
+
+/// Checked `(num, den)` conversion, rejecting a zero denominator.
+impl TryFrom<(u32, u32)> for FrameRate {
+  type Error = Error;
+
+  fn try_from((num, den): (u32, u32)) -> Result<Self, Self::Error> {
+    Self::try_new(num, den)
+  }
+}
+
+/// Checked `[num, den]` conversion, rejecting a zero denominator.
+impl TryFrom<[u32; 2]> for FrameRate {
+  type Error = Error;
+
+  fn try_from([num, den]: [u32; 2]) -> Result<Self, Self::Error> {
+    Self::try_new(num, den)
+  }
+}
+
+/// Reduced `(num, den)`.
+impl From<FrameRate> for (u32, u32) {
+  fn from(frame_rate: FrameRate) -> Self {
+    Ratio::<u32>::from(frame_rate).into_raw()
+  }
+}
+
+/// Reduced `[num, den]`.
+impl From<FrameRate> for [u32; 2] {
+  fn from(frame_rate: FrameRate) -> Self {
+    let (num, den) = frame_rate.into();
+    [num, den]
+  }
+}
+
+/// Checked conversion of signed rationals, as handed out by FFmpeg, rejecting
+/// negative values and a zero denominator.
+impl TryFrom<(i32, i32)> for FrameRate {
+  type Error = Error;
+
+  fn try_from((num, den): (i32, i32)) -> Result<Self, Self::Error> {
+    if num != 0 && (num < 0) != (den < 0) {
+      return Err(Error::NegativeFrameRate { num, den });
+    }
+    Self::try_new(num.unsigned_abs(), den.unsigned_abs())
+  }
+}
+
+/// Checked conversion of signed rationals, rejecting negative values and a
+/// zero denominator.
+impl TryFrom<Ratio<i32>> for FrameRate {
+  type Error = Error;
+
+  fn try_from(ratio: Ratio<i32>) -> Result<Self, Self::Error> {
+    Self::try_from(ratio.into_raw())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn tuple() {
+    assert_eq!(FrameRate::try_from((30000u32, 1001)), Ok(FrameRate::_29_97));
+    assert_eq!(FrameRate::try_from((50u32, 2)), Ok(FrameRate::_25_00));
+    assert_eq!(FrameRate::try_from((25u32, 0)), Err(Error::ZeroDenominator));
+    assert_eq!(<(u32, u32)>::from(FrameRate::_59_94), (60000, 1001));
+    assert_eq!(<(u32, u32)>::from(FrameRate::new(48, 2)), (24, 1));
+  }
+
+  #[test]
+  fn array() {
+    assert_eq!(FrameRate::try_from([24000, 1001]), Ok(FrameRate::_23_97));
+    assert_eq!(FrameRate::try_from([1, 0]), Err(Error::ZeroDenominator));
+    assert_eq!(<[u32; 2]>::from(FrameRate::_120_00), [120, 1]);
+  }
+
+  #[test]
+  fn signed() {
+    assert_eq!(FrameRate::try_from((30000i32, 1001)), Ok(FrameRate::_29_97));
+    assert_eq!(FrameRate::try_from((-25, -1)), Ok(FrameRate::_25_00));
+    assert_eq!(FrameRate::try_from((0, -1)), Ok(FrameRate::new(0, 1)));
+    assert_eq!(
+      FrameRate::try_from((-25, 1)),
+      Err(Error::NegativeFrameRate { num: -25, den: 1 })
+    );
+    assert_eq!(
+      FrameRate::try_from((25, -1)),
+      Err(Error::NegativeFrameRate { num: 25, den: -1 })
+    );
+    assert_eq!(FrameRate::try_from((25i32, 0)), Err(Error::ZeroDenominator));
+    assert_eq!(
+      FrameRate::try_from((i32::MIN, -1)),
+      Ok(FrameRate::new(1 << 31, 1))
+    );
+
+    assert_eq!(
+      FrameRate::try_from(Ratio::new(60000i32, 1001)),
+      Ok(FrameRate::_59_94)
+    );
+    assert_eq!(
+      FrameRate::try_from(Ratio::new(24i32, -1)),
+      Err(Error::NegativeFrameRate { num: -24, den: 1 })
+    );
+  }
+}
@@ -0,0 +1,367 @@
+use crate::{FrameRate, Rounding};
+use std::time::Duration;
+
+/// Outcome of feeding a hardware frame number to a [`FrameCounter`].
+///
+/// Frame numbers in events are extended past the counter width, so they keep
+/// increasing across wraps.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FrameCounterEvent {
+  /// First frame received, or first after [`FrameCounter::reset`].
+  First { frame: u64 },
+  /// The expected frame.
+  InOrder { frame: u64 },
+  /// `missing` frames were skipped before `frame`.
+  Gap { frame: u64, missing: u64 },
+  /// A frame already passed, duplicated or reordered.
+  Late { frame: u64 },
+  /// A jump larger than the re-sync threshold, the counter restarted from
+  /// `frame`.
+  Resync { frame: u64 },
+}
+
+/// Running totals of a [`FrameCounter`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct FrameCounterStats {
+  pub received: u64,
+  pub dropped: u64,
+  pub late: u64,
+  pub wraps: u64,
+  pub resyncs: u64,
+}
+
+impl FrameCounterStats {
+  /// Fraction of expected frames that never arrived.
+  pub fn drop_ratio(&self) -> f64 {
+    let expected = self.received - self.late + self.dropped;
+    if expected == 0 {
+      return 0.0;
+    }
+    self.dropped as f64 / expected as f64
+  }
+}
+
+/// Tracks frame numbers from a wrapping hardware counter (e.g. 16 or 24 bits
+/// on SDI and ST 2110 receivers), detecting wraps, gaps and re-syncs.
+///
+/// A forward jump up to the re-sync threshold counts as dropped frames, a
+/// backward jump up to the threshold as a late frame, anything further away as
+/// a re-sync. The threshold defaults to one second of frames.
+///
+/// With the `metrics` feature, every push is reported through the `metrics`
+/// facade under the names of the `*_METRIC` constants, labelled with the
+/// frame rate.
+#[derive(Clone, Debug)]
+pub struct FrameCounter {
+  frame_rate: FrameRate,
+  bits: u32,
+  resync_threshold: u64,
+  expected: Option<u64>,
+  stats: FrameCounterStats,
+  #[cfg(feature = "metrics")]
+  labels: Vec<metrics::Label>,
+}
+
+impl FrameCounter {
+  /// # Panics
+  ///
+  /// Panics if `bits` is not in `1..=64`.
+  pub fn new(frame_rate: FrameRate, bits: u32) -> Self {
+    assert!(
+      (1..=64).contains(&bits),
+      "counter width must be 1 to 64 bits"
+    );
+    Self {
+      frame_rate,
+      bits,
+      resync_threshold: frame_rate
+        .duration_to_frames(Duration::from_secs(1), Rounding::Ceil)
+        .max(1),
+      expected: None,
+      stats: FrameCounterStats::default(),
+      #[cfg(feature = "metrics")]
+      labels: vec![metrics::Label::new("frame_rate", frame_rate.to_string())],
+    }
+  }
+
+  /// Largest jump, in frames, still treated as a gap or a late frame.
+  pub fn with_resync_threshold(mut self, frames: u64) -> Self {
+    self.resync_threshold = frames;
+    self
+  }
+
+  pub fn frame_rate(&self) -> FrameRate {
+    self.frame_rate
+  }
+
+  pub fn resync_threshold(&self) -> u64 {
+    self.resync_threshold
+  }
+
+  pub fn stats(&self) -> FrameCounterStats {
+    self.stats
+  }
+
+  /// Extended number of the next expected frame.
+  pub fn expected(&self) -> Option<u64> {
+    self.expected
+  }
+
+  /// Forgets the expected frame, keeping the statistics.
+  pub fn reset(&mut self) {
+    self.expected = None;
+  }
+
+  fn mask(&self) -> u64 {
+    u64::MAX >> (64 - self.bits)
+  }
+
+  /// Feeds a raw counter value, only its low `bits` bits are used.
+  pub fn push(&mut self, raw: u64) -> FrameCounterEvent {
+    #[cfg(feature = "metrics")]
+    let before = self.stats;
+    let event = self.count(raw);
+    #[cfg(feature = "metrics")]
+    self.record(before, &event);
+    event
+  }
+
+  fn count(&mut self, raw: u64) -> FrameCounterEvent {
+    let mask = self.mask();
+    let raw = raw & mask;
+    self.stats.received += 1;
+
+    let Some(expected) = self.expected else {
+      self.expected = Some(raw.wrapping_add(1));
+      return FrameCounterEvent::First { frame: raw };
+    };
+
+    let forward = raw.wrapping_sub(expected) & mask;
+    let backward = expected.wrapping_sub(raw) & mask;
+
+    let (frame, event) = if forward == 0 {
+      (expected, FrameCounterEvent::InOrder { frame: expected })
+    } else if forward <= self.resync_threshold {
+      self.stats.dropped += forward;
+      let frame = expected.wrapping_add(forward);
+      let missing = forward;
+      (frame, FrameCounterEvent::Gap { frame, missing })
+    } else if backward <= self.resync_threshold {
+      self.stats.late += 1;
+      return FrameCounterEvent::Late {
+        frame: expected.wrapping_sub(backward),
+      };
+    } else {
+      self.stats.resyncs += 1;
+      let frame = (expected & !mask) | raw;
+      (frame, FrameCounterEvent::Resync { frame })
+    };
+
+    if self.bits < 64 && frame >> self.bits > expected.wrapping_sub(1) >> self.bits {
+      self.stats.wraps += 1;
+    }
+    self.expected = Some(frame.wrapping_add(1));
+    event
+  }
+}
+
+#[cfg(feature = "metrics")]
+impl FrameCounter {
+  /// Counter of frames received, late ones included.
+  pub const RECEIVED_METRIC: &str = "frame_rate_frames_received_total";
+  /// Counter of frames that never arrived.
+  pub const DROPPED_METRIC: &str = "frame_rate_frames_dropped_total";
+  /// Counter of frames arriving after a later one.
+  pub const LATE_METRIC: &str = "frame_rate_frames_late_total";
+  /// Counter of hardware counter wraps.
+  pub const WRAPS_METRIC: &str = "frame_rate_counter_wraps_total";
+  /// Counter of re-syncs.
+  pub const RESYNCS_METRIC: &str = "frame_rate_counter_resyncs_total";
+  /// Histogram of the frames missing at each gap.
+  pub const GAP_METRIC: &str = "frame_rate_gap_frames";
+
+  /// Adds `labels`, e.g. the stream or device name, to the reported metrics.
+  pub fn with_metrics_labels(mut self, labels: impl IntoIterator<Item = metrics::Label>) -> Self {
+    self.labels.extend(labels);
+    self
+  }
+
+  fn record(&self, before: FrameCounterStats, event: &FrameCounterEvent) {
+    let counters = [
+      (Self::RECEIVED_METRIC, self.stats.received - before.received),
+      (Self::DROPPED_METRIC, self.stats.dropped - before.dropped),
+      (Self::LATE_METRIC, self.stats.late - before.late),
+      (Self::WRAPS_METRIC, self.stats.wraps - before.wraps),
+      (Self::RESYNCS_METRIC, self.stats.resyncs - before.resyncs),
+    ];
+    for (name, increment) in counters {
+      if increment != 0 {
+        metrics::counter!(name, self.labels.iter()).increment(increment);
+      }
+    }
+    if let FrameCounterEvent::Gap { missing, .. } = event {
+      metrics::histogram!(Self::GAP_METRIC, self.labels.iter()).record(*missing as f64);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn in_order_with_wrap() {
+    let mut counter = FrameCounter::new(FrameRate::_25_00, 4);
+    assert_eq!(counter.push(14), FrameCounterEvent::First { frame: 14 });
+    assert_eq!(counter.push(15), FrameCounterEvent::InOrder { frame: 15 });
+    assert_eq!(counter.push(0), FrameCounterEvent::InOrder { frame: 16 });
+    assert_eq!(counter.push(1), FrameCounterEvent::InOrder { frame: 17 });
+
+    let stats = counter.stats();
+    assert_eq!(stats.received, 4);
+    assert_eq!(stats.wraps, 1);
+    assert_eq!(stats.drop_ratio(), 0.0);
+  }
+
+  #[test]
+  fn gaps_and_late_frames() {
+    let mut counter = FrameCounter::new(FrameRate::_29_97, 16);
+    assert_eq!(counter.resync_threshold(), 30);
+
+    counter.push(65_534);
+    assert_eq!(
+      counter.push(2),
+      FrameCounterEvent::Gap {
+        frame: 65_538,
+        missing: 3
+      }
+    );
+    assert_eq!(counter.push(1), FrameCounterEvent::Late { frame: 65_537 });
+    assert_eq!(
+      counter.push(3),
+      FrameCounterEvent::InOrder { frame: 65_539 }
+    );
+
+    let stats = counter.stats();
+    assert_eq!(stats.dropped, 3);
+    assert_eq!(stats.late, 1);
+    assert_eq!(stats.wraps, 1);
+    assert_eq!(stats.drop_ratio(), 0.5);
+  }
+
+  #[test]
+  fn resync() {
+    let mut counter = FrameCounter::new(FrameRate::_50_00, 24).with_resync_threshold(10);
+    counter.push(100);
+    assert_eq!(
+      counter.push(5000),
+      FrameCounterEvent::Resync { frame: 5000 }
+    );
+    assert_eq!(
+      counter.push(5001),
+      FrameCounterEvent::InOrder { frame: 5001 }
+    );
+    assert_eq!(counter.stats().resyncs, 1);
+    assert_eq!(counter.stats().dropped, 0);
+
+    counter.reset();
+    assert_eq!(counter.push(7), FrameCounterEvent::First { frame: 7 });
+  }
+
+  #[test]
+  fn full_width() {
+    let mut counter = FrameCounter::new(FrameRate::_60_00, 64);
+    counter.push(u64::MAX);
+    assert_eq!(counter.push(0), FrameCounterEvent::InOrder { frame: 0 });
+  }
+
+  #[cfg(feature = "metrics")]
+  #[test]
+  fn metrics() {
+    use metrics::{
+      Counter, Gauge, Histogram, HistogramFn, Key, KeyName, Metadata, Recorder, SharedString, Unit,
+    };
+    use std::collections::BTreeMap;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    #[derive(Default)]
+    struct Samples(Mutex<Vec<f64>>);
+
+    impl HistogramFn for Samples {
+      fn record(&self, value: f64) {
+        self.0.lock().unwrap().push(value);
+      }
+    }
+
+    #[derive(Default)]
+    struct TestRecorder {
+      counters: Mutex<BTreeMap<String, Arc<AtomicU64>>>,
+      histograms: Mutex<BTreeMap<String, Arc<Samples>>>,
+    }
+
+    fn name(key: &Key) -> String {
+      let labels: Vec<_> = key
+        .labels()
+        .map(|label| format!("{}={}", label.key(), label.value()))
+        .collect();
+      format!("{}{{{}}}", key.name(), labels.join(","))
+    }
+
+    impl Recorder for TestRecorder {
+      fn describe_counter(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+      fn describe_gauge(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+      fn describe_histogram(&self, _: KeyName, _: Option<Unit>, _: SharedString) {}
+
+      fn register_counter(&self, key: &Key, _: &Metadata<'_>) -> Counter {
+        let mut counters = self.counters.lock().unwrap();
+        Counter::from_arc(counters.entry(name(key)).or_default().clone())
+      }
+
+      fn register_gauge(&self, _: &Key, _: &Metadata<'_>) -> Gauge {
+        Gauge::noop()
+      }
+
+      fn register_histogram(&self, key: &Key, _: &Metadata<'_>) -> Histogram {
+        let mut histograms = self.histograms.lock().unwrap();
+        Histogram::from_arc(histograms.entry(name(key)).or_default().clone())
+      }
+    }
+
+    let recorder = TestRecorder::default();
+    metrics::with_local_recorder(&recorder, || {
+      let mut counter = FrameCounter::new(FrameRate::_29_97, 4)
+        .with_resync_threshold(4)
+        .with_metrics_labels([metrics::Label::new("stream", "cam-1")]);
+      for raw in [14, 15, 3, 2] {
+        counter.push(raw);
+      }
+    });
+
+    let counters: BTreeMap<_, _> = recorder
+      .counters
+      .lock()
+      .unwrap()
+      .iter()
+      .map(|(name, value)| (name.clone(), value.load(Ordering::Relaxed)))
+      .collect();
+    let labels = "{frame_rate=29.97,stream=cam-1}";
+    assert_eq!(
+      counters,
+      BTreeMap::from([
+        (format!("{}{labels}", FrameCounter::DROPPED_METRIC), 3),
+        (format!("{}{labels}", FrameCounter::LATE_METRIC), 1),
+        (format!("{}{labels}", FrameCounter::RECEIVED_METRIC), 4),
+        (format!("{}{labels}", FrameCounter::WRAPS_METRIC), 1),
+      ])
+    );
+    let histograms = recorder.histograms.lock().unwrap();
+    assert_eq!(
+      *histograms[&format!("{}{labels}", FrameCounter::GAP_METRIC)]
+        .0
+        .lock()
+        .unwrap(),
+      [3.0]
+    );
+  }
+}
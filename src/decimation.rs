@@ -0,0 +1,175 @@
+use crate::{FrameRate, Ratio};
+
+/// Schedule of the source frames kept when reducing a frame rate by dropping
+/// frames. Output frame `k` shows the source frame on screen at its start.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Decimation {
+  source: FrameRate,
+  target: FrameRate,
+  /// Source frames per output frame.
+  step: Ratio<u64>,
+}
+
+impl FrameRate {
+  /// Keeps one frame out of `n`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `n` is zero.
+  pub fn decimate_by(&self, n: u32) -> Decimation {
+    assert!(n != 0, "decimation by zero");
+    Decimation {
+      source: *self,
+      target: *self / n,
+      step: Ratio::from_integer(n.into()),
+    }
+  }
+
+  /// Drops frames to reach `target`, `None` unless `target` is positive and
+  /// not above this rate.
+  pub fn decimation_pattern(&self, target: FrameRate) -> Option<Decimation> {
+    let source = Ratio::<u32>::from(*self);
+    let ratio = Ratio::<u32>::from(target);
+    if *ratio.numer() == 0 || ratio > source {
+      return None;
+    }
+    Some(Decimation {
+      source: *self,
+      target,
+      step: Ratio::new(
+        u64::from(*source.numer()) * u64::from(*ratio.denom()),
+        u64::from(*source.denom()) * u64::from(*ratio.numer()),
+      ),
+    })
+  }
+}
+
+impl Decimation {
+  pub fn source(&self) -> FrameRate {
+    self.source
+  }
+
+  pub fn target(&self) -> FrameRate {
+    self.target
+  }
+
+  /// Whether dropping is regular, i.e. one frame kept out of a whole number.
+  pub fn is_integer(&self) -> bool {
+    self.step.is_integer()
+  }
+
+  /// Source frame shown as output frame `frame`, saturating at `u64::MAX`.
+  pub fn source_frame(&self, frame: u64) -> u64 {
+    let source =
+      u128::from(frame) * u128::from(*self.step.numer()) / u128::from(*self.step.denom());
+    u64::try_from(source).unwrap_or(u64::MAX)
+  }
+
+  /// Whether source frame `frame` is kept.
+  pub fn keeps(&self, frame: u64) -> bool {
+    let (numer, denom) = (
+      u128::from(*self.step.numer()),
+      u128::from(*self.step.denom()),
+    );
+    // Output frame starting at or after the start of `frame`.
+    let output = (u128::from(frame) * denom).div_ceil(numer);
+    output * numer / denom == u128::from(frame)
+  }
+
+  /// Keep (`true`) or drop (`false`) decision of each source frame over one
+  /// repetition of the schedule.
+  pub fn pattern(&self) -> Vec<bool> {
+    (0..*self.step.numer())
+      .map(|frame| self.keeps(frame))
+      .collect()
+  }
+
+  /// Lateness of each kept frame over one repetition of the schedule, in
+  /// source frame periods: how far the output frame start is past the start
+  /// of the source frame it shows. All zero for integer decimation.
+  pub fn errors(&self) -> Vec<Ratio<u64>> {
+    (0..*self.step.denom())
+      .map(|frame| self.step * frame - Ratio::from_integer(self.source_frame(frame)))
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn decimate_by() {
+    let decimation = FrameRate::_59_94.decimate_by(2);
+    assert_eq!(decimation.target(), FrameRate::_29_97);
+    assert!(decimation.is_integer());
+    assert_eq!(decimation.pattern(), [true, false]);
+    assert_eq!(decimation.errors(), [Ratio::from_integer(0)]);
+    assert_eq!(decimation.source_frame(10), 20);
+    assert!(decimation.keeps(20));
+    assert!(!decimation.keeps(21));
+  }
+
+  #[test]
+  fn rational() {
+    let decimation = FrameRate::_60_00
+      .decimation_pattern(FrameRate::_24_00)
+      .unwrap();
+    assert!(!decimation.is_integer());
+    assert_eq!(decimation.pattern(), [true, false, true, false, false]);
+    assert_eq!(
+      decimation.errors(),
+      [Ratio::from_integer(0), Ratio::new(1, 2)]
+    );
+    assert_eq!(
+      (0..4)
+        .map(|frame| decimation.source_frame(frame))
+        .collect::<Vec<_>>(),
+      [0, 2, 5, 7]
+    );
+
+    let decimation = FrameRate::_29_97
+      .decimation_pattern(FrameRate::_24_00)
+      .unwrap();
+    assert_eq!(decimation.pattern().len(), 1250);
+    assert_eq!(
+      decimation.pattern().iter().filter(|keep| **keep).count(),
+      1001
+    );
+    assert_eq!(decimation.errors().len(), 1001);
+  }
+
+  #[test]
+  fn largest_frames() {
+    let decimation = FrameRate::_60_00
+      .decimation_pattern(FrameRate::_24_00)
+      .unwrap();
+    let last = u64::MAX / 5 * 2;
+    assert_eq!(decimation.source_frame(last - 1), u64::MAX - 3);
+    assert_eq!(decimation.source_frame(last), u64::MAX);
+    assert_eq!(decimation.source_frame(last + 1), u64::MAX);
+    assert_eq!(decimation.source_frame(u64::MAX), u64::MAX);
+    assert!(decimation.keeps(u64::MAX));
+    assert!(!decimation.keeps(u64::MAX - 1));
+    assert!(decimation.keeps(u64::MAX - 3));
+  }
+
+  #[test]
+  fn invalid() {
+    assert_eq!(
+      FrameRate::_24_00.decimation_pattern(FrameRate::_25_00),
+      None
+    );
+    assert_eq!(
+      FrameRate::_24_00.decimation_pattern(FrameRate::new(0, 1)),
+      None
+    );
+    assert_eq!(
+      FrameRate::_25_00
+        .decimation_pattern(FrameRate::_25_00)
+        .unwrap()
+        .pattern(),
+      [true]
+    );
+  }
+}
@@ -0,0 +1,135 @@
+use crate::{Error, FrameRate, FrameRateParseError, ParseErrorKind, Ratio, SnapProfile};
+use miette::{Diagnostic, LabeledSpan};
+use std::fmt::Display;
+
+impl Diagnostic for Error {
+  fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+    let code = match self {
+      Self::FrameRateOutOfRange { .. } => "frame_rate::out_of_range",
+      Self::InvalidAv1TimingInfo(_) => "frame_rate::av1",
+      Self::InvalidClipRange { .. } => "frame_rate::clip_range",
+      Self::InvalidDpxHeader(_) => "frame_rate::dpx",
+      Self::InvalidEdidDescriptor(_) => "frame_rate::edid",
+      Self::InvalidExrHeader(_) => "frame_rate::exr",
+      Self::InvalidFcpxmlFrameDuration(_) => "frame_rate::fcpxml",
+      Self::InvalidFrameCount(_) => "frame_rate::frame_count",
+      Self::InvalidOtioTime => "frame_rate::otio",
+      Self::InvalidRelativeRate(_) => "frame_rate::relative",
+      Self::InvalidTimecode(_) => "frame_rate::timecode",
+      Self::InvalidTimeRange { .. } => "frame_rate::time_range",
+      Self::InvalidTimeRemap(_) => "frame_rate::time_remap",
+      Self::InvalidVuiTiming(_) => "frame_rate::vui",
+      Self::NegativeFrameRate { .. } => "frame_rate::negative",
+      Self::NoDropFrame(_) => "frame_rate::no_drop_frame",
+      Self::NoNtscCounterpart(_) => "frame_rate::no_ntsc_counterpart",
+      Self::NotAnIntegerFrameRate(_) => "frame_rate::not_integer",
+      Self::TimeOutOfRange { .. } => "frame_rate::time_out_of_range",
+      Self::UnorderedRateChange { .. } => "frame_rate::unordered_rate_change",
+      Self::ZeroDenominator => "frame_rate::zero_denominator",
+    };
+    Some(Box::new(code))
+  }
+
+  fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+    match self {
+      Self::FrameRateOutOfRange { num, den } => suggestion(*num as f64 / *den as f64),
+      Self::InvalidFrameCount(_) => Some(Box::new(
+        "expected frames (\"250f\"), a duration (\"2m30s\") or a timecode (\"00:00:05:12\")",
+      )),
+      Self::InvalidRelativeRate(_) => Some(Box::new(
+        "expected a percentage (\"200%\"), a factor (\"2x\") or a rate (\"48fps @ 24 base\")",
+      )),
+      Self::InvalidTimecode(_) => Some(Box::new(
+        "expected HH:MM:SS:FF, or HH:MM:SS;FF for drop-frame",
+      )),
+      Self::NoDropFrame(_) => Some(Box::new(
+        "drop-frame timecode only exists at 29.97 and 59.94, use `:` separators",
+      )),
+      Self::NoNtscCounterpart(_) => Some(Box::new(
+        "1000/1001 counterparts only exist for 24, 25, 30 and 60 fps",
+      )),
+      Self::NotAnIntegerFrameRate(frame_rate) => frame_rate
+        .to_integer_variant()
+        .ok()
+        .map(|integer| Box::new(format!("did you mean {integer}?")) as Box<dyn Display>),
+      Self::ZeroDenominator => Some(Box::new(ZERO_DENOMINATOR_HELP)),
+      _ => None,
+    }
+  }
+}
+
+impl Diagnostic for FrameRateParseError {
+  fn code<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+    Some(Box::new("frame_rate::parse"))
+  }
+
+  fn help<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
+    let help = match self.kind {
+      ParseErrorKind::Empty | ParseErrorKind::InvalidCharacter | ParseErrorKind::MissingNumber => {
+        "expected a ratio such as 30000/1001, an integer such as 25 or a decimal such as 29.97"
+      }
+      ParseErrorKind::MissingUnit => "rates below 1 fps are written \"1 frame / 10 s\"",
+      ParseErrorKind::Overflow => "numerator and denominator must fit in 32 bits",
+      ParseErrorKind::OutOfRange => "use an exact ratio such as 30000/1001",
+      ParseErrorKind::ZeroDenominator => ZERO_DENOMINATOR_HELP,
+    };
+    Some(Box::new(help))
+  }
+
+  fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+    Some(Box::new(std::iter::once(LabeledSpan::at(
+      self.span.clone(),
+      self.kind.to_string(),
+    ))))
+  }
+}
+
+const ZERO_DENOMINATOR_HELP: &str = "the denominator must be at least 1, as in 30000/1001";
+
+/// Points at the standard rate closest to `fps`, if any.
+fn suggestion(fps: f64) -> Option<Box<dyn Display>> {
+  let ratio = Ratio::<u32>::from(FrameRate::snap(fps, &SnapProfile::DEFAULT)?);
+  Some(Box::new(format!(
+    "did you mean {}/{}?",
+    ratio.numer(),
+    ratio.denom()
+  )))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::parse_frame_rate;
+
+  #[test]
+  fn error() {
+    let error = FrameRate::new_u64(29_970_029_971, 1_000_000_000).unwrap_err();
+    assert_eq!(
+      error.code().unwrap().to_string(),
+      "frame_rate::out_of_range"
+    );
+    assert_eq!(
+      error.help().unwrap().to_string(),
+      "did you mean 30000/1001?"
+    );
+    assert_eq!(
+      Error::NotAnIntegerFrameRate(FrameRate::_29_97)
+        .help()
+        .unwrap()
+        .to_string(),
+      "did you mean 30?"
+    );
+    assert!(Error::InvalidOtioTime.help().is_none());
+  }
+
+  #[test]
+  fn parse_error() {
+    let error = parse_frame_rate("25/0").unwrap_err();
+    let labels: Vec<_> = error.labels().unwrap().collect();
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[0].offset(), 3);
+    assert_eq!(labels[0].len(), 1);
+    assert_eq!(labels[0].label(), Some("zero denominator"));
+    assert_eq!(error.help().unwrap().to_string(), ZERO_DENOMINATOR_HELP);
+  }
+}
@@ -0,0 +1,110 @@
+use crate::{FrameRate, SnapProfile};
+
+/// Integer refresh rates whose 1000/1001 variant Windows reports truncated,
+/// e.g. 59 for 59.94 Hz.
+const NTSC_REFRESH_RATES: [u32; 5] = [24, 30, 48, 60, 120];
+
+/// Refresh rates of common display modes.
+const REFRESH_RATES: [(u32, u32); 19] = [
+  (24000, 1001),
+  (24, 1),
+  (25, 1),
+  (30000, 1001),
+  (30, 1),
+  (48000, 1001),
+  (48, 1),
+  (50, 1),
+  (60000, 1001),
+  (60, 1),
+  (72, 1),
+  (75, 1),
+  (90, 1),
+  (100, 1),
+  (120000, 1001),
+  (120, 1),
+  (144, 1),
+  (165, 1),
+  (240, 1),
+];
+
+/// Largest relative distance to a known refresh rate still snapping to it,
+/// covering the 59.88 Hz reported for some 59.94 Hz modes.
+const TOLERANCE: f64 = 0.0015;
+
+impl FrameRate {
+  /// Refresh rate of a display from the value reported by the OS, e.g.
+  /// `dmDisplayFrequency` on Windows or `CGDisplayModeGetRefreshRate` on
+  /// macOS, to match content frame rates against.
+  ///
+  /// Windows truncates 1000/1001 rates (59 for 59.94 Hz), macOS and some EDID
+  /// timings round them (59.94, 59.88), all mapped to the exact rate. Returns
+  /// `None` for 0, which macOS reports for built-in panels.
+  pub fn match_display_rate(display_hz_reported: f64) -> Option<FrameRate> {
+    if !display_hz_reported.is_finite() || display_hz_reported <= 0.0 {
+      return None;
+    }
+    if display_hz_reported.fract() == 0.0 {
+      let hz = display_hz_reported as u32;
+      if NTSC_REFRESH_RATES.contains(&(hz + 1)) {
+        return Some(FrameRate::new((hz + 1) * 1000, 1001));
+      }
+    }
+
+    REFRESH_RATES
+      .iter()
+      .map(|(num, den)| FrameRate::new(*num, *den))
+      .map(|frame_rate| {
+        let distance = (f64::from(frame_rate) - display_hz_reported).abs() / display_hz_reported;
+        (frame_rate, distance)
+      })
+      .filter(|(_, distance)| *distance <= TOLERANCE)
+      .min_by(|(_, a), (_, b)| a.total_cmp(b))
+      .map(|(frame_rate, _)| frame_rate)
+      .or_else(|| FrameRate::from_f64(display_hz_reported, &SnapProfile::DEFAULT))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn match_display_rate() {
+    assert_eq!(
+      FrameRate::match_display_rate(59.94),
+      Some(FrameRate::_59_94)
+    );
+    assert_eq!(
+      FrameRate::match_display_rate(59.940),
+      Some(FrameRate::_59_94)
+    );
+    assert_eq!(
+      FrameRate::match_display_rate(59.88),
+      Some(FrameRate::_59_94)
+    );
+    assert_eq!(FrameRate::match_display_rate(59.0), Some(FrameRate::_59_94));
+    assert_eq!(FrameRate::match_display_rate(60.0), Some(FrameRate::_60_00));
+    assert_eq!(
+      FrameRate::match_display_rate(60.01),
+      Some(FrameRate::_60_00)
+    );
+    assert_eq!(FrameRate::match_display_rate(23.0), Some(FrameRate::_23_97));
+    assert_eq!(
+      FrameRate::match_display_rate(119.0),
+      Some(FrameRate::new(120000, 1001))
+    );
+    assert_eq!(
+      FrameRate::match_display_rate(144.0),
+      Some(FrameRate::new(144, 1))
+    );
+    assert_eq!(
+      FrameRate::match_display_rate(143.856),
+      Some(FrameRate::new(144, 1))
+    );
+    assert_eq!(
+      FrameRate::match_display_rate(165.5),
+      Some(FrameRate::new(331, 2))
+    );
+    assert_eq!(FrameRate::match_display_rate(0.0), None);
+  }
+}
@@ -0,0 +1,150 @@
+//! Frame rate fields of DPX (SMPTE 268M) file headers.
+
+use crate::{Error, FrameRate, Ratio, SnapProfile};
+
+const FILM_FRAME_RATE_OFFSET: usize = 1724;
+const TELEVISION_FRAME_RATE_OFFSET: usize = 1940;
+const HEADER_SIZE: usize = 2048;
+/// Value of fields left undefined by the writer.
+const UNDEFINED: u32 = 0xFFFF_FFFF;
+
+/// Frame rates found in the motion picture film and television headers.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct DpxFrameRates {
+  pub film: Option<FrameRate>,
+  pub television: Option<FrameRate>,
+}
+
+#[derive(Clone, Copy)]
+enum Endianness {
+  Big,
+  Little,
+}
+
+fn endianness(header: &[u8]) -> Result<Endianness, Error> {
+  if header.len() < HEADER_SIZE {
+    return Err(Error::InvalidDpxHeader("header is shorter than 2048 bytes"));
+  }
+
+  match &header[..4] {
+    b"SDPX" => Ok(Endianness::Big),
+    b"XPDS" => Ok(Endianness::Little),
+    _ => Err(Error::InvalidDpxHeader("missing magic number")),
+  }
+}
+
+fn read_frame_rate(header: &[u8], offset: usize, endianness: Endianness) -> Option<FrameRate> {
+  let bytes = header[offset..offset + 4].try_into().unwrap();
+  let bits = match endianness {
+    Endianness::Big => u32::from_be_bytes(bytes),
+    Endianness::Little => u32::from_le_bytes(bytes),
+  };
+
+  if bits == UNDEFINED {
+    return None;
+  }
+
+  FrameRate::from_f64(f64::from(f32::from_bits(bits)), &SnapProfile::DEFAULT)
+}
+
+fn write_frame_rate(header: &mut [u8], offset: usize, endianness: Endianness, fps: f32) {
+  let bytes = match endianness {
+    Endianness::Big => fps.to_be_bytes(),
+    Endianness::Little => fps.to_le_bytes(),
+  };
+  header[offset..offset + 4].copy_from_slice(&bytes);
+}
+
+/// Read the film and television frame rates of a DPX header.
+///
+/// Floating point values are snapped with [`SnapProfile::DEFAULT`]; undefined
+/// or invalid values are reported as `None`.
+pub fn read_frame_rates(header: &[u8]) -> Result<DpxFrameRates, Error> {
+  let endianness = endianness(header)?;
+
+  Ok(DpxFrameRates {
+    film: read_frame_rate(header, FILM_FRAME_RATE_OFFSET, endianness),
+    television: read_frame_rate(header, TELEVISION_FRAME_RATE_OFFSET, endianness),
+  })
+}
+
+/// Write `frame_rate` to both the film and television headers, using the
+/// endianness of the existing header.
+pub fn write_frame_rates(header: &mut [u8], frame_rate: FrameRate) -> Result<(), Error> {
+  let endianness = endianness(header)?;
+  let ratio = Ratio::<u32>::from(frame_rate);
+  let fps = (f64::from(*ratio.numer()) / f64::from(*ratio.denom())) as f32;
+
+  write_frame_rate(header, FILM_FRAME_RATE_OFFSET, endianness, fps);
+  write_frame_rate(header, TELEVISION_FRAME_RATE_OFFSET, endianness, fps);
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn header(magic: &[u8; 4]) -> Vec<u8> {
+    let mut header = vec![0xFF; HEADER_SIZE];
+    header[..4].copy_from_slice(magic);
+    header
+  }
+
+  #[test]
+  fn read_undefined() {
+    assert_eq!(
+      read_frame_rates(&header(b"SDPX")).unwrap(),
+      DpxFrameRates::default()
+    );
+  }
+
+  #[test]
+  fn read() {
+    let mut header = header(b"SDPX");
+    header[FILM_FRAME_RATE_OFFSET..FILM_FRAME_RATE_OFFSET + 4]
+      .copy_from_slice(&23.976_f32.to_be_bytes());
+    header[TELEVISION_FRAME_RATE_OFFSET..TELEVISION_FRAME_RATE_OFFSET + 4]
+      .copy_from_slice(&29.97_f32.to_be_bytes());
+
+    assert_eq!(
+      read_frame_rates(&header).unwrap(),
+      DpxFrameRates {
+        film: Some(FrameRate::_23_97),
+        television: Some(FrameRate::_29_97),
+      }
+    );
+  }
+
+  #[test]
+  fn write_round_trip() {
+    for magic in [b"SDPX", b"XPDS"] {
+      let mut header = header(magic);
+      write_frame_rates(&mut header, FrameRate::_59_94).unwrap();
+      assert_eq!(
+        read_frame_rates(&header).unwrap(),
+        DpxFrameRates {
+          film: Some(FrameRate::_59_94),
+          television: Some(FrameRate::_59_94),
+        }
+      );
+
+      write_frame_rates(&mut header, FrameRate::new(18, 1)).unwrap();
+      assert_eq!(
+        read_frame_rates(&header).unwrap().film,
+        Some(FrameRate::new(18, 1))
+      );
+    }
+  }
+
+  #[test]
+  fn invalid_header() {
+    assert_eq!(
+      read_frame_rates(&[0; 16]),
+      Err(Error::InvalidDpxHeader("header is shorter than 2048 bytes"))
+    );
+    assert_eq!(
+      read_frame_rates(&header(b"JPEG")),
+      Err(Error::InvalidDpxHeader("missing magic number"))
+    );
+  }
+}
@@ -0,0 +1,133 @@
+use crate::{Error, FrameRate, SnapProfile};
+
+/// Fields of an EDID detailed timing descriptor used to derive its rate.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DetailedTiming {
+  /// Pixel clock in units of 10 kHz.
+  pub pixel_clock: u16,
+  pub h_active: u16,
+  pub h_blanking: u16,
+  /// Lines per field for interlaced timings.
+  pub v_active: u16,
+  pub v_blanking: u16,
+  pub interlaced: bool,
+}
+
+impl DetailedTiming {
+  /// Decodes an 18-byte detailed timing descriptor.
+  pub fn from_bytes(bytes: &[u8; 18]) -> Result<Self, Error> {
+    let pixel_clock = u16::from_le_bytes([bytes[0], bytes[1]]);
+    if pixel_clock == 0 {
+      return Err(Error::InvalidEdidDescriptor(
+        "display descriptor, not a detailed timing",
+      ));
+    }
+    let twelve_bits = |low: u8, high: u8| u16::from(low) | u16::from(high) << 8;
+
+    Ok(Self {
+      pixel_clock,
+      h_active: twelve_bits(bytes[2], bytes[4] >> 4),
+      h_blanking: twelve_bits(bytes[3], bytes[4] & 0x0f),
+      v_active: twelve_bits(bytes[5], bytes[7] >> 4),
+      v_blanking: twelve_bits(bytes[6], bytes[7] & 0x0f),
+      interlaced: bytes[17] & 0x80 != 0,
+    })
+  }
+
+  /// Vertical refresh rate in Hz: frames per second for progressive timings,
+  /// fields per second for interlaced ones.
+  pub fn refresh_rate(&self) -> f64 {
+    let h_total = f64::from(self.h_active) + f64::from(self.h_blanking);
+    let mut v_total = f64::from(self.v_active) + f64::from(self.v_blanking);
+    if self.interlaced {
+      // Interlaced fields alternate between v_total and v_total + 1 lines.
+      v_total += 0.5;
+    }
+    f64::from(self.pixel_clock) * 10_000.0 / (h_total * v_total)
+  }
+
+  /// Frame rate snapped with `profile`, half the field rate for interlaced
+  /// timings. The 10 kHz pixel clock resolution makes 1000/1001 rates
+  /// inexact, e.g. 148.35 MHz for 1080p59.94 gives 59.9394 Hz, so pick a
+  /// profile whose tolerance covers it.
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip(profile), ret)
+  )]
+  pub fn frame_rate(&self, profile: &SnapProfile) -> Option<FrameRate> {
+    let refresh_rate = self.refresh_rate();
+    let frame_rate = if self.interlaced {
+      refresh_rate / 2.0
+    } else {
+      refresh_rate
+    };
+    FrameRate::from_f64(frame_rate, profile)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// 1920x1080p60, 148.5 MHz, 280 / 45 blanking.
+  const DTD_1080P60: [u8; 18] = [
+    0x02, 0x3a, 0x80, 0x18, 0x71, 0x38, 0x2d, 0x40, 0x58, 0x2c, 0x45, 0x00, 0x80, 0x38, 0x74, 0x00,
+    0x00, 0x1e,
+  ];
+
+  #[test]
+  fn from_bytes() {
+    let timing = DetailedTiming::from_bytes(&DTD_1080P60).unwrap();
+    assert_eq!(
+      timing,
+      DetailedTiming {
+        pixel_clock: 14850,
+        h_active: 1920,
+        h_blanking: 280,
+        v_active: 1080,
+        v_blanking: 45,
+        interlaced: false,
+      }
+    );
+    assert_eq!(timing.refresh_rate(), 60.0);
+    assert_eq!(
+      timing.frame_rate(&SnapProfile::DEFAULT),
+      Some(FrameRate::_60_00)
+    );
+
+    let mut display_descriptor = DTD_1080P60;
+    display_descriptor[..2].fill(0);
+    assert!(DetailedTiming::from_bytes(&display_descriptor).is_err());
+  }
+
+  #[test]
+  fn frame_rate() {
+    let timing = DetailedTiming {
+      pixel_clock: 14835,
+      h_active: 1920,
+      h_blanking: 280,
+      v_active: 1080,
+      v_blanking: 45,
+      interlaced: false,
+    };
+    assert_eq!(
+      timing.frame_rate(&SnapProfile::DEFAULT),
+      Some(FrameRate::_59_94)
+    );
+
+    // 1080i50: 74.25 MHz, 2640 pixels and 562.5 lines per field.
+    let timing = DetailedTiming {
+      pixel_clock: 7425,
+      h_active: 1920,
+      h_blanking: 720,
+      v_active: 540,
+      v_blanking: 22,
+      interlaced: true,
+    };
+    assert_eq!(timing.refresh_rate(), 50.0);
+    assert_eq!(
+      timing.frame_rate(&SnapProfile::DEFAULT),
+      Some(FrameRate::_25_00)
+    );
+  }
+}
@@ -0,0 +1,93 @@
+use crate::{FrameRate, Ratio, SnapProfile};
+
+/// Equivalence class of frame rates carrying the same broadcast rate, e.g.
+/// 30000/1001, 2997/100 and 29.97 read back from a float.
+///
+/// Rates within the [`SnapProfile::DEFAULT`] tolerance of a standard rate
+/// belong to the class of that rate, other rates only equal themselves.
+/// Hashable, so it can key the deduplication of metadata probed from
+/// several sources.
+///
+/// ```
+/// use frame_rate::{BroadcastEquivalence, FrameRate};
+///
+/// assert_eq!(
+///   BroadcastEquivalence::from(FrameRate::new(2997, 100)),
+///   BroadcastEquivalence::from(FrameRate::_29_97)
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct BroadcastEquivalence(Ratio<u32>);
+
+impl BroadcastEquivalence {
+  /// Representative of the class, the standard rate when there is one.
+  pub fn canonical(&self) -> FrameRate {
+    self.0.into()
+  }
+}
+
+impl From<FrameRate> for BroadcastEquivalence {
+  fn from(frame_rate: FrameRate) -> Self {
+    Self(frame_rate.broadcast_canonical().into())
+  }
+}
+
+impl FrameRate {
+  /// Standard rate this rate is broadcast equivalent to, or this rate if
+  /// there is none. See [`BroadcastEquivalence`].
+  pub fn broadcast_canonical(&self) -> FrameRate {
+    FrameRate::snap(self.into(), &SnapProfile::DEFAULT).unwrap_or(*self)
+  }
+
+  pub fn is_broadcast_equivalent(&self, other: &FrameRate) -> bool {
+    BroadcastEquivalence::from(*self) == BroadcastEquivalence::from(*other)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::collections::HashSet;
+
+  #[test]
+  fn canonical() {
+    assert_eq!(
+      FrameRate::new(2997, 100).broadcast_canonical(),
+      FrameRate::_29_97
+    );
+    assert_eq!(
+      FrameRate::new(30000, 1001).broadcast_canonical(),
+      FrameRate::_29_97
+    );
+    assert_eq!(
+      FrameRate::new(23976, 1000).broadcast_canonical(),
+      FrameRate::_23_97
+    );
+    assert_eq!(
+      FrameRate::new(48, 1).broadcast_canonical(),
+      FrameRate::new(48, 1)
+    );
+    assert_eq!(
+      BroadcastEquivalence::from(FrameRate::new(5994, 100)).canonical(),
+      FrameRate::_59_94
+    );
+  }
+
+  #[test]
+  fn deduplication() {
+    let probed = [
+      FrameRate::_29_97,
+      FrameRate::new(30000, 1001),
+      FrameRate::new(2997, 100),
+      FrameRate::_30_00,
+      FrameRate::new(48, 1),
+      FrameRate::new(96, 2),
+    ];
+    let classes: HashSet<_> = probed.into_iter().map(BroadcastEquivalence::from).collect();
+    assert_eq!(classes.len(), 3);
+
+    assert!(FrameRate::new(2997, 100).is_broadcast_equivalent(&FrameRate::_29_97));
+    assert!(!FrameRate::_29_97.is_broadcast_equivalent(&FrameRate::_30_00));
+    assert!(!FrameRate::new(2997, 100).is_broadcast_equivalent(&FrameRate::new(2998, 100)));
+  }
+}
@@ -0,0 +1,48 @@
+use crate::{FrameRate, Ratio};
+
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Error), uniffi(flat_error))]
+pub enum Error {
+  #[error("frame rate {num}/{den} does not fit in 32-bit integers")]
+  FrameRateOutOfRange { num: u64, den: u64 },
+  #[error("invalid AV1 timing_info: {0}")]
+  InvalidAv1TimingInfo(&'static str),
+  #[error("invalid clip range: in point {start} is not before out point {end}")]
+  InvalidClipRange { start: u64, end: u64 },
+  #[error("invalid DPX header: {0}")]
+  InvalidDpxHeader(&'static str),
+  #[error("invalid EDID descriptor: {0}")]
+  InvalidEdidDescriptor(&'static str),
+  #[error("invalid OpenEXR header: {0}")]
+  InvalidExrHeader(&'static str),
+  #[error("invalid FCPXML frame duration: {0:?}")]
+  InvalidFcpxmlFrameDuration(String),
+  #[error("invalid frame count: {0:?}")]
+  InvalidFrameCount(String),
+  #[error("OpenTimelineIO time is negative or not finite")]
+  InvalidOtioTime,
+  #[error("invalid relative frame rate: {0:?}")]
+  InvalidRelativeRate(String),
+  #[error("invalid timecode: {0:?}")]
+  InvalidTimecode(String),
+  #[error("invalid time range: start {start} s is not before end {end} s")]
+  InvalidTimeRange { start: Ratio<u64>, end: Ratio<u64> },
+  #[error("invalid time remap curve: {0}")]
+  InvalidTimeRemap(&'static str),
+  #[error("invalid VUI timing_info: {0}")]
+  InvalidVuiTiming(&'static str),
+  #[error("frame rate {num}/{den} is negative")]
+  NegativeFrameRate { num: i32, den: i32 },
+  #[error("{0} fps has no drop-frame timecode")]
+  NoDropFrame(FrameRate),
+  #[error("{0} fps has no standard 1000/1001 counterpart")]
+  NoNtscCounterpart(FrameRate),
+  #[error("{0} is not a whole number of frames per second")]
+  NotAnIntegerFrameRate(FrameRate),
+  #[error("frame {frames} at {frame_rate} fps has no time in seconds fitting in a 64-bit ratio")]
+  TimeOutOfRange { frame_rate: FrameRate, frames: u64 },
+  #[error("frame rate change at frame {start} is not after the one at frame {previous}")]
+  UnorderedRateChange { previous: u64, start: u64 },
+  #[error("frame rate denominator is zero")]
+  ZeroDenominator,
+}
@@ -0,0 +1,356 @@
+use crate::{FrameRate, Ratio, Rounding};
+use num_traits::CheckedAdd;
+use std::{collections::HashMap, time::Duration};
+
+/// Exact number of frames in 1000 seconds at each standard rate.
+pub const FRAMES_PER_KILOSECOND: [(FrameRate, Ratio<u64>); 10] = [
+  (FrameRate::_24_00, Ratio::new_raw(24_000, 1)),
+  (FrameRate::_25_00, Ratio::new_raw(25_000, 1)),
+  (FrameRate::_30_00, Ratio::new_raw(30_000, 1)),
+  (FrameRate::_50_00, Ratio::new_raw(50_000, 1)),
+  (FrameRate::_60_00, Ratio::new_raw(60_000, 1)),
+  (FrameRate::_120_00, Ratio::new_raw(120_000, 1)),
+  (FrameRate::_23_97, Ratio::new_raw(24_000_000, 1001)),
+  (FrameRate::_24_97, Ratio::new_raw(25_000_000, 1001)),
+  (FrameRate::_29_97, Ratio::new_raw(30_000_000, 1001)),
+  (FrameRate::_59_94, Ratio::new_raw(60_000_000, 1001)),
+];
+
+/// Exact number of frames in a 86 400 seconds day at each standard rate.
+pub const FRAMES_PER_DAY: [(FrameRate, Ratio<u64>); 10] = [
+  (FrameRate::_24_00, Ratio::new_raw(2_073_600, 1)),
+  (FrameRate::_25_00, Ratio::new_raw(2_160_000, 1)),
+  (FrameRate::_30_00, Ratio::new_raw(2_592_000, 1)),
+  (FrameRate::_50_00, Ratio::new_raw(4_320_000, 1)),
+  (FrameRate::_60_00, Ratio::new_raw(5_184_000, 1)),
+  (FrameRate::_120_00, Ratio::new_raw(10_368_000, 1)),
+  (FrameRate::_23_97, Ratio::new_raw(2_073_600_000, 1001)),
+  (FrameRate::_24_97, Ratio::new_raw(2_160_000_000, 1001)),
+  (FrameRate::_29_97, Ratio::new_raw(2_592_000_000, 1001)),
+  (FrameRate::_59_94, Ratio::new_raw(5_184_000_000, 1001)),
+];
+
+/// Total duration of a playlist of `(frame_rate, frames)` segments, rounded
+/// to the nanosecond only once, after summing exact times. Saturates at
+/// `Duration::MAX`, which is also the result when frames are played at 0 fps
+/// or when the exact sum does not fit in a `Ratio<u128>`.
+pub fn total_duration(segments: impl IntoIterator<Item = (FrameRate, u64)>) -> Duration {
+  let mut frames_per_rate = HashMap::<Ratio<u32>, u128>::new();
+  for (frame_rate, frames) in segments.into_iter().filter(|(_, frames)| *frames > 0) {
+    *frames_per_rate.entry(frame_rate.into()).or_default() += u128::from(frames);
+  }
+
+  let mut seconds = Ratio::<u128>::from_integer(0);
+  for (ratio, frames) in frames_per_rate {
+    let time = (*ratio.numer() != 0)
+      .then(|| frames.checked_mul(u128::from(*ratio.denom())))
+      .flatten()
+      .map(|numerator| Ratio::new(numerator, u128::from(*ratio.numer())));
+    match time.and_then(|time| seconds.checked_add(&time)) {
+      Some(sum) => seconds = sum,
+      None => return Duration::MAX,
+    }
+  }
+
+  let whole = u64::try_from(seconds.to_integer()).ok();
+  let nanos = (seconds.numer() % seconds.denom())
+    .checked_mul(1_000_000_000)
+    .map(|nanos| Rounding::Nearest.div(nanos, *seconds.denom()) as u64);
+  whole
+    .zip(nanos)
+    .and_then(|(whole, nanos)| Duration::from_secs(whole).checked_add(Duration::from_nanos(nanos)))
+    .unwrap_or(Duration::MAX)
+}
+
+/// Rates of `candidates` at which `duration` lasts a whole number of frames,
+/// in the given order, e.g. to pick a rate for test content of a fixed
+/// length.
+///
+/// ```
+/// use frame_rate::{FrameRate, rates_with_integer_frames};
+/// use std::time::Duration;
+///
+/// assert_eq!(
+///   rates_with_integer_frames(Duration::from_millis(1001), FrameRate::standard_rates()),
+///   [FrameRate::_23_97, FrameRate::_24_97, FrameRate::_29_97, FrameRate::_59_94]
+/// );
+/// ```
+pub fn rates_with_integer_frames(
+  duration: Duration,
+  candidates: impl IntoIterator<Item = FrameRate>,
+) -> Vec<FrameRate> {
+  candidates
+    .into_iter()
+    .filter(|frame_rate| {
+      let ratio = Ratio::<u32>::from(*frame_rate);
+      (duration.as_nanos() * u128::from(*ratio.numer()))
+        .is_multiple_of(u128::from(*ratio.denom()) * 1_000_000_000)
+    })
+    .collect()
+}
+
+impl FrameRate {
+  /// Exact number of frames in `seconds` seconds, e.g. 2592000000/1001
+  /// frames per day at 29.97.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the reduced result does not fit in a `Ratio<u64>`.
+  pub fn frames_per(&self, seconds: u64) -> Ratio<u64> {
+    let ratio = Ratio::<u32>::from(*self);
+    let frames = Ratio::new(
+      u128::from(seconds) * u128::from(*ratio.numer()),
+      u128::from(*ratio.denom()),
+    );
+    Ratio::new(
+      u64::try_from(*frames.numer()).expect("frame count numerator overflow"),
+      u64::try_from(*frames.denom()).expect("frame count denominator overflow"),
+    )
+  }
+
+  /// Exact time in seconds at the start of frame `frames`, i.e. after
+  /// `frames` frames.
+  ///
+  /// # Panics
+  ///
+  /// Panics on a zero frame rate or if the reduced result does not fit in a
+  /// `Ratio<u64>`.
+  pub fn time_after(&self, frames: u64) -> Ratio<u64> {
+    let ratio = Ratio::<u32>::from(*self);
+    let seconds = Ratio::new(
+      u128::from(frames) * u128::from(*ratio.denom()),
+      u128::from(*ratio.numer()),
+    );
+    Ratio::new(
+      u64::try_from(*seconds.numer()).expect("time numerator overflow"),
+      u64::try_from(*seconds.denom()).expect("time denominator overflow"),
+    )
+  }
+
+  /// Exact time in seconds at the start of frame `frames`, `None` when the
+  /// frame never starts at 0 fps or if the reduced result does not fit in a
+  /// `Ratio<u64>`.
+  pub fn checked_time_after(&self, frames: u64) -> Option<Ratio<u64>> {
+    let ratio = Ratio::<u32>::from(*self);
+    if frames == 0 {
+      return Some(Ratio::from_integer(0));
+    }
+    if *ratio.numer() == 0 {
+      return None;
+    }
+    let seconds = Ratio::new(
+      u128::from(frames) * u128::from(*ratio.denom()),
+      u128::from(*ratio.numer()),
+    );
+    Some(Ratio::new(
+      u64::try_from(*seconds.numer()).ok()?,
+      u64::try_from(*seconds.denom()).ok()?,
+    ))
+  }
+
+  /// Number of whole frames elapsed at `time` seconds, saturating at
+  /// `u64::MAX`. Inverse of [`FrameRate::time_after`].
+  pub fn frames_before(&self, time: Ratio<u64>) -> u64 {
+    self.frames_at(time, Rounding::Floor)
+  }
+
+  /// Number of frames in `time` seconds, rounded as requested and saturating
+  /// at `u64::MAX`. Like [`FrameRate::duration_to_frames`], without the
+  /// nanosecond rounding of a `Duration`.
+  ///
+  /// ```
+  /// use frame_rate::{FrameRate, Ratio, Rounding};
+  ///
+  /// let time = Ratio::new(1001, 60_000);
+  /// assert_eq!(FrameRate::_29_97.frames_at(time, Rounding::Floor), 0);
+  /// assert_eq!(FrameRate::_29_97.frames_at(time, Rounding::Nearest), 1);
+  /// ```
+  pub fn frames_at(&self, time: Ratio<u64>, rounding: Rounding) -> u64 {
+    let ratio = Ratio::<u32>::from(*self);
+    let frames = rounding.div(
+      u128::from(*time.numer()) * u128::from(*ratio.numer()),
+      u128::from(*time.denom()) * u128::from(*ratio.denom()),
+    );
+    u64::try_from(frames).unwrap_or(u64::MAX)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::STANDARD_FRAME_RATES;
+
+  #[test]
+  fn time_after() {
+    assert_eq!(FrameRate::_25_00.time_after(50), Ratio::from_integer(2));
+    assert_eq!(FrameRate::_29_97.time_after(1), Ratio::new(1001, 30000));
+    assert_eq!(
+      FrameRate::_29_97.time_after(30000),
+      Ratio::from_integer(1001)
+    );
+    assert_eq!(FrameRate::new(1, 10).time_after(3), Ratio::from_integer(30));
+  }
+
+  #[test]
+  fn checked_time_after() {
+    assert_eq!(
+      FrameRate::_29_97.checked_time_after(1),
+      Some(Ratio::new(1001, 30000))
+    );
+    let still = FrameRate::new(0, 1);
+    assert_eq!(still.checked_time_after(0), Some(Ratio::from_integer(0)));
+    assert_eq!(still.checked_time_after(1), None);
+    assert_eq!(
+      FrameRate::new(1, u32::MAX).checked_time_after(u64::MAX),
+      None
+    );
+  }
+
+  #[test]
+  fn frames_before() {
+    assert_eq!(FrameRate::_25_00.frames_before(Ratio::new(1, 10)), 2);
+    assert_eq!(FrameRate::_29_97.frames_before(Ratio::from_integer(1)), 29);
+    assert_eq!(
+      FrameRate::_29_97.frames_before(Ratio::from_integer(1001)),
+      30000
+    );
+    assert_eq!(
+      FrameRate::_120_00.frames_before(Ratio::from_integer(u64::MAX)),
+      u64::MAX
+    );
+  }
+
+  #[test]
+  fn frames_at() {
+    let frame_rate = FrameRate::_29_97;
+    let half_frame = Ratio::new(1001, 60_000);
+    assert_eq!(frame_rate.frames_at(half_frame, Rounding::Floor), 0);
+    assert_eq!(frame_rate.frames_at(half_frame, Rounding::Nearest), 1);
+    assert_eq!(frame_rate.frames_at(half_frame, Rounding::Ceil), 1);
+    for rounding in [Rounding::Floor, Rounding::Ceil, Rounding::Nearest] {
+      assert_eq!(
+        frame_rate.frames_at(Ratio::from_integer(1001), rounding),
+        30_000
+      );
+      assert_eq!(frame_rate.frames_at(Ratio::from_integer(0), rounding), 0);
+    }
+    assert_eq!(
+      frame_rate.frames_at(Ratio::from_integer(1), Rounding::Nearest),
+      30
+    );
+  }
+
+  #[test]
+  fn frames_per() {
+    assert_eq!(FrameRate::_25_00.frames_per(60), Ratio::from_integer(1500));
+    assert_eq!(
+      FrameRate::_29_97.frames_per(86_400),
+      Ratio::new(2_592_000_000, 1001)
+    );
+    for (frame_rate, frames) in FRAMES_PER_KILOSECOND {
+      assert_eq!(frame_rate.frames_per(1000), frames);
+    }
+    for (frame_rate, frames) in FRAMES_PER_DAY {
+      assert_eq!(frame_rate.frames_per(86_400), frames);
+    }
+    assert_eq!(
+      FRAMES_PER_DAY.map(|(frame_rate, _)| frame_rate),
+      STANDARD_FRAME_RATES
+    );
+  }
+
+  #[test]
+  fn round_trip() {
+    for frame_rate in STANDARD_FRAME_RATES {
+      for frames in [0, 1, 1001, 86_400 * 120] {
+        assert_eq!(
+          frame_rate.frames_before(frame_rate.time_after(frames)),
+          frames
+        );
+      }
+    }
+  }
+
+  #[test]
+  fn total_duration() {
+    assert_eq!(super::total_duration([]), Duration::ZERO);
+    // 3000 single frames at 29.97 last exactly 100.1 s, whereas summing
+    // their rounded 33_366_667 ns durations drifts by 1 µs.
+    assert_eq!(
+      super::total_duration(std::iter::repeat_n((FrameRate::_29_97, 1), 3000)),
+      Duration::new(100, 100_000_000)
+    );
+    assert_eq!(
+      super::total_duration([
+        (FrameRate::_23_97, 24),
+        (FrameRate::_25_00, 25),
+        (FrameRate::_29_97, 30),
+        (FrameRate::_23_97, 24),
+      ]),
+      Duration::new(4, 3_000_000)
+    );
+    assert_eq!(
+      super::total_duration([(FrameRate::new(1, 2), u64::MAX)]),
+      Duration::MAX
+    );
+
+    let still = FrameRate::new(0, 1);
+    assert_eq!(
+      super::total_duration([(still, 0), (FrameRate::_25_00, 25)]),
+      Duration::from_secs(1)
+    );
+    assert_eq!(
+      super::total_duration([(still, 1), (FrameRate::_25_00, 25)]),
+      Duration::MAX
+    );
+    assert_eq!(
+      super::total_duration([
+        (FrameRate::new(u32::MAX - 1, u32::MAX), u64::MAX),
+        (FrameRate::new(u32::MAX - 2, u32::MAX - 4), u64::MAX),
+      ]),
+      Duration::MAX
+    );
+    assert_eq!(
+      super::total_duration([(FrameRate::new(1, u32::MAX), u64::MAX)]),
+      Duration::MAX
+    );
+  }
+
+  #[test]
+  fn rates_with_integer_frames() {
+    let rates = |duration| super::rates_with_integer_frames(duration, STANDARD_FRAME_RATES);
+
+    assert_eq!(rates(Duration::from_secs(1001)), STANDARD_FRAME_RATES);
+    assert_eq!(rates(Duration::ZERO), STANDARD_FRAME_RATES);
+    assert_eq!(rates(Duration::from_secs(10)), STANDARD_FRAME_RATES[..6]);
+    assert_eq!(
+      rates(Duration::from_millis(500)),
+      [
+        FrameRate::_24_00,
+        FrameRate::_30_00,
+        FrameRate::_50_00,
+        FrameRate::_60_00,
+        FrameRate::_120_00,
+      ]
+    );
+    assert!(rates(Duration::from_nanos(1)).is_empty());
+    assert_eq!(
+      super::rates_with_integer_frames(
+        Duration::from_secs(5),
+        [
+          FrameRate::new(1, 10),
+          FrameRate::new(1, 5),
+          FrameRate::new(25, 2),
+          FrameRate::new(48, 1),
+        ]
+      ),
+      [FrameRate::new(1, 5), FrameRate::new(48, 1)]
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "time numerator overflow")]
+  fn time_after_overflow() {
+    let _ = FrameRate::new(1, u32::MAX).time_after(u64::MAX);
+  }
+}
@@ -0,0 +1,160 @@
+//! `framesPerSecond` attribute of OpenEXR headers.
+
+use crate::{Error, FrameRate, Ratio};
+
+pub const ATTRIBUTE_NAME: &str = "framesPerSecond";
+pub const ATTRIBUTE_TYPE: &str = "rational";
+
+const MAGIC: [u8; 4] = [0x76, 0x2f, 0x31, 0x01];
+
+fn read_string<'a>(header: &'a [u8], position: &mut usize) -> Result<&'a [u8], Error> {
+  let remaining = header
+    .get(*position..)
+    .ok_or(Error::InvalidExrHeader("truncated header"))?;
+  let length = remaining
+    .iter()
+    .position(|byte| *byte == 0)
+    .ok_or(Error::InvalidExrHeader("unterminated attribute string"))?;
+  *position += length + 1;
+  Ok(&remaining[..length])
+}
+
+fn read_value<'a>(header: &'a [u8], position: &mut usize) -> Result<&'a [u8], Error> {
+  let truncated = Error::InvalidExrHeader("truncated header");
+  let size = header
+    .get(*position..*position + 4)
+    .ok_or(truncated.clone())?;
+  let size = i32::from_le_bytes(size.try_into().unwrap());
+  let size =
+    usize::try_from(size).map_err(|_| Error::InvalidExrHeader("negative attribute size"))?;
+  *position += 4;
+
+  let value = header.get(*position..*position + size).ok_or(truncated)?;
+  *position += size;
+  Ok(value)
+}
+
+/// Decode the 8 bytes of a `rational` attribute value.
+pub fn decode_frames_per_second(value: &[u8]) -> Result<FrameRate, Error> {
+  let invalid = Error::InvalidExrHeader("invalid framesPerSecond value");
+  let value: [u8; 8] = value.try_into().map_err(|_| invalid.clone())?;
+  let num = i32::from_le_bytes(value[..4].try_into().unwrap());
+  let den = u32::from_le_bytes(value[4..].try_into().unwrap());
+
+  match u32::try_from(num) {
+    Ok(num) if num != 0 && den != 0 => Ok(FrameRate::new(num, den)),
+    _ => Err(invalid),
+  }
+}
+
+/// Encode `frame_rate` as a `rational` attribute value.
+///
+/// Returns `None` when the numerator does not fit the signed numerator of an
+/// EXR rational.
+pub fn encode_frames_per_second(frame_rate: FrameRate) -> Option<[u8; 8]> {
+  let ratio = Ratio::<u32>::from(frame_rate);
+  let num = i32::try_from(*ratio.numer()).ok()?;
+
+  let mut value = [0; 8];
+  value[..4].copy_from_slice(&num.to_le_bytes());
+  value[4..].copy_from_slice(&ratio.denom().to_le_bytes());
+  Some(value)
+}
+
+/// Full `framesPerSecond` attribute (name, type, size and value), ready to be
+/// inserted in a header attribute list.
+pub fn frames_per_second_attribute(frame_rate: FrameRate) -> Option<Vec<u8>> {
+  let value = encode_frames_per_second(frame_rate)?;
+
+  let mut attribute = Vec::with_capacity(ATTRIBUTE_NAME.len() + ATTRIBUTE_TYPE.len() + 14);
+  attribute.extend_from_slice(ATTRIBUTE_NAME.as_bytes());
+  attribute.push(0);
+  attribute.extend_from_slice(ATTRIBUTE_TYPE.as_bytes());
+  attribute.push(0);
+  attribute.extend_from_slice(&(value.len() as i32).to_le_bytes());
+  attribute.extend_from_slice(&value);
+  Some(attribute)
+}
+
+/// Look for the `framesPerSecond` attribute in the (first part) header of an
+/// OpenEXR file.
+pub fn read_frames_per_second(header: &[u8]) -> Result<Option<FrameRate>, Error> {
+  if header.get(..4) != Some(&MAGIC[..]) {
+    return Err(Error::InvalidExrHeader("missing magic number"));
+  }
+
+  // Skip the magic number and version field.
+  let mut position = 8;
+  loop {
+    let name = read_string(header, &mut position)?;
+    if name.is_empty() {
+      return Ok(None);
+    }
+    let attribute_type = read_string(header, &mut position)?;
+    let value = read_value(header, &mut position)?;
+
+    if name == ATTRIBUTE_NAME.as_bytes() {
+      if attribute_type != ATTRIBUTE_TYPE.as_bytes() {
+        return Err(Error::InvalidExrHeader("framesPerSecond is not a rational"));
+      }
+      return decode_frames_per_second(value).map(Some);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn header(attributes: &[&[u8]]) -> Vec<u8> {
+    let mut header = MAGIC.to_vec();
+    header.extend_from_slice(&[2, 0, 0, 0]);
+    for attribute in attributes {
+      header.extend_from_slice(attribute);
+    }
+    header.push(0);
+    header
+  }
+
+  #[test]
+  fn round_trip() {
+    let mut other = b"compression\0compression\0".to_vec();
+    other.extend_from_slice(&[1, 0, 0, 0, 3]);
+
+    for frame_rate in [FrameRate::_23_97, FrameRate::_25_00, FrameRate::new(1, 10)] {
+      let attribute = frames_per_second_attribute(frame_rate).unwrap();
+      let header = header(&[&other, &attribute]);
+      assert_eq!(read_frames_per_second(&header), Ok(Some(frame_rate)));
+    }
+  }
+
+  #[test]
+  fn missing() {
+    assert_eq!(read_frames_per_second(&header(&[])), Ok(None));
+    assert_eq!(
+      read_frames_per_second(b"\x76\x2f\x31\x01\x02\0\0\0abc"),
+      Err(Error::InvalidExrHeader("unterminated attribute string"))
+    );
+    assert_eq!(
+      read_frames_per_second(b"gif"),
+      Err(Error::InvalidExrHeader("missing magic number"))
+    );
+  }
+
+  #[test]
+  fn invalid_values() {
+    assert_eq!(
+      decode_frames_per_second(&[0xFF, 0xFF, 0xFF, 0xFF, 1, 0, 0, 0]),
+      Err(Error::InvalidExrHeader("invalid framesPerSecond value"))
+    );
+    assert_eq!(
+      decode_frames_per_second(&[25, 0, 0, 0, 0, 0, 0, 0]),
+      Err(Error::InvalidExrHeader("invalid framesPerSecond value"))
+    );
+    assert_eq!(
+      decode_frames_per_second(&[25, 0, 0, 0]),
+      Err(Error::InvalidExrHeader("invalid framesPerSecond value"))
+    );
+    assert_eq!(encode_frames_per_second(FrameRate::new(u32::MAX, 1)), None);
+  }
+}
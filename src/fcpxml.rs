@@ -0,0 +1,109 @@
+use crate::{Error, FrameRate, Ratio};
+
+impl FrameRate {
+  /// Parse an FCPXML `frameDuration` attribute, e.g. `"1001/30000s"` or
+  /// `"100/2500s"`.
+  pub fn from_fcpxml_frame_duration(frame_duration: &str) -> Result<Self, Error> {
+    let invalid = || Error::InvalidFcpxmlFrameDuration(frame_duration.to_string());
+
+    let value = frame_duration.strip_suffix('s').ok_or_else(invalid)?;
+    let (seconds, timescale) = value.split_once('/').unwrap_or((value, "1"));
+    let parse = |digits: &str| {
+      digits
+        .bytes()
+        .all(|byte| byte.is_ascii_digit())
+        .then(|| digits.parse::<u64>().ok())
+        .flatten()
+        .filter(|value| *value != 0)
+        .ok_or_else(invalid)
+    };
+
+    // The frame rate is the inverse of the frame duration.
+    let rate = Ratio::new(parse(timescale)?, parse(seconds)?);
+    let num = u32::try_from(*rate.numer()).map_err(|_| invalid())?;
+    let den = u32::try_from(*rate.denom()).map_err(|_| invalid())?;
+    Ok(Self::new(num, den))
+  }
+
+  /// Format as an FCPXML `frameDuration` attribute, following Final Cut Pro
+  /// conventions: `"100/2500s"` for integer rates, `"1001/30000s"` for NTSC
+  /// rates.
+  pub fn to_fcpxml_frame_duration(&self) -> String {
+    let ratio = Ratio::<u32>::from(*self);
+    let (num, den) = (u64::from(*ratio.numer()), u64::from(*ratio.denom()));
+
+    match den {
+      1 => format!("100/{}s", num * 100),
+      _ => format!("{den}/{num}s"),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse() {
+    let parse = FrameRate::from_fcpxml_frame_duration;
+    assert_eq!(parse("1001/24000s"), Ok(FrameRate::_23_97));
+    assert_eq!(parse("100/2400s"), Ok(FrameRate::_24_00));
+    assert_eq!(parse("100/2500s"), Ok(FrameRate::_25_00));
+    assert_eq!(parse("1001/30000s"), Ok(FrameRate::_29_97));
+    assert_eq!(parse("1/30s"), Ok(FrameRate::_30_00));
+    assert_eq!(parse("100/5000s"), Ok(FrameRate::_50_00));
+    assert_eq!(parse("1001/60000s"), Ok(FrameRate::_59_94));
+    assert_eq!(parse("10s"), Ok(FrameRate::new(1, 10)));
+    assert_eq!(parse("2/25s"), Ok(FrameRate::new(25, 2)));
+
+    for invalid in [
+      "",
+      "s",
+      "1001/30000",
+      "0/25s",
+      "1/0s",
+      "/25s",
+      "1/s",
+      "-1/25s",
+      "+1/25s",
+      "1.5/25s",
+      "1/25/2s",
+      "1 /25s",
+      "1/4294967296s",
+    ] {
+      assert_eq!(
+        parse(invalid),
+        Err(Error::InvalidFcpxmlFrameDuration(invalid.to_string())),
+        "{invalid}"
+      );
+    }
+  }
+
+  #[test]
+  fn format() {
+    assert_eq!(FrameRate::_23_97.to_fcpxml_frame_duration(), "1001/24000s");
+    assert_eq!(FrameRate::_24_00.to_fcpxml_frame_duration(), "100/2400s");
+    assert_eq!(FrameRate::_25_00.to_fcpxml_frame_duration(), "100/2500s");
+    assert_eq!(FrameRate::_29_97.to_fcpxml_frame_duration(), "1001/30000s");
+    assert_eq!(FrameRate::_30_00.to_fcpxml_frame_duration(), "100/3000s");
+    assert_eq!(FrameRate::_59_94.to_fcpxml_frame_duration(), "1001/60000s");
+    assert_eq!(FrameRate::_120_00.to_fcpxml_frame_duration(), "100/12000s");
+    assert_eq!(FrameRate::new(25, 2).to_fcpxml_frame_duration(), "2/25s");
+    assert_eq!(FrameRate::new(1, 10).to_fcpxml_frame_duration(), "10/1s");
+  }
+
+  #[test]
+  fn round_trip() {
+    for frame_rate in [
+      FrameRate::_23_97,
+      FrameRate::_60_00,
+      FrameRate::new(u32::MAX, 1),
+      FrameRate::new(1, u32::MAX),
+    ] {
+      assert_eq!(
+        FrameRate::from_fcpxml_frame_duration(&frame_rate.to_fcpxml_frame_duration()),
+        Ok(frame_rate)
+      );
+    }
+  }
+}
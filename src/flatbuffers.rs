@@ -0,0 +1,149 @@
+//! FlatBuffers adapter for the `FrameRate` table of `schema/frame_rate.fbs`.
+//!
+//! [`FrameRateTable`] is laid out as `flatc --rust` generated code, without a
+//! build step.
+//!
+//! ```
+//! use flatbuffers::FlatBufferBuilder;
+//! use frame_rate::FrameRate;
+//! use frame_rate::flatbuffers::FrameRateTable;
+//!
+//! let mut builder = FlatBufferBuilder::new();
+//! let table = FrameRateTable::create(&mut builder, FrameRate::_29_97);
+//! builder.finish_minimal(table);
+//!
+//! let table = FrameRateTable::root(builder.finished_data()).unwrap();
+//! assert_eq!(FrameRate::try_from(table), Ok(FrameRate::_29_97));
+//! ```
+
+use crate::{Error, FrameRate, Ratio};
+use ::flatbuffers::{
+  Allocator, FlatBufferBuilder, Follow, InvalidFlatbuffer, Table, VOffsetT, Verifiable, Verifier,
+  WIPOffset,
+};
+
+/// Source of `schema/frame_rate.fbs`.
+pub const FRAME_RATE_FBS: &str = include_str!("../schema/frame_rate.fbs");
+
+/// `frame_rate.FrameRate` table.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FrameRateTable<'a> {
+  table: Table<'a>,
+}
+
+impl<'a> FrameRateTable<'a> {
+  pub const VT_NUM: VOffsetT = 4;
+  pub const VT_DEN: VOffsetT = 6;
+
+  /// Verifies `buffer` and returns its root table.
+  pub fn root(buffer: &'a [u8]) -> Result<Self, InvalidFlatbuffer> {
+    ::flatbuffers::root::<Self>(buffer)
+  }
+
+  /// Writes the reduced ratio of `frame_rate`.
+  pub fn create<'b, A: Allocator + 'b>(
+    builder: &mut FlatBufferBuilder<'b, A>,
+    frame_rate: FrameRate,
+  ) -> WIPOffset<FrameRateTable<'b>> {
+    let ratio = Ratio::<u32>::from(frame_rate);
+    let start = builder.start_table();
+    builder.push_slot::<u32>(Self::VT_DEN, *ratio.denom(), 0);
+    builder.push_slot::<u32>(Self::VT_NUM, *ratio.numer(), 0);
+    WIPOffset::new(builder.end_table(start).value())
+  }
+
+  pub fn num(&self) -> u32 {
+    // SAFETY: the table was checked by `Verifiable` when read with `root`.
+    unsafe { self.table.get::<u32>(Self::VT_NUM, Some(0)) }.unwrap_or_default()
+  }
+
+  pub fn den(&self) -> u32 {
+    // SAFETY: the table was checked by `Verifiable` when read with `root`.
+    unsafe { self.table.get::<u32>(Self::VT_DEN, Some(0)) }.unwrap_or_default()
+  }
+}
+
+impl<'a> Follow<'a> for FrameRateTable<'a> {
+  type Inner = Self;
+
+  unsafe fn follow(buffer: &'a [u8], location: usize) -> Self {
+    Self {
+      // SAFETY: forwarded from the caller.
+      table: unsafe { Table::new(buffer, location) },
+    }
+  }
+}
+
+impl Verifiable for FrameRateTable<'_> {
+  fn run_verifier(verifier: &mut Verifier, position: usize) -> Result<(), InvalidFlatbuffer> {
+    verifier
+      .visit_table(position)?
+      .visit_field::<u32>("num", Self::VT_NUM, false)?
+      .visit_field::<u32>("den", Self::VT_DEN, false)?
+      .finish();
+    Ok(())
+  }
+}
+
+/// Fails on a zero denominator, which is also what an unset `den` reads as.
+impl TryFrom<FrameRateTable<'_>> for FrameRate {
+  type Error = Error;
+
+  fn try_from(table: FrameRateTable<'_>) -> Result<Self, Error> {
+    Self::try_new(table.num(), table.den())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn encode(frame_rate: FrameRate) -> Vec<u8> {
+    let mut builder = FlatBufferBuilder::new();
+    let table = FrameRateTable::create(&mut builder, frame_rate);
+    builder.finish_minimal(table);
+    builder.finished_data().to_vec()
+  }
+
+  fn decode(bytes: Vec<u8>) -> Result<FrameRate, Error> {
+    FrameRate::try_from(FrameRateTable::root(&bytes).unwrap())
+  }
+
+  #[test]
+  fn round_trip() {
+    let bytes = encode(FrameRate::_29_97);
+    let table = FrameRateTable::root(&bytes).unwrap();
+    assert_eq!((table.num(), table.den()), (30000, 1001));
+    assert_eq!(decode(encode(FrameRate::new(50, 2))), Ok(FrameRate::_25_00));
+  }
+
+  #[test]
+  fn zero_denominator() {
+    let mut builder = FlatBufferBuilder::new();
+    let start = builder.start_table();
+    builder.push_slot::<u32>(FrameRateTable::VT_NUM, 25, 0);
+    let table = WIPOffset::<FrameRateTable>::new(builder.end_table(start).value());
+    builder.finish_minimal(table);
+    assert_eq!(
+      decode(builder.finished_data().to_vec()),
+      Err(Error::ZeroDenominator)
+    );
+  }
+
+  #[test]
+  fn invalid_buffer() {
+    assert!(FrameRateTable::root(&[1, 2, 3]).is_err());
+  }
+
+  #[cfg(feature = "test-util")]
+  #[test]
+  fn conformance() {
+    crate::conformance::assert_round_trip(encode, decode);
+  }
+
+  #[test]
+  fn schema_file() {
+    assert!(FRAME_RATE_FBS.contains("num:uint;"));
+    assert!(FRAME_RATE_FBS.contains("den:uint;"));
+  }
+}
@@ -0,0 +1,66 @@
+use crate::FrameRate;
+use crate::parse::parse_ratio;
+use async_graphql::{InputValueError, InputValueResult, Name, Scalar, ScalarType, Value};
+
+/// Accepts a `"30000/1001"` string or a `{num, den}` object, and outputs the
+/// string form.
+#[Scalar(name = "FrameRate")]
+impl ScalarType for FrameRate {
+  fn parse(value: Value) -> InputValueResult<Self> {
+    match &value {
+      Value::String(string) => {
+        parse_ratio(string).ok_or_else(|| InputValueError::custom("expected \"num/den\""))
+      }
+      Value::Object(object) => {
+        let field = |name: &str| match object.get(&Name::new(name)) {
+          Some(Value::Number(number)) => number.as_u64().and_then(|n| u32::try_from(n).ok()),
+          _ => None,
+        };
+        match (field("num"), field("den")) {
+          (Some(num), Some(den)) if den != 0 => Ok(Self::new(num, den)),
+          _ => Err(InputValueError::custom(
+            "expected unsigned integers num and den, den not zero",
+          )),
+        }
+      }
+      _ => Err(InputValueError::expected_type(value)),
+    }
+  }
+
+  fn is_valid(value: &Value) -> bool {
+    matches!(value, Value::String(_) | Value::Object(_))
+  }
+
+  fn to_value(&self) -> Value {
+    let ratio = crate::Ratio::<u32>::from(*self);
+    Value::String(format!("{}/{}", ratio.numer(), ratio.denom()))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use async_graphql::value;
+
+  #[test]
+  fn parse() {
+    assert_eq!(
+      <FrameRate as ScalarType>::parse(value!("30000/1001")).unwrap(),
+      FrameRate::_29_97
+    );
+    assert_eq!(
+      <FrameRate as ScalarType>::parse(value!({"num": 25, "den": 1})).unwrap(),
+      FrameRate::_25_00
+    );
+    assert!(<FrameRate as ScalarType>::parse(value!("25/0")).is_err());
+    assert!(<FrameRate as ScalarType>::parse(value!({"num": 25, "den": 0})).is_err());
+    assert!(<FrameRate as ScalarType>::parse(value!({"num": -25, "den": 1})).is_err());
+    assert!(<FrameRate as ScalarType>::parse(value!(29.97)).is_err());
+  }
+
+  #[test]
+  fn to_value() {
+    assert_eq!(FrameRate::_29_97.to_value(), value!("30000/1001"));
+    assert_eq!(FrameRate::_25_00.to_value(), value!("25/1"));
+  }
+}
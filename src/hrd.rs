@@ -0,0 +1,135 @@
+//! Hypothetical reference decoder (HRD) and VBV buffer timing.
+
+use crate::{FrameRate, Ratio, Rounding};
+
+/// 90 kHz clock of `initial_cpb_removal_delay` and MPEG-TS timestamps.
+pub const CLOCK_90KHZ: u64 = 90_000;
+
+/// 27 MHz system clock of MPEG-2 `vbv_delay` and MPEG-TS PCR.
+pub const CLOCK_27MHZ: u64 = 27_000_000;
+
+/// Coded picture buffer timing of a stream at `frame_rate`, counted in ticks
+/// of a `clock` Hz clock.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct HrdTiming {
+  frame_rate: FrameRate,
+  clock: u64,
+}
+
+impl HrdTiming {
+  pub fn new(frame_rate: FrameRate, clock: u64) -> Self {
+    Self { frame_rate, clock }
+  }
+
+  pub fn frame_rate(&self) -> FrameRate {
+    self.frame_rate
+  }
+
+  pub fn clock(&self) -> u64 {
+    self.clock
+  }
+
+  /// Exact number of clock ticks per frame, e.g. 3003 at 29.97 fps on the
+  /// 90 kHz clock but 3753.75 at 23.976 fps. `None` at 0 fps or on overflow.
+  pub fn ticks_per_frame(&self) -> Option<Ratio<u64>> {
+    let ratio = Ratio::<u32>::from(self.frame_rate);
+    if *ratio.numer() == 0 {
+      return None;
+    }
+    Some(Ratio::new(
+      self.clock.checked_mul(u64::from(*ratio.denom()))?,
+      u64::from(*ratio.numer()),
+    ))
+  }
+
+  /// Initial removal delay filling the buffer with `buffer_bits` at `bitrate`
+  /// bits per second, rounded up to a tick. `None` for a zero `bitrate` or
+  /// on overflow.
+  pub fn initial_cpb_removal_delay(&self, buffer_bits: u64, bitrate: u64) -> Option<u64> {
+    if bitrate == 0 {
+      return None;
+    }
+    let ticks = Rounding::Ceil.div(
+      u128::from(buffer_bits) * u128::from(self.clock),
+      u128::from(bitrate),
+    );
+    u64::try_from(ticks).ok()
+  }
+
+  /// Initial removal delay buffering `frames` frames, rounded up to a tick.
+  /// `None` at 0 fps or on overflow.
+  pub fn initial_delay_for_frames(&self, frames: u64) -> Option<u64> {
+    self.ticks_for_frames(frames, Rounding::Ceil)
+  }
+
+  /// Removal time of `frame`, `initial_delay` ticks after the arrival of the
+  /// first bit. Computed from the exact elapsed time and rounded down, so
+  /// removal times never drift at 1001-based rates. `None` at 0 fps or on
+  /// overflow.
+  pub fn removal_time(&self, initial_delay: u64, frame: u64) -> Option<u64> {
+    initial_delay.checked_add(self.ticks_for_frames(frame, Rounding::Floor)?)
+  }
+
+  fn ticks_for_frames(&self, frames: u64, rounding: Rounding) -> Option<u64> {
+    let ratio = Ratio::<u32>::from(self.frame_rate);
+    if *ratio.numer() == 0 {
+      return None;
+    }
+    let numerator =
+      (u128::from(frames) * u128::from(self.clock)).checked_mul(u128::from(*ratio.denom()))?;
+    let ticks = rounding.div(numerator, u128::from(*ratio.numer()));
+    u64::try_from(ticks).ok()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn ticks_per_frame() {
+    let timing = HrdTiming::new(FrameRate::_29_97, CLOCK_90KHZ);
+    assert_eq!(timing.ticks_per_frame(), Some(Ratio::from_integer(3003)));
+    let timing = HrdTiming::new(FrameRate::_23_97, CLOCK_90KHZ);
+    assert_eq!(timing.ticks_per_frame(), Some(Ratio::new(15015, 4)));
+    let timing = HrdTiming::new(FrameRate::_59_94, CLOCK_27MHZ);
+    assert_eq!(timing.ticks_per_frame(), Some(Ratio::from_integer(450_450)));
+  }
+
+  #[test]
+  fn initial_delay() {
+    let timing = HrdTiming::new(FrameRate::_25_00, CLOCK_90KHZ);
+    assert_eq!(
+      timing.initial_cpb_removal_delay(5_000_000, 10_000_000),
+      Some(45_000)
+    );
+    assert_eq!(timing.initial_cpb_removal_delay(1, 10_000_000), Some(1));
+    assert_eq!(timing.initial_cpb_removal_delay(1, 0), None);
+    assert_eq!(timing.initial_cpb_removal_delay(u64::MAX, 1), None);
+    assert_eq!(timing.initial_delay_for_frames(3), Some(10_800));
+    let timing = HrdTiming::new(FrameRate::_23_97, CLOCK_90KHZ);
+    assert_eq!(timing.initial_delay_for_frames(1), Some(3754));
+  }
+
+  #[test]
+  fn zero_frame_rate() {
+    let timing = HrdTiming::new(FrameRate::new(0, 1), CLOCK_90KHZ);
+    assert_eq!(timing.ticks_per_frame(), None);
+    assert_eq!(timing.initial_delay_for_frames(1), None);
+    assert_eq!(timing.removal_time(900, 1), None);
+  }
+
+  #[test]
+  fn removal_time() {
+    let timing = HrdTiming::new(FrameRate::_23_97, CLOCK_90KHZ);
+    let times: Vec<_> = (0..5)
+      .map(|frame| timing.removal_time(900, frame).unwrap())
+      .collect();
+    assert_eq!(times, [900, 4653, 8407, 12161, 15915]);
+    // One hour of frames lands exactly on the 1001-scaled hour.
+    assert_eq!(timing.removal_time(0, 86_400), Some(3600 * 1001 * 90));
+    assert_eq!(timing.removal_time(u64::MAX, 1), None);
+    let timing = HrdTiming::new(FrameRate::new(1, u32::MAX), u64::MAX);
+    assert_eq!(timing.removal_time(0, u64::MAX), None);
+  }
+}
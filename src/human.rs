@@ -0,0 +1,134 @@
+use crate::{Error, FrameRate, Ratio, Rounding, Timecode};
+use num_traits::{CheckedAdd, CheckedMul};
+
+impl FrameRate {
+  /// Parses a length given on a command line or in a query string into a
+  /// frame count at this rate:
+  ///
+  /// - frames: `"250f"`
+  /// - durations: `"10s"`, `"2m30s"`, `"1h"`, `"1.5s"` or `"500ms"`, rounded
+  ///   to the nearest frame, optionally followed by frames as in `"10s12f"`
+  /// - timecodes: `"00:00:05:12"`, or `"00:01:00;02"` for drop-frame
+  ///
+  /// ```
+  /// use frame_rate::FrameRate;
+  ///
+  /// assert_eq!(FrameRate::_25_00.parse_frames("2m30s"), Ok(3750));
+  /// assert_eq!(FrameRate::_25_00.parse_frames("00:00:05:12"), Ok(137));
+  /// ```
+  pub fn parse_frames(&self, value: &str) -> Result<u64, Error> {
+    let invalid = || Error::InvalidFrameCount(value.to_string());
+    let trimmed = value.trim();
+    if trimmed.contains([':', ';']) {
+      Timecode::parse(*self, trimmed)
+        .map(|timecode| timecode.to_frames())
+        .map_err(|_| invalid())
+    } else {
+      self.duration_frames(trimmed).ok_or_else(invalid)
+    }
+  }
+
+  fn duration_frames(&self, mut value: &str) -> Option<u64> {
+    let mut seconds = Ratio::<u64>::from_integer(0);
+    let mut frames = 0u64;
+    if value.is_empty() {
+      return None;
+    }
+    while !value.is_empty() {
+      let split = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .filter(|split| *split > 0)?;
+      let (number, rest) = value.split_at(split);
+      let unit_end = rest
+        .find(|c: char| c.is_ascii_digit())
+        .unwrap_or(rest.len());
+      let (unit, rest) = rest.split_at(unit_end);
+      if unit == "f" {
+        frames = frames.checked_add(number.parse().ok()?)?;
+      } else {
+        let scale = match unit {
+          "h" => Ratio::from_integer(3600),
+          "m" => Ratio::from_integer(60),
+          "s" => Ratio::from_integer(1),
+          "ms" => Ratio::new(1, 1000),
+          _ => return None,
+        };
+        seconds = seconds.checked_add(&decimal(number)?.checked_mul(&scale)?)?;
+      }
+      value = rest;
+    }
+
+    let ratio = Ratio::<u32>::from(*self);
+    let from_seconds = Rounding::Nearest.div(
+      u128::from(*seconds.numer()) * u128::from(*ratio.numer()),
+      u128::from(*seconds.denom()) * u128::from(*ratio.denom()),
+    );
+    u64::try_from(from_seconds).ok()?.checked_add(frames)
+  }
+}
+
+/// Exact value of a non negative decimal such as `"1.5"`.
+fn decimal(value: &str) -> Option<Ratio<u64>> {
+  let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+  if integer.is_empty() || fraction.contains('.') {
+    return None;
+  }
+  let denominator = 10u64.checked_pow(fraction.len() as u32)?;
+  let numerator = format!("{integer}{fraction}").parse::<u64>().ok()?;
+  Some(Ratio::new(numerator, denominator))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn durations() {
+    let frame_rate = FrameRate::_25_00;
+    assert_eq!(frame_rate.parse_frames("250f"), Ok(250));
+    assert_eq!(frame_rate.parse_frames("10s"), Ok(250));
+    assert_eq!(frame_rate.parse_frames(" 2m30s "), Ok(3750));
+    assert_eq!(frame_rate.parse_frames("1h"), Ok(90_000));
+    assert_eq!(frame_rate.parse_frames("1.5s"), Ok(38));
+    assert_eq!(frame_rate.parse_frames("500ms"), Ok(13));
+    assert_eq!(frame_rate.parse_frames("10s12f"), Ok(262));
+    assert_eq!(FrameRate::_29_97.parse_frames("1001s"), Ok(30_000));
+    assert_eq!(FrameRate::_29_97.parse_frames("10s"), Ok(300));
+  }
+
+  #[test]
+  fn timecodes() {
+    assert_eq!(FrameRate::_25_00.parse_frames("00:00:05:12"), Ok(137));
+    assert_eq!(FrameRate::_25_00.parse_frames("01:00:00:00"), Ok(90_000));
+    assert_eq!(FrameRate::_29_97.parse_frames("00:01:00:00"), Ok(1800));
+    assert_eq!(FrameRate::_29_97.parse_frames("00:01:00;02"), Ok(1800));
+    assert_eq!(FrameRate::_29_97.parse_frames("00:10:00;00"), Ok(17_982));
+    assert_eq!(FrameRate::_59_94.parse_frames("00:01:00;04"), Ok(3600));
+  }
+
+  #[test]
+  fn invalid() {
+    let frame_rate = FrameRate::_25_00;
+    for value in [
+      "",
+      "250",
+      "f",
+      "10x",
+      "1.2.3s",
+      "-1s",
+      "00:00:05",
+      "00:00:05:25",
+      "00:60:00:00",
+      "00:00:00:00:00",
+      "00:00:00;00",
+      "1:2:3:x",
+    ] {
+      assert_eq!(
+        frame_rate.parse_frames(value),
+        Err(Error::InvalidFrameCount(value.to_string())),
+        "{value:?}"
+      );
+    }
+    assert!(FrameRate::_29_97.parse_frames("00:01:00;01").is_err());
+  }
+}
@@ -0,0 +1,214 @@
+use crate::{FrameRate, Rounding, Timecode};
+use std::time::Duration;
+
+/// Timecode generator free-running at a frame rate from a local monotonic
+/// clock, periodically jammed to a reference such as LTC or PTP.
+///
+/// Times are local clock readings, e.g. `Instant::elapsed` since start-up,
+/// and positions are frame counts since the reference epoch. Offsets up to
+/// the jump threshold, one frame period by default, are slewed away at most
+/// `max_slew_ppm` (1000 by default) so the output never skips a frame;
+/// larger offsets are jumped over.
+#[derive(Clone, Debug, PartialEq)]
+pub struct JammedTimecodeGenerator {
+  frame_rate: FrameRate,
+  max_slew_ppm: u32,
+  jump_threshold: Duration,
+  drop_frame: bool,
+  anchor: Option<Anchor>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Anchor {
+  local: Duration,
+  /// Reference time at `local`, in nanoseconds.
+  position: i128,
+  /// Offset left to slew from `local` on, in nanoseconds.
+  correction: i128,
+}
+
+impl JammedTimecodeGenerator {
+  pub fn new(frame_rate: FrameRate) -> Self {
+    Self {
+      frame_rate,
+      max_slew_ppm: 1000,
+      jump_threshold: frame_rate.frames_to_duration(1, Rounding::Nearest),
+      drop_frame: false,
+      anchor: None,
+    }
+  }
+
+  /// Slews offsets at most `max_slew_ppm` parts per million of elapsed time,
+  /// 0 jumping over every offset.
+  pub fn with_max_slew_ppm(mut self, max_slew_ppm: u32) -> Self {
+    self.max_slew_ppm = max_slew_ppm;
+    self
+  }
+
+  /// Jumps over offsets larger than `jump_threshold` instead of slewing.
+  pub fn with_jump_threshold(mut self, jump_threshold: Duration) -> Self {
+    self.jump_threshold = jump_threshold;
+    self
+  }
+
+  /// Labels frames with drop-frame timecode, ignored at rates without
+  /// drop-frame timecode.
+  pub fn with_drop_frame(mut self, drop_frame: bool) -> Self {
+    self.drop_frame = drop_frame
+      && self
+        .frame_rate
+        .metadata()
+        .is_some_and(|metadata| metadata.drop_frame);
+    self
+  }
+
+  pub fn frame_rate(&self) -> FrameRate {
+    self.frame_rate
+  }
+
+  /// Whether the generator has been jammed at least once.
+  pub fn is_jammed(&self) -> bool {
+    self.anchor.is_some()
+  }
+
+  /// Jams the generator to `reference_frame` starting at local time `local`.
+  ///
+  /// Returns the offset in nanoseconds between the reference and the
+  /// generator before the jam, positive when the generator was late.
+  pub fn jam(&mut self, local: Duration, reference_frame: u64) -> i64 {
+    let reference = self
+      .frame_rate
+      .frames_to_duration(reference_frame, Rounding::Nearest)
+      .as_nanos() as i128;
+    let Some(position) = self.position(local) else {
+      self.anchor = Some(Anchor {
+        local,
+        position: reference,
+        correction: 0,
+      });
+      return 0;
+    };
+
+    let offset = reference - position;
+    self.anchor = Some(
+      if self.max_slew_ppm == 0 || offset.unsigned_abs() > self.jump_threshold.as_nanos() {
+        Anchor {
+          local,
+          position: reference,
+          correction: 0,
+        }
+      } else {
+        Anchor {
+          local,
+          position,
+          correction: offset,
+        }
+      },
+    );
+    offset.clamp(i64::MIN.into(), i64::MAX.into()) as i64
+  }
+
+  /// Frame position at local time `local`, `None` until jammed.
+  pub fn frame_at(&self, local: Duration) -> Option<u64> {
+    let position = u128::try_from(self.position(local)?).unwrap_or(0);
+    let position = Duration::new(
+      (position / 1_000_000_000) as u64,
+      (position % 1_000_000_000) as u32,
+    );
+    Some(
+      self
+        .frame_rate
+        .duration_to_frames(position, Rounding::Floor),
+    )
+  }
+
+  /// Timecode at local time `local`, `None` until jammed.
+  pub fn timecode_at(&self, local: Duration) -> Option<Timecode> {
+    Timecode::with_drop_frame(self.frame_rate, self.frame_at(local)?, self.drop_frame).ok()
+  }
+
+  /// Offset in nanoseconds still to be slewed at local time `local`.
+  pub fn remaining_correction(&self, local: Duration) -> i64 {
+    self.anchor.map_or(0, |anchor| {
+      (anchor.correction - self.slewed(&anchor, local)) as i64
+    })
+  }
+
+  fn position(&self, local: Duration) -> Option<i128> {
+    let anchor = self.anchor?;
+    let elapsed = local.saturating_sub(anchor.local).as_nanos() as i128;
+    Some(anchor.position + elapsed + self.slewed(&anchor, local))
+  }
+
+  /// Part of the anchor correction applied by local time `local`.
+  fn slewed(&self, anchor: &Anchor, local: Duration) -> i128 {
+    let elapsed = local.saturating_sub(anchor.local).as_nanos() as i128;
+    let max = elapsed * i128::from(self.max_slew_ppm) / 1_000_000;
+    anchor.correction.clamp(-max, max)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn secs(secs: f64) -> Duration {
+    Duration::from_secs_f64(secs)
+  }
+
+  #[test]
+  fn free_run() {
+    let mut generator = JammedTimecodeGenerator::new(FrameRate::_29_97);
+    assert_eq!(generator.frame_at(secs(1.0)), None);
+    assert_eq!(generator.jam(secs(5.0), 1000), 0);
+    assert!(generator.is_jammed());
+    assert_eq!(generator.frame_at(secs(5.0)), Some(1000));
+    assert_eq!(generator.frame_at(secs(5.0 + 1001.0)), Some(31_000));
+  }
+
+  #[test]
+  fn slew() {
+    let mut generator = JammedTimecodeGenerator::new(FrameRate::_25_00);
+    generator.jam(secs(0.0), 0);
+    // The local clock runs fast: 10 s locally are 9.96 s of reference.
+    assert_eq!(generator.jam(secs(10.0), 249), -40_000_000);
+    assert_eq!(generator.frame_at(secs(10.0)), Some(250));
+    // 1000 ppm slews 10 ms in 10 s.
+    assert_eq!(generator.remaining_correction(secs(20.0)), -30_000_000);
+    assert_eq!(generator.frame_at(secs(20.0)), Some(499));
+    assert_eq!(generator.remaining_correction(secs(60.0)), 0);
+    assert_eq!(generator.frame_at(secs(60.0)), Some(1499));
+  }
+
+  #[test]
+  fn jump() {
+    let mut generator = JammedTimecodeGenerator::new(FrameRate::_25_00);
+    generator.jam(secs(0.0), 0);
+    assert_eq!(generator.jam(secs(30.0), 1000), 10_000_000_000);
+    assert_eq!(generator.frame_at(secs(30.0)), Some(1000));
+    assert_eq!(generator.remaining_correction(secs(30.0)), 0);
+
+    let mut generator = JammedTimecodeGenerator::new(FrameRate::_25_00).with_max_slew_ppm(0);
+    generator.jam(secs(0.0), 0);
+    assert_eq!(generator.jam(secs(10.0), 249), -40_000_000);
+    assert_eq!(generator.frame_at(secs(10.0)), Some(249));
+  }
+
+  #[test]
+  fn timecode_at() {
+    let mut generator = JammedTimecodeGenerator::new(FrameRate::_29_97).with_drop_frame(true);
+    assert_eq!(generator.timecode_at(secs(0.0)), None);
+    generator.jam(secs(0.0), 1799);
+    assert_eq!(
+      generator.timecode_at(secs(0.04)).unwrap().to_string(),
+      "00:01:00;02"
+    );
+
+    let mut generator = JammedTimecodeGenerator::new(FrameRate::_25_00).with_drop_frame(true);
+    generator.jam(secs(0.0), 25);
+    assert_eq!(
+      generator.timecode_at(secs(0.0)).unwrap().to_string(),
+      "00:00:01:00"
+    );
+  }
+}
@@ -0,0 +1,87 @@
+use crate::FrameRate;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Variant of a [`FrameRate`] without the ratio of custom rates, for
+/// `match` arms and telemetry labels with a bounded set of values.
+#[derive(Clone, Copy, Debug, Deserialize, Eq, Hash, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FrameRateKind {
+  Std23_97,
+  Std24,
+  Std24_97,
+  Std25,
+  Std29_97,
+  Std30,
+  Std50,
+  Std59_94,
+  Std60,
+  Std120,
+  Custom,
+}
+
+impl FrameRate {
+  pub fn kind(&self) -> FrameRateKind {
+    match self {
+      Self::_23_97 => FrameRateKind::Std23_97,
+      Self::_24_00 => FrameRateKind::Std24,
+      Self::_24_97 => FrameRateKind::Std24_97,
+      Self::_25_00 => FrameRateKind::Std25,
+      Self::_29_97 => FrameRateKind::Std29_97,
+      Self::_30_00 => FrameRateKind::Std30,
+      Self::_50_00 => FrameRateKind::Std50,
+      Self::_59_94 => FrameRateKind::Std59_94,
+      Self::_60_00 => FrameRateKind::Std60,
+      Self::_120_00 => FrameRateKind::Std120,
+      Self::FrCustom(_) => FrameRateKind::Custom,
+    }
+  }
+}
+
+/// Same names as the standard rates' `Display`, `"custom"` otherwise.
+impl fmt::Display for FrameRateKind {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      Self::Std23_97 => "23.976",
+      Self::Std24 => "24",
+      Self::Std24_97 => "24.975",
+      Self::Std25 => "25",
+      Self::Std29_97 => "29.97",
+      Self::Std30 => "30",
+      Self::Std50 => "50",
+      Self::Std59_94 => "59.94",
+      Self::Std60 => "60",
+      Self::Std120 => "120",
+      Self::Custom => "custom",
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::STANDARD_FRAME_RATES;
+
+  #[test]
+  fn kind() {
+    for frame_rate in STANDARD_FRAME_RATES {
+      assert_ne!(frame_rate.kind(), FrameRateKind::Custom);
+      assert_eq!(frame_rate.kind().to_string(), frame_rate.to_string());
+    }
+    assert_eq!(FrameRate::new(48, 1).kind(), FrameRateKind::Custom);
+    assert_eq!(FrameRate::new(30000, 1001).kind(), FrameRateKind::Std29_97);
+    assert_eq!(FrameRateKind::Custom.to_string(), "custom");
+  }
+
+  #[test]
+  fn serde() {
+    assert_eq!(
+      serde_json::to_string(&FrameRateKind::Std29_97).unwrap(),
+      "\"std29_97\""
+    );
+    assert_eq!(
+      serde_json::from_str::<FrameRateKind>("\"custom\"").unwrap(),
+      FrameRateKind::Custom
+    );
+  }
+}
@@ -0,0 +1,69 @@
+use crate::{FrameRate, Ratio};
+
+impl FrameRate {
+  /// Rates reachable from this one by keeping every n-th frame, this rate
+  /// first, down to `min` included, e.g. 59.94, 29.97, 19.98 and 14.985 for
+  /// adaptive streaming renditions of a 59.94 source. Empty for a zero
+  /// `min`, which would make the ladder endless, and cut short at the first
+  /// rate whose denominator does not fit in 32 bits.
+  pub fn compatible_ladder(&self, min: FrameRate) -> Vec<FrameRate> {
+    let ratio = Ratio::<u32>::from(*self);
+    let min = Ratio::<u32>::from(min);
+    if *min.numer() == 0 {
+      return Vec::new();
+    }
+    (1..)
+      .map(|step| Ratio::new(u64::from(*ratio.numer()), u64::from(*ratio.denom()) * step))
+      .take_while(|rung| {
+        rung.numer() * u64::from(*min.denom()) >= rung.denom() * u64::from(*min.numer())
+      })
+      .map_while(|rung| {
+        Some(FrameRate::new(
+          u32::try_from(*rung.numer()).ok()?,
+          u32::try_from(*rung.denom()).ok()?,
+        ))
+      })
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn compatible_ladder() {
+    assert_eq!(
+      FrameRate::_59_94.compatible_ladder(FrameRate::new(12, 1)),
+      [
+        FrameRate::_59_94,
+        FrameRate::_29_97,
+        FrameRate::new(20000, 1001),
+        FrameRate::new(15000, 1001),
+      ]
+    );
+    assert_eq!(
+      FrameRate::_50_00.compatible_ladder(FrameRate::_25_00),
+      [FrameRate::_50_00, FrameRate::_25_00]
+    );
+    assert!(
+      FrameRate::_24_00
+        .compatible_ladder(FrameRate::_25_00)
+        .is_empty()
+    );
+    assert!(
+      FrameRate::_24_00
+        .compatible_ladder(FrameRate::new(0, 1))
+        .is_empty()
+    );
+    assert!(
+      FrameRate::new(0, 1)
+        .compatible_ladder(FrameRate::_24_00)
+        .is_empty()
+    );
+    assert_eq!(
+      FrameRate::new(3, 1 << 31).compatible_ladder(FrameRate::new(1, u32::MAX)),
+      [FrameRate::new(3, 1 << 31)]
+    );
+  }
+}
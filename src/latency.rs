@@ -0,0 +1,94 @@
+use crate::{FrameRate, Rounding};
+use std::fmt;
+use std::time::Duration;
+
+/// Latency counted in frames at a given rate, as in glass-to-glass latency
+/// reports.
+///
+/// ```
+/// use frame_rate::{FrameLatency, FrameRate};
+///
+/// let latency = FrameLatency::new(FrameRate::_29_97, 3);
+/// assert_eq!(latency.to_string(), "3 frames (100.1 ms @29.97)");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FrameLatency {
+  frame_rate: FrameRate,
+  frames: u64,
+}
+
+impl FrameLatency {
+  pub fn new(frame_rate: FrameRate, frames: u64) -> Self {
+    Self { frame_rate, frames }
+  }
+
+  /// Smallest number of frames covering `duration`.
+  pub fn from_duration(frame_rate: FrameRate, duration: Duration) -> Self {
+    Self::new(
+      frame_rate,
+      frame_rate.duration_to_frames(duration, Rounding::Ceil),
+    )
+  }
+
+  pub fn frame_rate(&self) -> FrameRate {
+    self.frame_rate
+  }
+
+  pub fn frames(&self) -> u64 {
+    self.frames
+  }
+
+  /// Latency as time, rounded to the nearest nanosecond.
+  pub fn duration(&self) -> Duration {
+    self.frame_rate.duration_of(self.frames)
+  }
+}
+
+impl fmt::Display for FrameLatency {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let frames = if self.frames == 1 { "frame" } else { "frames" };
+    let micros = Rounding::Nearest.div(self.duration().as_nanos(), 1000);
+    write!(f, "{} {frames} ({}", self.frames, micros / 1000)?;
+    if !micros.is_multiple_of(1000) {
+      let fraction = format!("{:03}", micros % 1000);
+      write!(f, ".{}", fraction.trim_end_matches('0'))?;
+    }
+    write!(f, " ms @{})", self.frame_rate)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn conversions() {
+    let latency = FrameLatency::from_duration(FrameRate::_25_00, Duration::from_millis(100));
+    assert_eq!(latency.frames(), 3);
+    assert_eq!(latency.duration(), Duration::from_millis(120));
+
+    let latency = FrameLatency::from_duration(FrameRate::_29_97, Duration::from_millis(100));
+    assert_eq!(latency.frames(), 3);
+    assert_eq!(latency.duration(), Duration::from_micros(100_100));
+  }
+
+  #[test]
+  fn display() {
+    assert_eq!(
+      FrameLatency::new(FrameRate::_29_97, 3).to_string(),
+      "3 frames (100.1 ms @29.97)"
+    );
+    assert_eq!(
+      FrameLatency::new(FrameRate::_29_97, 1).to_string(),
+      "1 frame (33.367 ms @29.97)"
+    );
+    assert_eq!(
+      FrameLatency::new(FrameRate::_50_00, 0).to_string(),
+      "0 frames (0 ms @50)"
+    );
+    assert_eq!(
+      FrameLatency::new(FrameRate::_25_00, 2).to_string(),
+      "2 frames (80 ms @25)"
+    );
+  }
+}
@@ -0,0 +1,121 @@
+use crate::{FrameRate, Ratio};
+
+/// Synchronization of a camera with an LED wall, see
+/// [`FrameRate::led_wall_sync`].
+///
+/// A camera exposure covering a fraction of a wall refresh cycle records the
+/// wall mid-scan, showing banding. Compatible combinations have a whole number
+/// of refresh cycles per frame and per exposure.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct LedWallSync {
+  frame_rate: FrameRate,
+  wall_refresh: Ratio<u64>,
+}
+
+impl FrameRate {
+  /// Sync of a camera at this rate with a wall refreshing at `wall_refresh`
+  /// Hz, e.g. 24 fps with a 3840 Hz wall.
+  pub fn led_wall_sync(&self, wall_refresh: Ratio<u32>) -> LedWallSync {
+    LedWallSync {
+      frame_rate: *self,
+      wall_refresh: Ratio::new(
+        u64::from(*wall_refresh.numer()),
+        u64::from(*wall_refresh.denom()),
+      ),
+    }
+  }
+}
+
+impl LedWallSync {
+  fn frame_rate(&self) -> Ratio<u64> {
+    let ratio = Ratio::<u32>::from(self.frame_rate);
+    Ratio::new(u64::from(*ratio.numer()), u64::from(*ratio.denom()))
+  }
+
+  /// Wall refresh cycles during one camera frame.
+  pub fn refreshes_per_frame(&self) -> Ratio<u64> {
+    self.wall_refresh / self.frame_rate()
+  }
+
+  /// Whether each frame spans a whole number of refresh cycles.
+  pub fn is_compatible(&self) -> bool {
+    self.refreshes_per_frame().is_integer()
+  }
+
+  /// Wall refresh rate closest to the current one with a whole number of
+  /// cycles per frame, to genlock the wall to, e.g. 3836.16 Hz for a 23.976
+  /// fps camera next to a 3840 Hz wall.
+  pub fn compatible_refresh(&self) -> Ratio<u64> {
+    let cycles = self
+      .refreshes_per_frame()
+      .round()
+      .max(Ratio::from_integer(1));
+    cycles * self.frame_rate()
+  }
+
+  /// Wall refresh cycles during an exposure at `shutter_angle` degrees.
+  pub fn refreshes_per_exposure(&self, shutter_angle: Ratio<u32>) -> Ratio<u64> {
+    let shutter_angle = Ratio::new(
+      u64::from(*shutter_angle.numer()),
+      u64::from(*shutter_angle.denom()),
+    );
+    self.refreshes_per_frame() * shutter_angle / Ratio::from_integer(360)
+  }
+
+  /// Whether an exposure at `shutter_angle` degrees spans a whole, non zero
+  /// number of refresh cycles.
+  pub fn is_shutter_compatible(&self, shutter_angle: Ratio<u32>) -> bool {
+    let cycles = self.refreshes_per_exposure(shutter_angle);
+    cycles.is_integer() && *cycles.numer() != 0
+  }
+
+  /// Every shutter angle up to 360° spanning a whole number of refresh cycles,
+  /// in ascending order. Empty when frames do not span a whole number of
+  /// cycles.
+  pub fn compatible_shutter_angles(&self) -> Vec<Ratio<u64>> {
+    if !self.is_compatible() {
+      return Vec::new();
+    }
+    let cycles = self.refreshes_per_frame().to_integer();
+    (1..=cycles)
+      .map(|cycle| Ratio::new(360 * cycle, cycles))
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn compatible() {
+    let sync = FrameRate::_24_00.led_wall_sync(Ratio::from_integer(3840));
+    assert_eq!(sync.refreshes_per_frame(), Ratio::from_integer(160));
+    assert!(sync.is_compatible());
+    assert!(sync.is_shutter_compatible(Ratio::from_integer(180)));
+    assert!(sync.is_shutter_compatible(Ratio::from_integer(360)));
+    assert!(!sync.is_shutter_compatible(Ratio::new(1, 2)));
+    assert!(!sync.is_shutter_compatible(Ratio::from_integer(0)));
+    assert_eq!(
+      sync.refreshes_per_exposure(Ratio::new(1729, 10)),
+      Ratio::new(3458, 45)
+    );
+
+    let angles = sync.compatible_shutter_angles();
+    assert_eq!(angles.len(), 160);
+    assert_eq!(angles[0], Ratio::new(9, 4));
+    assert_eq!(angles[79], Ratio::from_integer(180));
+  }
+
+  #[test]
+  fn incompatible() {
+    let sync = FrameRate::_23_97.led_wall_sync(Ratio::from_integer(3840));
+    assert_eq!(sync.refreshes_per_frame(), Ratio::new(4004, 25));
+    assert!(!sync.is_compatible());
+    assert!(sync.compatible_shutter_angles().is_empty());
+    assert_eq!(sync.compatible_refresh(), Ratio::new(3_840_000, 1001));
+
+    let genlocked = FrameRate::_23_97.led_wall_sync(Ratio::new(3_840_000, 1001));
+    assert!(genlocked.is_compatible());
+  }
+}
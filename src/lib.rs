@@ -1,7 +1,162 @@
+mod animation;
+mod archival;
+mod atomic;
+mod av1;
+mod batch;
+mod bitrate;
+mod bounded;
+mod breakdown;
+mod broadcast;
+#[cfg(feature = "capnp")]
+pub mod capnp;
+mod change;
+mod chunk;
+mod clip;
+mod conform;
+#[cfg(feature = "test-util")]
+pub mod conformance;
+mod continuity;
+mod convert;
+mod counter;
+mod decimation;
+#[cfg(feature = "miette")]
+mod diagnostic;
+#[cfg(feature = "display")]
+mod display;
+#[cfg(feature = "dpx")]
+pub mod dpx;
+mod edid;
+mod equivalence;
+mod error;
+mod exact;
+#[cfg(feature = "exr")]
+pub mod exr;
+mod fcpxml;
+#[cfg(feature = "flatbuffers")]
+pub mod flatbuffers;
+#[cfg(feature = "async-graphql")]
+mod graphql;
+pub mod hrd;
+mod human;
+mod jam;
+mod kind;
+mod ladder;
+mod latency;
+mod led;
+mod limits;
+mod log;
+mod macros;
+mod metadata;
+pub mod mpegts;
+mod multicam;
+mod ntsc;
+mod ops;
+mod order;
+#[cfg(feature = "otio")]
+pub mod otio;
+mod parse;
+pub mod per_rate;
+mod premiere;
+mod preset;
+mod progress;
+#[cfg(feature = "prost")]
+mod proto;
+mod pulldown;
+#[cfg(feature = "redis")]
+mod redis;
+mod relative;
+mod remap;
+mod retransmit;
+mod scan;
+mod seek;
+mod segmented;
+pub mod sequence;
+mod sizing;
+mod slate;
+mod sleep;
+mod snap;
+pub mod st2110;
+mod string;
+mod ticks;
+mod time_range;
+mod timecode;
+mod timeline;
+mod timeout;
+#[cfg(feature = "ts-rs")]
+mod ts;
+#[cfg(feature = "uniffi")]
+mod uniffi;
+mod vic;
+mod vui;
+#[cfg(feature = "tokio")]
+mod watch;
+mod watchdog;
+
+pub use atomic::AtomicFrameRate;
+pub use av1::Av1TimingInfo;
+pub use bounded::BoundedFrameRate;
+pub use breakdown::Breakdown;
+pub use broadcast::BroadcastDay;
+pub use change::RateChange;
+pub use chunk::{Chunk, ChunkAlignment};
+pub use clip::{ClipRange, RangeRounding};
+pub use conform::{ConformPlan, ConformStats};
+pub use continuity::Discontinuity;
+pub use counter::{FrameCounter, FrameCounterEvent, FrameCounterStats};
+pub use decimation::Decimation;
+pub use edid::DetailedTiming;
+pub use equivalence::BroadcastEquivalence;
+pub use error::Error;
+pub use exact::{FRAMES_PER_DAY, FRAMES_PER_KILOSECOND, rates_with_integer_frames, total_duration};
+pub use jam::JammedTimecodeGenerator;
+pub use kind::FrameRateKind;
+pub use latency::FrameLatency;
+pub use led::LedWallSync;
+pub use metadata::{Family, FrameRateMetadata, METADATA};
+pub use multicam::{CameraSync, FrameAlignment};
+pub use ntsc::NTSC_FACTOR;
 pub use num_rational::Ratio;
+pub use order::DisplayOrder;
+pub use parse::{FrameRateParseError, ParseErrorKind, parse_frame_rate};
+pub use per_rate::PerRate;
+pub use premiere::PREMIERE_TICKS_PER_SECOND;
+pub use preset::{PRESETS, Preset, PresetRegistry};
+pub use progress::TranscodeProgress;
+#[cfg(feature = "prost")]
+pub use proto::{FRAME_RATE_PROTO, FrameRateProto};
+pub use pulldown::{CADENCES, Cadence};
+pub use relative::parse_relative;
+pub use remap::{SpeedKeyframe, TimeRemap};
+pub use retransmit::RetransmitBackoff;
+pub use scan::{Field, FieldOrder, ScanMode};
+pub use seek::SeekTarget;
+pub use segmented::SegmentedFrameRate;
+pub use sequence::ImageSequence;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+pub use slate::{SlateReading, read_slate};
+pub use snap::{SnapCandidate, SnapProfile};
+use std::fmt;
+use std::time::Duration;
+pub use string::FrameRateString;
+pub use ticks::Ticks;
+pub use time_range::TimeRange;
+pub use timecode::Timecode;
+pub use timeline::{Timeline, TimelineSegment};
+pub use timeout::GracePeriod;
+#[cfg(feature = "uniffi")]
+pub use uniffi::FrameRateRecord;
+pub use vic::{VICS, Vic};
+pub use vui::{VuiCodec, VuiTiming};
+#[cfg(feature = "tokio")]
+pub use watch::FrameRateWatch;
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg(feature = "uniffi")]
+::uniffi::setup_scaffolding!();
+#[cfg(feature = "tokio")]
+pub use watchdog::AsyncFrameWatchdog;
+pub use watchdog::FrameWatchdog;
+
+#[derive(Clone, Copy, Eq, PartialEq)]
 pub enum FrameRate {
   _24_00,
   _25_00,
@@ -16,6 +171,19 @@ pub enum FrameRate {
   FrCustom(Ratio<u32>),
 }
 
+const STANDARD_FRAME_RATES: [FrameRate; 10] = [
+  FrameRate::_24_00,
+  FrameRate::_25_00,
+  FrameRate::_30_00,
+  FrameRate::_50_00,
+  FrameRate::_60_00,
+  FrameRate::_120_00,
+  FrameRate::_23_97,
+  FrameRate::_24_97,
+  FrameRate::_29_97,
+  FrameRate::_59_94,
+];
+
 impl utoipa::ToSchema for FrameRate {
   fn name() -> std::borrow::Cow<'static, str> {
     std::borrow::Cow::Borrowed("FrameRate")
@@ -24,7 +192,48 @@ impl utoipa::ToSchema for FrameRate {
 
 impl utoipa::PartialSchema for FrameRate {
   fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
-    utoipa::openapi::ObjectBuilder::new().into()
+    use utoipa::openapi::{KnownFormat, ObjectBuilder, SchemaFormat, Type};
+
+    // Both fields are u32, wider than the int32 format.
+    let integer = |minimum: u32| {
+      ObjectBuilder::new()
+        .schema_type(Type::Integer)
+        .format(Some(SchemaFormat::KnownFormat(KnownFormat::Int64)))
+        .minimum(Some(minimum))
+        .maximum(Some(u32::MAX))
+    };
+
+    let mut description = String::from("Exact frame rate ratio. Known presets:");
+    for metadata in &METADATA {
+      description.push_str(&format!(
+        "\n- {}: {}/{}",
+        metadata.name, metadata.num, metadata.den
+      ));
+    }
+
+    ObjectBuilder::new()
+      .description(Some(description))
+      .examples(
+        METADATA
+          .iter()
+          .map(|metadata| serde_json::json!({"num": metadata.num, "den": metadata.den})),
+      )
+      .property("num", integer(0))
+      .required("num")
+      .property("den", integer(1))
+      .required("den")
+      .into()
+  }
+}
+
+#[cfg(feature = "arbitrary")]
+impl<'a> arbitrary::Arbitrary<'a> for FrameRate {
+  fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+    if u.arbitrary()? {
+      Ok(*u.choose(&STANDARD_FRAME_RATES)?)
+    } else {
+      Ok(Self::new(u.arbitrary()?, u.int_in_range(1..=u32::MAX)?))
+    }
   }
 }
 
@@ -32,8 +241,199 @@ impl FrameRate {
   pub fn new(num: u32, den: u32) -> Self {
     Ratio::new(num, den).into()
   }
+
+  /// Like [`FrameRate::new`], but returns an error instead of panicking on a
+  /// zero denominator. Meant for `{num, den}` pairs decoded from the wire,
+  /// e.g. the Cap'n Proto and FlatBuffers structs in `schema/`.
+  pub fn try_new(num: u32, den: u32) -> Result<Self, Error> {
+    if den == 0 {
+      return Err(Error::ZeroDenominator);
+    }
+    Ok(Self::new(num, den))
+  }
+
+  /// Reduces `num / den` before checking that it fits in a `Ratio<u32>`,
+  /// e.g. `8_000_000_000 / 4_000_000` is 2000 fps.
+  pub fn new_u64(num: u64, den: u64) -> Result<Self, Error> {
+    if den == 0 {
+      return Err(Error::ZeroDenominator);
+    }
+    let ratio = Ratio::new(num, den);
+    match (u32::try_from(*ratio.numer()), u32::try_from(*ratio.denom())) {
+      (Ok(num), Ok(den)) => Ok(Self::new(num, den)),
+      _ => Err(Error::FrameRateOutOfRange {
+        num: *ratio.numer(),
+        den: *ratio.denom(),
+      }),
+    }
+  }
+
+  /// Frame rate as `f64`, with whether the conversion is exact: true for
+  /// integer rates and halves like 12.5, false for 1001-based ones.
+  pub fn to_f64_exactness(&self) -> (f64, bool) {
+    let ratio = Ratio::<u32>::from(*self);
+    (f64::from(self), ratio.denom().is_power_of_two())
+  }
+
+  /// Time between two consecutive frames, rounded to the nearest nanosecond.
+  ///
+  /// For rates below 1 fps this is longer than a second, e.g. 10 s at 1/10 fps.
+  pub fn interval(&self) -> Duration {
+    self.duration_of(1)
+  }
+
+  /// Duration of `frames` frames, rounded to the nearest nanosecond.
+  pub(crate) fn duration_of(&self, frames: u64) -> Duration {
+    self.frames_to_duration(frames, Rounding::Nearest)
+  }
+
+  /// Duration of `frames` frames, rounded to a nanosecond and saturating at
+  /// `Duration::MAX`, which is also the result of frames at 0 fps. Computed
+  /// exactly, without going through `f64`.
+  ///
+  /// ```
+  /// use frame_rate::{FrameRate, Rounding};
+  /// use std::time::Duration;
+  ///
+  /// let frame_rate = FrameRate::_29_97;
+  /// assert_eq!(frame_rate.frames_to_duration(30_000, Rounding::Nearest), Duration::from_secs(1001));
+  /// assert_eq!(frame_rate.frames_to_duration(1, Rounding::Floor), Duration::from_nanos(33_366_666));
+  /// assert_eq!(frame_rate.frames_to_duration(1, Rounding::Ceil), Duration::from_nanos(33_366_667));
+  /// ```
+  pub fn frames_to_duration(&self, frames: u64, rounding: Rounding) -> Duration {
+    self
+      .checked_frames_to_duration(frames, rounding)
+      .unwrap_or(Duration::MAX)
+  }
+
+  /// Duration of `frames` frames, rounded to a nanosecond, or `None` when it
+  /// does not fit in a `Duration` or is endless at 0 fps.
+  pub fn checked_frames_to_duration(&self, frames: u64, rounding: Rounding) -> Option<Duration> {
+    if frames == 0 {
+      return Some(Duration::ZERO);
+    }
+    let ratio = Ratio::<u32>::from(*self);
+    if *ratio.numer() == 0 {
+      return None;
+    }
+    let nanos = rounding.div(
+      u128::from(frames) * u128::from(*ratio.denom()) * 1_000_000_000,
+      u128::from(*ratio.numer()),
+    );
+    Some(Duration::new(
+      u64::try_from(nanos / 1_000_000_000).ok()?,
+      (nanos % 1_000_000_000) as u32,
+    ))
+  }
+
+  /// Number of frames in `duration`, saturating at `u64::MAX`. With
+  /// [`Rounding::Floor`] this is the index of the frame shown at `duration`.
+  ///
+  /// ```
+  /// use frame_rate::{FrameRate, Rounding};
+  /// use std::time::Duration;
+  ///
+  /// let frame_rate = FrameRate::_29_97;
+  /// let duration = Duration::from_secs(1001);
+  /// assert_eq!(frame_rate.duration_to_frames(duration, Rounding::Floor), 30_000);
+  /// assert_eq!(frame_rate.duration_to_frames(Duration::from_secs(1), Rounding::Floor), 29);
+  /// assert_eq!(frame_rate.duration_to_frames(Duration::from_secs(1), Rounding::Ceil), 30);
+  /// ```
+  pub fn duration_to_frames(&self, duration: Duration, rounding: Rounding) -> u64 {
+    self
+      .checked_duration_to_frames(duration, rounding)
+      .unwrap_or(u64::MAX)
+  }
+
+  /// Number of frames in `duration`, or `None` when it does not fit in a
+  /// `u64`.
+  ///
+  /// ```
+  /// use frame_rate::{FrameRate, Rounding};
+  /// use std::time::Duration;
+  ///
+  /// let frame_rate = FrameRate::_120_00;
+  /// assert_eq!(frame_rate.checked_duration_to_frames(Duration::from_secs(1), Rounding::Floor), Some(120));
+  /// assert_eq!(frame_rate.checked_duration_to_frames(Duration::MAX, Rounding::Floor), None);
+  /// ```
+  pub fn checked_duration_to_frames(&self, duration: Duration, rounding: Rounding) -> Option<u64> {
+    let ratio = Ratio::<u32>::from(*self);
+    let frames = rounding.div(
+      duration.as_nanos() * u128::from(*ratio.numer()),
+      u128::from(*ratio.denom()) * 1_000_000_000,
+    );
+    u64::try_from(frames).ok()
+  }
+
+  /// Index of the frame at `frame_rate` matching the start of `frame` at this
+  /// rate, saturating at `u64::MAX` like when this rate is 0 fps.
+  pub(crate) fn convert_frame(&self, frame: u64, frame_rate: FrameRate, rounding: Rounding) -> u64 {
+    let from = Ratio::<u32>::from(*self);
+    let to = Ratio::<u32>::from(frame_rate);
+    if *from.numer() == 0 {
+      return u64::MAX;
+    }
+    let factor = Ratio::new(
+      u64::from(*from.denom()) * u64::from(*to.numer()),
+      u64::from(*from.numer()) * u64::from(*to.denom()),
+    );
+    let frame = rounding.div(
+      u128::from(frame) * u128::from(*factor.numer()),
+      u128::from(*factor.denom()),
+    );
+    u64::try_from(frame).unwrap_or(u64::MAX)
+  }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "uniffi", derive(::uniffi::Enum))]
+pub enum Rounding {
+  /// Round towards zero.
+  Floor,
+  /// Round away from zero.
+  Ceil,
+  /// Round to the nearest value, halfway cases away from zero.
+  Nearest,
 }
 
+impl Rounding {
+  pub(crate) fn div(self, numerator: u128, denominator: u128) -> u128 {
+    match self {
+      Self::Floor => numerator / denominator,
+      Self::Ceil => numerator.div_ceil(denominator),
+      Self::Nearest => {
+        let remainder = numerator % denominator;
+        numerator / denominator + u128::from(remainder >= denominator - remainder)
+      }
+    }
+  }
+
+  /// Rounds a fractional frame count, snapping values within
+  /// `FRAME_EPSILON` of a whole frame onto it so float noise such as
+  /// `2.0000000000000004` does not round up. `None` for negative or invalid
+  /// values.
+  pub(crate) fn round_frames(self, frames: f64) -> Option<u64> {
+    if !frames.is_finite() || frames < -FRAME_EPSILON {
+      return None;
+    }
+
+    let nearest = frames.round();
+    let frames = if (frames - nearest).abs() < FRAME_EPSILON {
+      nearest
+    } else {
+      match self {
+        Self::Floor => frames.floor(),
+        Self::Ceil => frames.ceil(),
+        Self::Nearest => nearest,
+      }
+    };
+    Some(frames.max(0.0) as u64)
+  }
+}
+
+/// Values within this distance of a whole frame are considered to be on it.
+const FRAME_EPSILON: f64 = 1e-6;
+
 impl From<&FrameRate> for f64 {
   fn from(frame_rate: &FrameRate) -> Self {
     let ratio: Ratio<u32> = (*frame_rate).into();
@@ -41,6 +441,38 @@ impl From<&FrameRate> for f64 {
   }
 }
 
+impl From<FrameRate> for f64 {
+  fn from(frame_rate: FrameRate) -> Self {
+    Self::from(&frame_rate)
+  }
+}
+
+impl From<&FrameRate> for f32 {
+  fn from(frame_rate: &FrameRate) -> Self {
+    f64::from(frame_rate) as f32
+  }
+}
+
+impl From<FrameRate> for f32 {
+  fn from(frame_rate: FrameRate) -> Self {
+    Self::from(&frame_rate)
+  }
+}
+
+/// Only succeeds for whole frame rates, e.g. 25 but not 29.97.
+impl TryFrom<FrameRate> for u32 {
+  type Error = Error;
+
+  fn try_from(frame_rate: FrameRate) -> Result<Self, Self::Error> {
+    let ratio = Ratio::<u32>::from(frame_rate);
+    if ratio.is_integer() {
+      Ok(ratio.to_integer())
+    } else {
+      Err(Error::NotAnIntegerFrameRate(frame_rate))
+    }
+  }
+}
+
 impl From<FrameRate> for Ratio<u32> {
   fn from(frame_rate: FrameRate) -> Self {
     match frame_rate {
@@ -77,7 +509,61 @@ impl From<Ratio<u32>> for FrameRate {
   }
 }
 
+impl fmt::Display for FrameRate {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      Self::_24_00 => f.write_str("24"),
+      Self::_25_00 => f.write_str("25"),
+      Self::_30_00 => f.write_str("30"),
+      Self::_50_00 => f.write_str("50"),
+      Self::_60_00 => f.write_str("60"),
+      Self::_120_00 => f.write_str("120"),
+      Self::_23_97 => f.write_str("23.976"),
+      Self::_24_97 => f.write_str("24.975"),
+      Self::_29_97 => f.write_str("29.97"),
+      Self::_59_94 => f.write_str("59.94"),
+      Self::FrCustom(_) if let Some(name) = PRESETS.name_of(*self) => f.write_str(&name),
+      // Below 1 fps, spell out the interval between frames.
+      Self::FrCustom(rational) if *rational.numer() != 0 && rational.numer() < rational.denom() => {
+        let frames = if *rational.numer() == 1 {
+          "frame"
+        } else {
+          "frames"
+        };
+        write!(f, "{} {frames} / {} s", rational.numer(), rational.denom())
+      }
+      Self::FrCustom(rational) => write!(f, "{}/{}", rational.numer(), rational.denom()),
+    }
+  }
+}
+
+/// Prints `FrameRate(29.97 [30000/1001])`, or the variant as declared with
+/// `{:#?}`.
+impl fmt::Debug for FrameRate {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    if !f.alternate() {
+      let ratio = Ratio::<u32>::from(*self);
+      return write!(f, "FrameRate({self} [{}/{}])", ratio.numer(), ratio.denom());
+    }
+
+    match self {
+      Self::_24_00 => f.write_str("_24_00"),
+      Self::_25_00 => f.write_str("_25_00"),
+      Self::_30_00 => f.write_str("_30_00"),
+      Self::_50_00 => f.write_str("_50_00"),
+      Self::_60_00 => f.write_str("_60_00"),
+      Self::_120_00 => f.write_str("_120_00"),
+      Self::_23_97 => f.write_str("_23_97"),
+      Self::_24_97 => f.write_str("_24_97"),
+      Self::_29_97 => f.write_str("_29_97"),
+      Self::_59_94 => f.write_str("_59_94"),
+      Self::FrCustom(rational) => f.debug_tuple("FrCustom").field(rational).finish(),
+    }
+  }
+}
+
 #[derive(Serialize, Deserialize)]
+#[cfg_attr(feature = "ts-rs", derive(ts_rs::TS), ts(rename = "FrameRate"))]
 struct SerializeRational {
   num: u32,
   den: u32,
@@ -121,6 +607,113 @@ impl<'de> Deserialize<'de> for FrameRate {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use proptest::prelude::*;
+
+  pub(crate) fn any_frame_rate() -> impl Strategy<Value = FrameRate> {
+    prop_oneof![
+      proptest::sample::select(STANDARD_FRAME_RATES.to_vec()),
+      (any::<u32>(), 1..=u32::MAX).prop_map(|(num, den)| FrameRate::new(num, den)),
+    ]
+  }
+
+  proptest! {
+    #[test]
+    fn ratio_round_trip(frame_rate in any_frame_rate()) {
+      prop_assert_eq!(FrameRate::from(Ratio::from(frame_rate)), frame_rate);
+    }
+
+    #[test]
+    fn serde_round_trip(frame_rate in any_frame_rate()) {
+      let value = serde_json::to_value(frame_rate).unwrap();
+      prop_assert_eq!(serde_json::from_value::<FrameRate>(value).unwrap(), frame_rate);
+    }
+
+    #[test]
+    fn duration_round_trip(
+      frame_rate in proptest::sample::select(STANDARD_FRAME_RATES.to_vec()),
+      frames in 0..1u64 << 40,
+    ) {
+      let floor = frame_rate.frames_to_duration(frames, Rounding::Floor);
+      let ceil = frame_rate.frames_to_duration(frames, Rounding::Ceil);
+      prop_assert!(ceil - floor <= Duration::from_nanos(1));
+      prop_assert_eq!(frame_rate.duration_to_frames(ceil, Rounding::Floor), frames);
+      prop_assert_eq!(frame_rate.duration_to_frames(floor, Rounding::Ceil), frames);
+      let nearest = frame_rate.frames_to_duration(frames, Rounding::Nearest);
+      prop_assert_eq!(frame_rate.duration_to_frames(nearest, Rounding::Nearest), frames);
+    }
+  }
+
+  #[test]
+  fn to_f64_exactness() {
+    assert_eq!(FrameRate::_25_00.to_f64_exactness(), (25.0, true));
+    assert_eq!(FrameRate::new(25, 2).to_f64_exactness(), (12.5, true));
+    assert_eq!(
+      FrameRate::_29_97.to_f64_exactness(),
+      (30000.0 / 1001.0, false)
+    );
+    assert_eq!(FrameRate::new(1, 10).to_f64_exactness(), (0.1, false));
+  }
+
+  #[test]
+  fn zero_frame_rate() {
+    let frame_rate = FrameRate::new(0, 1);
+    assert_eq!(
+      frame_rate.checked_frames_to_duration(1, Rounding::Nearest),
+      None
+    );
+    assert_eq!(
+      frame_rate.frames_to_duration(1, Rounding::Floor),
+      Duration::MAX
+    );
+    assert_eq!(frame_rate.interval(), Duration::MAX);
+    assert_eq!(
+      frame_rate.checked_frames_to_duration(0, Rounding::Nearest),
+      Some(Duration::ZERO)
+    );
+    assert_eq!(
+      frame_rate.frames_to_duration(0, Rounding::Ceil),
+      Duration::ZERO
+    );
+    assert_eq!(
+      frame_rate.duration_to_frames(Duration::from_secs(10), Rounding::Ceil),
+      0
+    );
+    assert_eq!(
+      frame_rate.convert_frame(1, FrameRate::_25_00, Rounding::Nearest),
+      u64::MAX
+    );
+    assert_eq!(
+      FrameRate::_25_00.convert_frame(25, frame_rate, Rounding::Nearest),
+      0
+    );
+  }
+
+  #[test]
+  fn try_new() {
+    assert_eq!(FrameRate::try_new(60000, 1001), Ok(FrameRate::_59_94));
+    assert_eq!(FrameRate::try_new(48, 2), Ok(FrameRate::_24_00));
+    assert_eq!(FrameRate::try_new(25, 0), Err(Error::ZeroDenominator));
+  }
+
+  #[test]
+  fn new_u64() {
+    assert_eq!(
+      FrameRate::new_u64(8_000_000_000, 4_000_000),
+      Ok(FrameRate::new(2000, 1))
+    );
+    assert_eq!(
+      FrameRate::new_u64(30_000 << 32, 1001 << 32),
+      Ok(FrameRate::_29_97)
+    );
+    assert_eq!(FrameRate::new_u64(25, 0), Err(Error::ZeroDenominator));
+    assert_eq!(
+      FrameRate::new_u64(10_000_000_001, 2),
+      Err(Error::FrameRateOutOfRange {
+        num: 10_000_000_001,
+        den: 2
+      })
+    );
+  }
 
   #[test]
   fn rational_from_frame_rate() {
@@ -168,6 +761,77 @@ mod tests {
     assert_eq!(FrameRate::from(Ratio::new(200, 4)), FrameRate::_50_00);
   }
 
+  #[test]
+  fn float_and_integer_conversions() {
+    assert_eq!(f64::from(FrameRate::_25_00), 25.0);
+    assert_eq!(f64::from(FrameRate::_29_97), 30000.0 / 1001.0);
+    assert_eq!(f32::from(FrameRate::_29_97), 30000.0 / 1001.0);
+    assert_eq!(f32::from(&FrameRate::_50_00), 50.0);
+
+    assert_eq!(u32::try_from(FrameRate::_25_00), Ok(25));
+    assert_eq!(u32::try_from(FrameRate::_120_00), Ok(120));
+    assert_eq!(u32::try_from(FrameRate::new(48, 1)), Ok(48));
+    assert_eq!(
+      u32::try_from(FrameRate::_29_97),
+      Err(Error::NotAnIntegerFrameRate(FrameRate::_29_97))
+    );
+    assert_eq!(
+      u32::try_from(FrameRate::new(1, 10)),
+      Err(Error::NotAnIntegerFrameRate(FrameRate::new(1, 10)))
+    );
+  }
+
+  #[test]
+  fn display() {
+    assert_eq!(FrameRate::_24_00.to_string(), "24");
+    assert_eq!(FrameRate::_25_00.to_string(), "25");
+    assert_eq!(FrameRate::_30_00.to_string(), "30");
+    assert_eq!(FrameRate::_50_00.to_string(), "50");
+    assert_eq!(FrameRate::_60_00.to_string(), "60");
+    assert_eq!(FrameRate::_120_00.to_string(), "120");
+    assert_eq!(FrameRate::_23_97.to_string(), "23.976");
+    assert_eq!(FrameRate::_24_97.to_string(), "24.975");
+    assert_eq!(FrameRate::_29_97.to_string(), "29.97");
+    assert_eq!(FrameRate::_59_94.to_string(), "59.94");
+    assert_eq!(FrameRate::new(48, 1).to_string(), "48/1");
+    assert_eq!(FrameRate::new(25, 2).to_string(), "25/2");
+    assert_eq!(FrameRate::new(1, 10).to_string(), "1 frame / 10 s");
+    assert_eq!(FrameRate::new(2, 25).to_string(), "2 frames / 25 s");
+    assert_eq!(FrameRate::new(0, 1).to_string(), "0/1");
+  }
+
+  #[test]
+  fn debug() {
+    assert_eq!(format!("{:?}", FrameRate::_25_00), "FrameRate(25 [25/1])");
+    assert_eq!(
+      format!("{:?}", FrameRate::_29_97),
+      "FrameRate(29.97 [30000/1001])"
+    );
+    assert_eq!(
+      format!("{:?}", FrameRate::new(1, 10)),
+      "FrameRate(1 frame / 10 s [1/10])"
+    );
+    assert_eq!(format!("{:#?}", FrameRate::_29_97), "_29_97");
+    assert_eq!(
+      format!("{:#?}", FrameRate::new(25, 2)),
+      "FrCustom(\n    Ratio {\n        numer: 25,\n        denom: 2,\n    },\n)"
+    );
+  }
+
+  #[test]
+  fn interval() {
+    assert_eq!(FrameRate::_25_00.interval(), Duration::from_millis(40));
+    assert_eq!(
+      FrameRate::_29_97.interval(),
+      Duration::from_nanos(33_366_667)
+    );
+    assert_eq!(FrameRate::new(1, 10).interval(), Duration::from_secs(10));
+    assert_eq!(
+      FrameRate::new(2, 25).interval(),
+      Duration::from_millis(12_500)
+    );
+  }
+
   #[test]
   fn serialize() {
     assert_eq!(
@@ -256,6 +920,51 @@ mod tests {
     );
   }
 
+  #[test]
+  fn schema() {
+    use utoipa::PartialSchema;
+
+    let mut schema = serde_json::to_value(FrameRate::schema()).unwrap();
+    let description = schema["description"].take();
+    let examples = schema["examples"].take();
+    assert_eq!(
+      schema,
+      serde_json::json!({
+        "type": "object",
+        "description": null,
+        "examples": null,
+        "properties": {
+          "num": {
+            "type": "integer",
+            "format": "int64",
+            "minimum": 0,
+            "maximum": 4294967295u32
+          },
+          "den": {
+            "type": "integer",
+            "format": "int64",
+            "minimum": 1,
+            "maximum": 4294967295u32
+          }
+        },
+        "required": ["num", "den"]
+      })
+    );
+
+    let description = description.as_str().unwrap();
+    assert!(description.contains("\n- 29.97: 30000/1001\n"));
+    assert_eq!(description.lines().count(), METADATA.len() + 1);
+
+    let examples = examples.as_array().unwrap();
+    assert_eq!(examples.len(), METADATA.len());
+    for (example, frame_rate) in examples.iter().zip(FrameRate::standard_rates()) {
+      assert_eq!(
+        serde_json::from_value::<FrameRate>(example.clone()).unwrap(),
+        frame_rate
+      );
+    }
+  }
+
   #[test]
   fn deserialize() {
     assert_eq!(
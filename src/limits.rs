@@ -0,0 +1,57 @@
+use crate::{FrameRate, Rounding};
+use std::time::Duration;
+
+impl FrameRate {
+  /// Largest frame count lasting at most `max`, the largest value of a
+  /// duration type, e.g. `Duration::MAX` or `Duration::from_nanos(u64::MAX)`
+  /// for `u64` nanosecond timestamps. Saturates at `u64::MAX`.
+  pub fn max_frames_for(&self, max: Duration) -> u64 {
+    self.duration_to_frames(max, Rounding::Floor)
+  }
+
+  /// Duration of a timeline whose frame counter reaches `max_frames`, e.g.
+  /// `u32::MAX.into()` for 32-bit counters, or `None` when longer than
+  /// `Duration::MAX`.
+  pub fn max_duration_for(&self, max_frames: u64) -> Option<Duration> {
+    self.checked_frames_to_duration(max_frames, Rounding::Floor)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn max_frames_for() {
+    let nanos = Duration::from_nanos(u64::MAX);
+    // u64::MAX ns is 584.94 years.
+    assert_eq!(FrameRate::_25_00.max_frames_for(nanos), 461_168_601_842);
+    assert_eq!(FrameRate::_29_97.max_frames_for(nanos), 552_849_472_738);
+    assert!(FrameRate::_25_00.duration_of(461_168_601_842) <= nanos);
+    assert!(FrameRate::_25_00.duration_of(461_168_601_843) > nanos);
+
+    assert_eq!(FrameRate::_120_00.max_frames_for(Duration::MAX), u64::MAX);
+    assert_eq!(
+      FrameRate::new(1, 10).max_frames_for(Duration::MAX),
+      u64::MAX / 10
+    );
+  }
+
+  #[test]
+  fn max_duration_for() {
+    // A 32-bit counter at 60 fps wraps after 828 days.
+    assert_eq!(
+      FrameRate::_60_00.max_duration_for(u32::MAX.into()),
+      Some(Duration::new(71_582_788, 250_000_000))
+    );
+    assert_eq!(
+      FrameRate::_25_00.max_duration_for(u64::MAX),
+      Some(Duration::new(737_869_762_948_382_064, 600_000_000))
+    );
+    assert_eq!(FrameRate::new(1, 2).max_duration_for(u64::MAX), None);
+    assert_eq!(
+      FrameRate::new(1, 2).frames_to_duration(u64::MAX, Rounding::Floor),
+      Duration::MAX
+    );
+  }
+}
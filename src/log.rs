@@ -0,0 +1,56 @@
+use crate::{Family, FrameRate, Ratio};
+use serde_json::{Value, json};
+
+impl FrameRate {
+  /// Compact object to attach to log and tracing events, so dashboards can
+  /// facet on the rate: `fps` rounded to 3 decimals, the exact `num` and
+  /// `den`, and the `family` (`"integer"`, `"ntsc"`, or `null` for other
+  /// custom rates).
+  ///
+  /// ```
+  /// use frame_rate::FrameRate;
+  /// use serde_json::json;
+  ///
+  /// assert_eq!(
+  ///   FrameRate::_29_97.to_log_value(),
+  ///   json!({"fps": 29.97, "num": 30000, "den": 1001, "family": "ntsc"})
+  /// );
+  /// ```
+  pub fn to_log_value(&self) -> Value {
+    let ratio = Ratio::<u32>::from(*self);
+    let family = if *ratio.denom() == 1 {
+      Some(Family::Integer)
+    } else if self.has_ntsc_factor() {
+      Some(Family::Ntsc)
+    } else {
+      None
+    };
+    json!({
+      "fps": (f64::from(self) * 1000.0).round() / 1000.0,
+      "num": ratio.numer(),
+      "den": ratio.denom(),
+      "family": family,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn to_log_value() {
+    assert_eq!(
+      FrameRate::_23_97.to_log_value(),
+      json!({"fps": 23.976, "num": 24000, "den": 1001, "family": "ntsc"})
+    );
+    assert_eq!(
+      FrameRate::new(48, 1).to_log_value(),
+      json!({"fps": 48.0, "num": 48, "den": 1, "family": "integer"})
+    );
+    assert_eq!(
+      FrameRate::new(25, 2).to_log_value(),
+      json!({"fps": 12.5, "num": 25, "den": 2, "family": null})
+    );
+  }
+}
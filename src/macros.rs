@@ -0,0 +1,260 @@
+use crate::{FrameRate, Ratio, Timecode};
+
+/// Frame rate constant checked at compile time, from the decimal spelling of
+/// a standard rate, an integer, or a `num / den` ratio.
+///
+/// ```
+/// use frame_rate::{FrameRate, frame_rate};
+///
+/// assert_eq!(frame_rate!(29.97), FrameRate::_29_97);
+/// assert_eq!(frame_rate!(30000 / 1001), FrameRate::_29_97);
+/// assert_eq!(frame_rate!(48), FrameRate::new(48, 1));
+///
+/// const PAL: FrameRate = frame_rate!(50 / 2);
+/// assert_eq!(PAL, FrameRate::_25_00);
+/// ```
+///
+/// Other decimals and zero denominators do not compile:
+///
+/// ```compile_fail
+/// frame_rate::frame_rate!(12.5);
+/// ```
+///
+/// ```compile_fail
+/// frame_rate::frame_rate!(25 / 0);
+/// ```
+#[macro_export]
+macro_rules! frame_rate {
+  (23.976) => {
+    $crate::FrameRate::_23_97
+  };
+  (23.98) => {
+    $crate::FrameRate::_23_97
+  };
+  (24.975) => {
+    $crate::FrameRate::_24_97
+  };
+  (24.98) => {
+    $crate::FrameRate::_24_97
+  };
+  (29.97) => {
+    $crate::FrameRate::_29_97
+  };
+  (59.94) => {
+    $crate::FrameRate::_59_94
+  };
+  ($num:literal / $den:literal) => {
+    const { $crate::FrameRate::__from_literal($num, $den) }
+  };
+  ($num:literal) => {
+    const { $crate::FrameRate::__from_literal($num, 1) }
+  };
+}
+
+/// [`Timecode`] constant from a `HH:MM:SS:FF` label, or `HH:MM:SS;FF` for
+/// drop-frame, at a rate given as to [`frame_rate!`], checked at compile
+/// time.
+///
+/// ```
+/// use frame_rate::{Timecode, timecode};
+///
+/// assert_eq!(timecode!("00:00:05:12" @ 25).to_frames(), 137);
+/// assert_eq!(timecode!("01:00:00;02" @ 29.97).to_frames(), 107_894);
+///
+/// const OFFSET: Timecode = timecode!("00:10:00;00" @ 30000 / 1001);
+/// assert_eq!(OFFSET.to_string(), "00:10:00;00");
+/// ```
+///
+/// Labels skipped by drop-frame, drop-frame at rates without it and out of
+/// range fields do not compile:
+///
+/// ```compile_fail
+/// frame_rate::timecode!("00:01:00;01" @ 29.97);
+/// ```
+///
+/// ```compile_fail
+/// frame_rate::timecode!("00:00:00;00" @ 25);
+/// ```
+///
+/// ```compile_fail
+/// frame_rate::timecode!("00:00:00:25" @ 25);
+/// ```
+///
+/// ```compile_fail
+/// frame_rate::timecode!("00:00:00:00:00" @ 25);
+/// ```
+#[macro_export]
+macro_rules! timecode {
+  ($timecode:literal @ $($frame_rate:tt)+) => {
+    const { $crate::FrameRate::__timecode($crate::frame_rate!($($frame_rate)+), $timecode) }
+  };
+}
+
+impl FrameRate {
+  /// Reduced rate for [`frame_rate!`], panicking at compile time on a zero
+  /// denominator.
+  #[doc(hidden)]
+  pub const fn __from_literal(num: u32, den: u32) -> Self {
+    assert!(den != 0, "frame rate denominator is zero");
+    let (mut a, mut b) = (num, den);
+    while b != 0 {
+      (a, b) = (b, a % b);
+    }
+    match (num / a, den / a) {
+      (24, 1) => Self::_24_00,
+      (25, 1) => Self::_25_00,
+      (30, 1) => Self::_30_00,
+      (50, 1) => Self::_50_00,
+      (60, 1) => Self::_60_00,
+      (120, 1) => Self::_120_00,
+      (24000, 1001) => Self::_23_97,
+      (25000, 1001) => Self::_24_97,
+      (30000, 1001) => Self::_29_97,
+      (60000, 1001) => Self::_59_94,
+      (num, den) => Self::FrCustom(Ratio::new_raw(num, den)),
+    }
+  }
+
+  /// Timecode for [`timecode!`], panicking at compile time on invalid
+  /// labels. Same rules as [`Timecode::parse`].
+  #[doc(hidden)]
+  pub const fn __timecode(self, timecode: &str) -> Timecode {
+    let (frames, drop_frame) = self.timecode_label_frames(timecode);
+    Timecode::from_checked(self, frames, drop_frame)
+  }
+
+  const fn timecode_label_frames(self, timecode: &str) -> (u64, bool) {
+    let (num, den, drop_frame_rate) = match self {
+      Self::_29_97 => (30000, 1001, true),
+      Self::_59_94 => (60000, 1001, true),
+      Self::_23_97 => (24000, 1001, false),
+      Self::_24_97 => (25000, 1001, false),
+      Self::_24_00 => (24, 1, false),
+      Self::_25_00 => (25, 1, false),
+      Self::_30_00 => (30, 1, false),
+      Self::_50_00 => (50, 1, false),
+      Self::_60_00 => (60, 1, false),
+      Self::_120_00 => (120, 1, false),
+      Self::FrCustom(ratio) => (*ratio.numer(), *ratio.denom(), false),
+    };
+    assert!(num >= den, "timecode! needs a rate of at least 1 fps");
+    let nominal = num.div_ceil(den) as u64;
+
+    let ([hours, minutes, seconds, frames], drop_frame) = label_fields(timecode);
+    assert!(
+      minutes < 60 && seconds < 60 && frames < nominal,
+      "timecode field out of range"
+    );
+
+    let total_minutes = hours * 60 + minutes;
+    let count = (total_minutes * 60 + seconds) * nominal + frames;
+    if !drop_frame {
+      return (count, false);
+    }
+    assert!(drop_frame_rate, "no drop-frame timecode at this rate");
+    let dropped = nominal / 15;
+    assert!(
+      !(seconds == 0 && !minutes.is_multiple_of(10) && frames < dropped),
+      "timecode skipped by drop-frame"
+    );
+    (count - dropped * (total_minutes - total_minutes / 10), true)
+  }
+}
+
+/// Hours, minutes, seconds and frames of a label, each with any number of
+/// digits, and whether a `;` separates them.
+const fn label_fields(timecode: &str) -> ([u64; 4], bool) {
+  const INVALID: &str = "expected HH:MM:SS:FF or HH:MM:SS;FF";
+  let bytes = timecode.trim_ascii().as_bytes();
+  let mut fields = [0u64; 4];
+  let (mut field, mut digits, mut drop_frame) = (0, 0, false);
+  let mut index = 0;
+  while index < bytes.len() {
+    match bytes[index] {
+      digit @ b'0'..=b'9' => {
+        fields[field] = fields[field]
+          .checked_mul(10)
+          .expect("timecode field out of range")
+          .checked_add((digit - b'0') as u64)
+          .expect("timecode field out of range");
+        digits += 1;
+      }
+      separator @ (b':' | b';') => {
+        assert!(digits > 0 && field < 3, "{}", INVALID);
+        drop_frame |= separator == b';';
+        field += 1;
+        digits = 0;
+      }
+      _ => panic!("{}", INVALID),
+    }
+    index += 1;
+  }
+  assert!(digits > 0 && field == 3, "{}", INVALID);
+  (fields, drop_frame)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::STANDARD_FRAME_RATES;
+
+  #[test]
+  fn literals() {
+    assert_eq!(frame_rate!(23.976), FrameRate::_23_97);
+    assert_eq!(frame_rate!(23.98), FrameRate::_23_97);
+    assert_eq!(frame_rate!(24), FrameRate::_24_00);
+    assert_eq!(frame_rate!(24.975), FrameRate::_24_97);
+    assert_eq!(frame_rate!(60000 / 1001), FrameRate::_59_94);
+    assert_eq!(frame_rate!(0), FrameRate::new(0, 1));
+    assert_eq!(frame_rate!(96 / 4), FrameRate::_24_00);
+    assert_eq!(frame_rate!(2 / 20), FrameRate::new(1, 10));
+  }
+
+  #[test]
+  fn timecodes() {
+    assert_eq!(
+      timecode!("00:00:00:00" @ 24),
+      Timecode::new(FrameRate::_24_00, 0).unwrap()
+    );
+    assert_eq!(
+      timecode!("00:00:59;29" @ 29.97),
+      Timecode::new_drop_frame(FrameRate::_29_97, 1799).unwrap()
+    );
+    assert_eq!(timecode!("00:01:00;02" @ 29.97).to_frames(), 1800);
+    assert_eq!(timecode!("00:01:00;04" @ 59.94).to_frames(), 3600);
+    assert_eq!(
+      timecode!("00:01:00:00" @ 29.97),
+      Timecode::new(FrameRate::_29_97, 1800).unwrap()
+    );
+    assert_eq!(timecode!("23:59:59:23" @ 24).to_frames(), 24 * 86_400 - 1);
+    assert_eq!(timecode!("00:00:00:119" @ 120).to_frames(), 119);
+    assert_eq!(timecode!("100:00:00:00" @ 25).to_frames(), 9_000_000);
+    assert_eq!(timecode!(" 0:0:1;2 " @ 29.97).to_frames(), 32);
+
+    for frame_rate in [FrameRate::_29_97, FrameRate::_59_94, FrameRate::_25_00] {
+      for frames in (0..200_000).step_by(97) {
+        for drop_frame in [false, frame_rate.metadata().unwrap().drop_frame] {
+          let timecode = Timecode::with_drop_frame(frame_rate, frames, drop_frame).unwrap();
+          assert_eq!(frame_rate.__timecode(&timecode.to_string()), timecode);
+        }
+      }
+    }
+  }
+
+  #[test]
+  #[should_panic(expected = "timecode skipped by drop-frame")]
+  fn skipped_timecode() {
+    FrameRate::_29_97.__timecode("00:01:00;00");
+  }
+
+  #[test]
+  fn matches_new() {
+    for frame_rate in STANDARD_FRAME_RATES {
+      let ratio = Ratio::<u32>::from(frame_rate);
+      assert_eq!(
+        FrameRate::__from_literal(*ratio.numer() * 3, *ratio.denom() * 3),
+        frame_rate
+      );
+    }
+  }
+}
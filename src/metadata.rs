@@ -0,0 +1,127 @@
+use crate::FrameRate;
+use serde::Serialize;
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Family {
+  /// Whole number of frames per second.
+  Integer,
+  /// Integer rate slowed down by 1000/1001.
+  Ntsc,
+}
+
+/// Description of a standard frame rate, meant for code and documentation
+/// generators.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Serialize)]
+pub struct FrameRateMetadata {
+  #[serde(skip)]
+  pub frame_rate: FrameRate,
+  pub num: u32,
+  pub den: u32,
+  /// Conventional short name, as printed by `Display`.
+  pub name: &'static str,
+  pub family: Family,
+  /// Whether SMPTE drop-frame timecode is defined at this rate.
+  pub drop_frame: bool,
+  /// `frame_rate_code` of MPEG-2 video sequence headers.
+  pub mpeg2_frame_rate_code: Option<u8>,
+}
+
+const fn entry(
+  frame_rate: FrameRate,
+  num: u32,
+  den: u32,
+  name: &'static str,
+  drop_frame: bool,
+  mpeg2_frame_rate_code: Option<u8>,
+) -> FrameRateMetadata {
+  FrameRateMetadata {
+    frame_rate,
+    num,
+    den,
+    name,
+    family: if den == 1 {
+      Family::Integer
+    } else {
+      Family::Ntsc
+    },
+    drop_frame,
+    mpeg2_frame_rate_code,
+  }
+}
+
+/// Metadata of every standard frame rate, in ascending order.
+pub static METADATA: [FrameRateMetadata; 10] = [
+  entry(FrameRate::_23_97, 24000, 1001, "23.976", false, Some(1)),
+  entry(FrameRate::_24_00, 24, 1, "24", false, Some(2)),
+  entry(FrameRate::_24_97, 25000, 1001, "24.975", false, None),
+  entry(FrameRate::_25_00, 25, 1, "25", false, Some(3)),
+  entry(FrameRate::_29_97, 30000, 1001, "29.97", true, Some(4)),
+  entry(FrameRate::_30_00, 30, 1, "30", false, Some(5)),
+  entry(FrameRate::_50_00, 50, 1, "50", false, Some(6)),
+  entry(FrameRate::_59_94, 60000, 1001, "59.94", true, Some(7)),
+  entry(FrameRate::_60_00, 60, 1, "60", false, Some(8)),
+  entry(FrameRate::_120_00, 120, 1, "120", false, None),
+];
+
+impl FrameRate {
+  /// Metadata of a standard frame rate, `None` for custom ones.
+  pub fn metadata(&self) -> Option<&'static FrameRateMetadata> {
+    METADATA
+      .iter()
+      .find(|metadata| metadata.frame_rate == *self)
+  }
+
+  /// Every standard frame rate, in ascending order.
+  pub fn standard_rates() -> impl DoubleEndedIterator<Item = FrameRate> + ExactSizeIterator {
+    METADATA.iter().map(|metadata| metadata.frame_rate)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{Ratio, STANDARD_FRAME_RATES};
+
+  #[test]
+  fn consistency() {
+    assert_eq!(METADATA.len(), STANDARD_FRAME_RATES.len());
+
+    for frame_rate in STANDARD_FRAME_RATES {
+      let metadata = frame_rate.metadata().unwrap();
+      assert_eq!(metadata.frame_rate, frame_rate);
+      assert_eq!(
+        Ratio::from(frame_rate),
+        Ratio::new(metadata.num, metadata.den)
+      );
+      assert_eq!(metadata.name, frame_rate.to_string());
+    }
+
+    assert!(
+      METADATA
+        .windows(2)
+        .all(|pair| Ratio::from(pair[0].frame_rate) < Ratio::from(pair[1].frame_rate))
+    );
+    assert_eq!(FrameRate::new(48, 1).metadata(), None);
+    assert!(
+      STANDARD_FRAME_RATES
+        .iter()
+        .all(|frame_rate| FrameRate::standard_rates().any(|standard| standard == *frame_rate))
+    );
+  }
+
+  #[test]
+  fn serialize() {
+    assert_eq!(
+      serde_json::to_value(FrameRate::_29_97.metadata()).unwrap(),
+      serde_json::json!({
+        "num": 30000,
+        "den": 1001,
+        "name": "29.97",
+        "family": "ntsc",
+        "drop_frame": true,
+        "mpeg2_frame_rate_code": 4
+      })
+    );
+  }
+}
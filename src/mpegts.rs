@@ -0,0 +1,142 @@
+use crate::{FrameRate, SnapProfile};
+
+/// MPEG-TS presentation timestamp clock, in ticks per second.
+pub const PTS_CLOCK: u64 = 90_000;
+
+/// PTS values wrap around at 2^33 ticks, about 26.5 hours.
+pub const PTS_MODULO: u64 = 1 << 33;
+
+/// Signed distance from `a` to `b` on the 33-bit PTS clock, taking the
+/// shortest way around a wrap.
+///
+/// ```
+/// use frame_rate::mpegts::{PTS_MODULO, pts_delta_33bit};
+///
+/// assert_eq!(pts_delta_33bit(PTS_MODULO - 1000, 2003), 3003);
+/// ```
+pub fn pts_delta_33bit(a: u64, b: u64) -> i64 {
+  let delta = b.wrapping_sub(a) & (PTS_MODULO - 1);
+  if delta >= PTS_MODULO / 2 {
+    delta as i64 - PTS_MODULO as i64
+  } else {
+    delta as i64
+  }
+}
+
+/// Extends 33-bit PTS values past wraps, so the sequence keeps increasing
+/// (or decreasing for reordered frames) across them. Each value is placed at
+/// the shortest distance from the previous one, values above 33 bits are
+/// masked.
+pub fn unwrap_pts<I: IntoIterator<Item = u64>>(pts: I) -> impl Iterator<Item = i64> {
+  let mut previous: Option<(u64, i64)> = None;
+  pts.into_iter().map(move |value| {
+    let value = value & (PTS_MODULO - 1);
+    let unwrapped = match previous {
+      Some((raw, unwrapped)) => unwrapped + pts_delta_33bit(raw, value),
+      None => value as i64,
+    };
+    previous = Some((value, unwrapped));
+    unwrapped
+  })
+}
+
+impl FrameRate {
+  /// Infers the frame rate of a stream from the PTS of consecutive frames,
+  /// handling 33-bit wraps and B-frame reordering.
+  ///
+  /// The median interval between frames gives the number of frame periods
+  /// spanned by the sample, so occasional drops do not skew the result, and
+  /// the rate is averaged over that whole span, so cadences such as the
+  /// alternating 1501 and 1502 ticks of 59.94 cancel out. It is then snapped
+  /// with `profile` (see [`FrameRate::from_f64`]).
+  /// Returns `None` with fewer than two distinct timestamps.
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip_all, fields(count = pts.len()), ret)
+  )]
+  pub fn from_pts(pts: &[u64], profile: &SnapProfile) -> Option<Self> {
+    let mut unwrapped: Vec<_> = unwrap_pts(pts.iter().copied()).collect();
+    unwrapped.sort_unstable();
+    unwrapped.dedup();
+
+    let mut deltas: Vec<_> = unwrapped.windows(2).map(|pair| pair[1] - pair[0]).collect();
+    if deltas.is_empty() {
+      return None;
+    }
+    let middle = deltas.len() / 2;
+    let (_, median, _) = deltas.select_nth_unstable(middle);
+    #[cfg(feature = "tracing")]
+    tracing::debug!(median = *median, "median PTS delta");
+
+    let span = unwrapped[unwrapped.len() - 1] - unwrapped[0];
+    let periods = (span as f64 / *median as f64).round();
+    Self::from_f64(periods * PTS_CLOCK as f64 / span as f64, profile)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::SnapCandidate;
+
+  #[test]
+  fn delta() {
+    assert_eq!(pts_delta_33bit(0, 3003), 3003);
+    assert_eq!(pts_delta_33bit(3003, 0), -3003);
+    assert_eq!(pts_delta_33bit(PTS_MODULO - 1000, 2003), 3003);
+    assert_eq!(pts_delta_33bit(2003, PTS_MODULO - 1000), -3003);
+  }
+
+  #[test]
+  fn unwrap() {
+    let pts = [PTS_MODULO - 3600, PTS_MODULO - 1800, 0, 1800, 900];
+    let base = (PTS_MODULO - 3600) as i64;
+    assert_eq!(
+      unwrap_pts(pts).collect::<Vec<_>>(),
+      [base, base + 1800, base + 3600, base + 5400, base + 4500]
+    );
+  }
+
+  #[test]
+  fn from_pts() {
+    let profile = SnapProfile::DEFAULT;
+
+    let pts: Vec<_> = (0..100)
+      .map(|frame| (PTS_MODULO - 50 * 3003 + frame * 3003) % PTS_MODULO)
+      .collect();
+    assert_eq!(FrameRate::from_pts(&pts, &profile), Some(FrameRate::_29_97));
+
+    // 23.976 alternates 3753 and 3754 ticks; B-frames reorder, one is lost.
+    let mut pts: Vec<_> = (0..48)
+      .map(|frame| frame * 90_000 * 1001 / 24_000)
+      .collect();
+    pts.swap(3, 4);
+    pts.remove(20);
+    assert_eq!(FrameRate::from_pts(&pts, &profile), Some(FrameRate::_23_97));
+
+    // 59.94 alternates 1501 and 1502 ticks, 119.88 750 and 751.
+    let mut pts: Vec<_> = (0..120)
+      .map(|frame| frame * 90_000 * 1001 / 60_000)
+      .collect();
+    assert_eq!(FrameRate::from_pts(&pts, &profile), Some(FrameRate::_59_94));
+    pts.remove(60);
+    assert_eq!(FrameRate::from_pts(&pts, &profile), Some(FrameRate::_59_94));
+    let pts: Vec<_> = (0..240)
+      .map(|frame| frame * 90_000 * 1001 / 120_000)
+      .collect();
+    let candidates = [SnapCandidate::new(FrameRate::new(120_000, 1001), 0.005)];
+    let high_frame_rate = SnapProfile {
+      candidates: &candidates,
+    };
+    assert_eq!(
+      FrameRate::from_pts(&pts, &high_frame_rate),
+      Some(FrameRate::new(120_000, 1001))
+    );
+
+    let pts: Vec<_> = (0..10).map(|frame| frame * 1800).collect();
+    assert_eq!(FrameRate::from_pts(&pts, &profile), Some(FrameRate::_50_00));
+
+    assert_eq!(FrameRate::from_pts(&[42, 42], &profile), None);
+    assert_eq!(FrameRate::from_pts(&[], &profile), None);
+  }
+}
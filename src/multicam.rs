@@ -0,0 +1,115 @@
+use crate::{FrameRate, Ratio};
+use num_integer::Integer;
+
+/// Alignment of two cameras triggered at the same time, see
+/// [`FrameRate::sync_with`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CameraSync {
+  frame_rate: FrameRate,
+  other: FrameRate,
+}
+
+/// Frame of the other camera covering a frame start, see
+/// [`CameraSync::align`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FrameAlignment {
+  /// Frame of the other camera being exposed when the frame starts.
+  pub other_frame: u64,
+  /// Time in seconds from the start of `other_frame` to the frame start.
+  pub offset: Ratio<u64>,
+}
+
+impl FrameRate {
+  /// Alignment of a camera at this rate with one at `other`, both starting
+  /// frame 0 at the trigger time.
+  pub fn sync_with(&self, other: FrameRate) -> CameraSync {
+    CameraSync {
+      frame_rate: *self,
+      other,
+    }
+  }
+}
+
+impl CameraSync {
+  /// Time in seconds after which frame starts of both cameras coincide again,
+  /// the offset pattern repeating from there.
+  pub fn beat_period(&self) -> Ratio<u64> {
+    let period = self.frame_rate.time_after(1);
+    let other = self.other.time_after(1);
+    Ratio::new(
+      period.numer().lcm(other.numer()),
+      period.denom().gcd(other.denom()),
+    )
+  }
+
+  /// Frames of each camera during a beat period, e.g. `(24, 25)` for 24 and
+  /// 25 fps cameras.
+  pub fn beat_frames(&self) -> (u64, u64) {
+    let beat_period = self.beat_period();
+    let frames = |frame_rate: FrameRate| (beat_period / frame_rate.time_after(1)).to_integer();
+    (frames(self.frame_rate), frames(self.other))
+  }
+
+  /// Frame of the other camera at the start of `frame`.
+  pub fn align(&self, frame: u64) -> FrameAlignment {
+    let time = self.frame_rate.time_after(frame);
+    let other_frame = self.other.frames_before(time);
+    FrameAlignment {
+      other_frame,
+      offset: time - self.other.time_after(other_frame),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn beat() {
+    let sync = FrameRate::_24_00.sync_with(FrameRate::_25_00);
+    assert_eq!(sync.beat_period(), Ratio::from_integer(1));
+    assert_eq!(sync.beat_frames(), (24, 25));
+
+    let sync = FrameRate::_29_97.sync_with(FrameRate::_25_00);
+    assert_eq!(sync.beat_period(), Ratio::new(1001, 25));
+    assert_eq!(sync.beat_frames(), (1200, 1001));
+
+    let sync = FrameRate::_50_00.sync_with(FrameRate::_25_00);
+    assert_eq!(sync.beat_frames(), (2, 1));
+  }
+
+  #[test]
+  fn align() {
+    let sync = FrameRate::_25_00.sync_with(FrameRate::_24_00);
+    assert_eq!(
+      sync.align(0),
+      FrameAlignment {
+        other_frame: 0,
+        offset: Ratio::from_integer(0)
+      }
+    );
+    assert_eq!(
+      sync.align(1),
+      FrameAlignment {
+        other_frame: 0,
+        offset: Ratio::new(1, 25)
+      }
+    );
+    assert_eq!(
+      sync.align(13),
+      FrameAlignment {
+        other_frame: 12,
+        offset: Ratio::new(1, 50)
+      }
+    );
+    assert_eq!(sync.align(25).offset, Ratio::from_integer(0));
+    assert_eq!(
+      sync.align(26),
+      FrameAlignment {
+        other_frame: 24,
+        offset: Ratio::new(1, 25)
+      }
+    );
+  }
+}
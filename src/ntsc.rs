@@ -0,0 +1,116 @@
+use crate::{Error, FrameRate, Ratio};
+
+/// Factor between an integer rate and its NTSC counterpart, 1000/1001.
+pub const NTSC_FACTOR: Ratio<u32> = Ratio::new_raw(1000, 1001);
+
+impl FrameRate {
+  /// Slows this rate down by [`NTSC_FACTOR`], e.g. 50 → 50000/1001.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the result does not fit in a `Ratio<u32>`.
+  pub fn apply_ntsc_factor(&self) -> Self {
+    *self * NTSC_FACTOR
+  }
+
+  /// Speeds this rate up by the inverse of [`NTSC_FACTOR`], e.g.
+  /// 30000/1001 → 30.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the result does not fit in a `Ratio<u32>`.
+  pub fn remove_ntsc_factor(&self) -> Self {
+    *self * NTSC_FACTOR.recip()
+  }
+
+  /// Whether this rate belongs to the 1001-based family, i.e. is an integer
+  /// or decimal rate slowed down by [`NTSC_FACTOR`].
+  pub fn has_ntsc_factor(&self) -> bool {
+    Ratio::<u32>::from(*self).denom() % 1001 == 0
+  }
+
+  /// Standard rate slowed down by 1000/1001: 24 → 23.976, 25 → 24.975,
+  /// 30 → 29.97 and 60 → 59.94.
+  pub fn to_ntsc_variant(&self) -> Result<Self, Error> {
+    match self {
+      Self::_24_00 => Ok(Self::_23_97),
+      Self::_25_00 => Ok(Self::_24_97),
+      Self::_30_00 => Ok(Self::_29_97),
+      Self::_60_00 => Ok(Self::_59_94),
+      _ => Err(Error::NoNtscCounterpart(*self)),
+    }
+  }
+
+  /// Standard rate sped up by 1001/1000: 23.976 → 24, 24.975 → 25,
+  /// 29.97 → 30 and 59.94 → 60.
+  pub fn to_integer_variant(&self) -> Result<Self, Error> {
+    match self {
+      Self::_23_97 => Ok(Self::_24_00),
+      Self::_24_97 => Ok(Self::_25_00),
+      Self::_29_97 => Ok(Self::_30_00),
+      Self::_59_94 => Ok(Self::_60_00),
+      _ => Err(Error::NoNtscCounterpart(*self)),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::STANDARD_FRAME_RATES;
+
+  #[test]
+  fn counterparts() {
+    assert_eq!(FrameRate::_24_00.to_ntsc_variant(), Ok(FrameRate::_23_97));
+    assert_eq!(FrameRate::_25_00.to_ntsc_variant(), Ok(FrameRate::_24_97));
+    assert_eq!(FrameRate::_30_00.to_ntsc_variant(), Ok(FrameRate::_29_97));
+    assert_eq!(FrameRate::_60_00.to_ntsc_variant(), Ok(FrameRate::_59_94));
+
+    for frame_rate in STANDARD_FRAME_RATES {
+      if let Ok(ntsc) = frame_rate.to_ntsc_variant() {
+        assert_eq!(ntsc.to_integer_variant(), Ok(frame_rate));
+        assert_eq!(frame_rate.apply_ntsc_factor(), ntsc, "{frame_rate:?}");
+      }
+    }
+  }
+
+  #[test]
+  fn ntsc_factor() {
+    assert_eq!(FrameRate::_30_00.apply_ntsc_factor(), FrameRate::_29_97);
+    assert_eq!(
+      FrameRate::_50_00.apply_ntsc_factor(),
+      FrameRate::new(50000, 1001)
+    );
+    assert_eq!(FrameRate::_59_94.remove_ntsc_factor(), FrameRate::_60_00);
+    assert_eq!(
+      FrameRate::_25_00.remove_ntsc_factor(),
+      FrameRate::new(1001, 40)
+    );
+
+    for frame_rate in STANDARD_FRAME_RATES {
+      assert_eq!(
+        frame_rate.has_ntsc_factor(),
+        frame_rate.to_integer_variant().is_ok()
+      );
+    }
+    assert!(FrameRate::new(48000, 1001).has_ntsc_factor());
+    assert!(FrameRate::new(15000, 1001).has_ntsc_factor());
+    assert!(!FrameRate::new(1001, 40).has_ntsc_factor());
+  }
+
+  #[test]
+  fn no_counterpart() {
+    for frame_rate in [FrameRate::_50_00, FrameRate::_120_00, FrameRate::_29_97] {
+      assert_eq!(
+        frame_rate.to_ntsc_variant(),
+        Err(Error::NoNtscCounterpart(frame_rate))
+      );
+    }
+    for frame_rate in [FrameRate::_50_00, FrameRate::_30_00, FrameRate::new(48, 1)] {
+      assert_eq!(
+        frame_rate.to_integer_variant(),
+        Err(Error::NoNtscCounterpart(frame_rate))
+      );
+    }
+  }
+}
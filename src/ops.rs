@@ -0,0 +1,134 @@
+use crate::{FrameRate, Ratio};
+use std::ops::{Div, Mul};
+
+impl FrameRate {
+  /// Whether `fps` is within `tolerance` of this rate, e.g.
+  /// `FrameRate::_29_97.approx_eq(29.97, 0.001)`.
+  ///
+  /// There is no `PartialEq<f64>`, as exact float comparison would reject most
+  /// NTSC rates.
+  pub fn approx_eq(&self, fps: f64, tolerance: f64) -> bool {
+    (f64::from(self) - fps).abs() <= tolerance
+  }
+
+  /// # Panics
+  ///
+  /// Panics if `den` is zero or if the reduced result does not fit in a
+  /// `Ratio<u32>`.
+  fn scale(self, num: u32, den: u32) -> Self {
+    let ratio = Ratio::<u32>::from(self);
+    let scaled = Ratio::new(
+      u64::from(*ratio.numer()) * u64::from(num),
+      u64::from(*ratio.denom()) * u64::from(den),
+    );
+    Self::new(
+      u32::try_from(*scaled.numer()).expect("frame rate numerator overflow"),
+      u32::try_from(*scaled.denom()).expect("frame rate denominator overflow"),
+    )
+  }
+}
+
+/// Compares values, so `FrameRate::_25_00 == Ratio::new(50, 2)`.
+impl PartialEq<Ratio<u32>> for FrameRate {
+  fn eq(&self, other: &Ratio<u32>) -> bool {
+    Ratio::from(*self) == *other
+  }
+}
+
+impl PartialEq<FrameRate> for Ratio<u32> {
+  fn eq(&self, other: &FrameRate) -> bool {
+    other == self
+  }
+}
+
+/// # Panics
+///
+/// Panics if the result does not fit in a `Ratio<u32>`.
+impl Mul<Ratio<u32>> for FrameRate {
+  type Output = Self;
+
+  fn mul(self, factor: Ratio<u32>) -> Self {
+    self.scale(*factor.numer(), *factor.denom())
+  }
+}
+
+/// # Panics
+///
+/// Panics if the result does not fit in a `Ratio<u32>`.
+impl Mul<u32> for FrameRate {
+  type Output = Self;
+
+  fn mul(self, factor: u32) -> Self {
+    self.scale(factor, 1)
+  }
+}
+
+/// # Panics
+///
+/// Panics if `divisor` is zero or if the result does not fit in a
+/// `Ratio<u32>`.
+impl Div<u32> for FrameRate {
+  type Output = Self;
+
+  fn div(self, divisor: u32) -> Self {
+    self.scale(1, divisor)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn eq() {
+    assert_eq!(FrameRate::_29_97, Ratio::new(30000, 1001));
+    assert_eq!(Ratio::new(50, 2), FrameRate::_25_00);
+    assert_eq!(FrameRate::new(48, 1), Ratio::from_integer(48));
+    assert_ne!(FrameRate::_30_00, Ratio::new(30000, 1001));
+
+    assert!(FrameRate::_29_97.approx_eq(29.97, 0.001));
+    assert!(FrameRate::_23_97.approx_eq(23.976, 0.001));
+    assert!(!FrameRate::_23_97.approx_eq(24.0, 0.001));
+    assert!(FrameRate::_25_00.approx_eq(25.0, 0.0));
+  }
+
+  #[test]
+  fn mul() {
+    assert_eq!(FrameRate::_25_00 * 2, FrameRate::_50_00);
+    assert_eq!(FrameRate::_29_97 * 2, FrameRate::_59_94);
+    assert_eq!(FrameRate::_60_00 * 2, FrameRate::_120_00);
+    assert_eq!(FrameRate::_24_00 * 2, FrameRate::new(48, 1));
+    assert_eq!(
+      FrameRate::_30_00 * Ratio::new(1000, 1001),
+      FrameRate::_29_97
+    );
+    assert_eq!(
+      FrameRate::_23_97 * Ratio::new(1001, 1000),
+      FrameRate::_24_00
+    );
+    assert_eq!(
+      FrameRate::_29_97 * Ratio::new(1001, 1000),
+      FrameRate::_30_00
+    );
+  }
+
+  #[test]
+  fn div() {
+    assert_eq!(FrameRate::_50_00 / 2, FrameRate::_25_00);
+    assert_eq!(FrameRate::_59_94 / 2, FrameRate::_29_97);
+    assert_eq!(FrameRate::_120_00 / 5, FrameRate::_24_00);
+    assert_eq!(FrameRate::_29_97 / 2, FrameRate::new(15000, 1001));
+  }
+
+  #[test]
+  #[should_panic(expected = "frame rate numerator overflow")]
+  fn mul_overflow() {
+    let _ = FrameRate::new(u32::MAX, 1) * 2;
+  }
+
+  #[test]
+  #[should_panic]
+  fn div_by_zero() {
+    let _ = FrameRate::_25_00 / 0;
+  }
+}
@@ -0,0 +1,68 @@
+use crate::{FrameRate, Ratio};
+
+/// Sort key returned by [`FrameRate::display_order`].
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct DisplayOrder {
+  custom: bool,
+  ratio: Ratio<u32>,
+}
+
+impl FrameRate {
+  /// Key ordering rates as in camera and monitor menus: standard rates in
+  /// ascending order (23.976, 24, 24.975, 25, 29.97, 30, 50, 59.94, 60, 120),
+  /// then custom rates in ascending order.
+  ///
+  /// ```
+  /// use frame_rate::FrameRate;
+  ///
+  /// let mut rates = [FrameRate::new(48, 1), FrameRate::_25_00, FrameRate::_23_97];
+  /// rates.sort_by_key(FrameRate::display_order);
+  /// assert_eq!(rates, [FrameRate::_23_97, FrameRate::_25_00, FrameRate::new(48, 1)]);
+  /// ```
+  pub fn display_order(&self) -> DisplayOrder {
+    DisplayOrder {
+      custom: self.metadata().is_none(),
+      ratio: Ratio::from(*self),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn display_order() {
+    let mut frame_rates = vec![
+      FrameRate::new(1, 10),
+      FrameRate::_60_00,
+      FrameRate::new(48, 1),
+      FrameRate::_120_00,
+      FrameRate::new(15, 1),
+    ];
+    frame_rates.extend(FrameRate::standard_rates().rev());
+    frame_rates.sort_by_key(FrameRate::display_order);
+
+    let names: Vec<_> = frame_rates.iter().map(ToString::to_string).collect();
+    assert_eq!(
+      names,
+      [
+        "23.976",
+        "24",
+        "24.975",
+        "25",
+        "29.97",
+        "30",
+        "50",
+        "59.94",
+        "60",
+        "60",
+        "120",
+        "120",
+        "1 frame / 10 s",
+        "15/1",
+        "48/1"
+      ]
+    );
+  }
+}
@@ -0,0 +1,197 @@
+//! Conversions with OpenTimelineIO `RationalTime` and `TimeRange` values.
+//!
+//! OTIO stores both the value and the rate as doubles, so NTSC rates show up
+//! as `23.976023976023978` or as the rounded `23.976`. Rates are snapped with
+//! [`SnapProfile::DEFAULT`] before any conversion to avoid drifting by a frame.
+
+use crate::{ClipRange, Error, FrameRate, RangeRounding, Rounding, SnapProfile};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "OTIO_SCHEMA", rename = "RationalTime.1")]
+pub struct RationalTime {
+  pub value: f64,
+  pub rate: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "OTIO_SCHEMA", rename = "TimeRange.1")]
+pub struct TimeRange {
+  pub start_time: RationalTime,
+  pub duration: RationalTime,
+}
+
+impl RationalTime {
+  pub fn from_frames(frames: u64, frame_rate: FrameRate) -> Self {
+    Self {
+      value: frames as f64,
+      rate: f64::from(&frame_rate),
+    }
+  }
+
+  /// Frame rate of the `rate` field, snapped to a standard rate when close
+  /// enough.
+  pub fn frame_rate(&self) -> Option<FrameRate> {
+    FrameRate::from_f64(self.rate, &SnapProfile::DEFAULT)
+  }
+
+  /// Frame index at `frame_rate`, or `None` for negative or invalid times.
+  /// Whole frame values are converted exactly from the snapped rate.
+  pub fn to_frames(&self, frame_rate: FrameRate, rounding: Rounding) -> Option<u64> {
+    let source = self.frame_rate()?;
+    if source == frame_rate {
+      return rounding.round_frames(self.value);
+    }
+    if self.value >= 0.0 && self.value.fract() == 0.0 && self.value < u64::MAX as f64 {
+      return Some(source.convert_frame(self.value as u64, frame_rate, rounding));
+    }
+    rounding.round_frames(self.value / f64::from(&source) * f64::from(&frame_rate))
+  }
+}
+
+impl TimeRange {
+  /// Map the range to a [`ClipRange`] at `frame_rate`.
+  pub fn to_clip_range(
+    &self,
+    frame_rate: FrameRate,
+    rounding: RangeRounding,
+  ) -> Result<ClipRange, Error> {
+    let (start_rounding, end_rounding) = rounding.boundaries();
+    let duration = if self.duration.frame_rate() == self.start_time.frame_rate() {
+      self.duration.value
+    } else {
+      self.duration.value * self.start_time.rate / self.duration.rate
+    };
+    let end = RationalTime {
+      value: self.start_time.value + duration,
+      rate: self.start_time.rate,
+    };
+
+    ClipRange::new(
+      frame_rate,
+      self
+        .start_time
+        .to_frames(frame_rate, start_rounding)
+        .ok_or(Error::InvalidOtioTime)?,
+      end
+        .to_frames(frame_rate, end_rounding)
+        .ok_or(Error::InvalidOtioTime)?,
+    )
+  }
+}
+
+impl From<ClipRange> for TimeRange {
+  fn from(range: ClipRange) -> Self {
+    Self {
+      start_time: RationalTime::from_frames(range.start(), range.frame_rate()),
+      duration: RationalTime::from_frames(range.duration(), range.frame_rate()),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn rational_time() {
+    let time = RationalTime::from_frames(86400, FrameRate::_23_97);
+    assert_eq!(time.rate, 24000.0 / 1001.0);
+    assert_eq!(time.frame_rate(), Some(FrameRate::_23_97));
+    assert_eq!(
+      time.to_frames(FrameRate::_23_97, Rounding::Floor),
+      Some(86400)
+    );
+
+    let rounded_rate = RationalTime {
+      value: 86400.0,
+      rate: 23.976,
+    };
+    assert_eq!(rounded_rate.frame_rate(), Some(FrameRate::_23_97));
+    assert_eq!(
+      rounded_rate.to_frames(FrameRate::_23_97, Rounding::Floor),
+      Some(86400)
+    );
+
+    // 86400 frames at 23.976 last exactly 3603.6 s, i.e. 108000 frames at
+    // 29.97, whereas the raw 23.976 rate rounds up to 108001.
+    assert_eq!(
+      rounded_rate.to_frames(FrameRate::_29_97, Rounding::Ceil),
+      Some(108_000)
+    );
+    assert_eq!(
+      rounded_rate.to_frames(FrameRate::_25_00, Rounding::Ceil),
+      Some(90_090)
+    );
+    let fractional = RationalTime {
+      value: 0.5,
+      rate: 23.976,
+    };
+    assert_eq!(
+      fractional.to_frames(FrameRate::_59_94, Rounding::Nearest),
+      Some(1)
+    );
+
+    let seconds = RationalTime {
+      value: 10.0,
+      rate: 1.0,
+    };
+    assert_eq!(
+      seconds.to_frames(FrameRate::_25_00, Rounding::Floor),
+      Some(250)
+    );
+    assert_eq!(
+      seconds.to_frames(FrameRate::_29_97, Rounding::Floor),
+      Some(299)
+    );
+    assert_eq!(
+      seconds.to_frames(FrameRate::_29_97, Rounding::Ceil),
+      Some(300)
+    );
+
+    let negative = RationalTime {
+      value: -1.0,
+      rate: 24.0,
+    };
+    assert_eq!(negative.to_frames(FrameRate::_24_00, Rounding::Floor), None);
+  }
+
+  #[test]
+  fn time_range() {
+    let range = ClipRange::new(FrameRate::_29_97, 100, 250).unwrap();
+    let otio = TimeRange::from(range);
+    assert_eq!(
+      otio.to_clip_range(FrameRate::_29_97, RangeRounding::Inward),
+      Ok(range)
+    );
+    assert_eq!(
+      otio
+        .to_clip_range(FrameRate::_59_94, RangeRounding::Inward)
+        .map(|range| (range.start(), range.end())),
+      Ok((200, 500))
+    );
+  }
+
+  #[test]
+  fn serialize() {
+    assert_eq!(
+      serde_json::to_value(TimeRange::from(
+        ClipRange::new(FrameRate::_24_00, 10, 58).unwrap()
+      ))
+      .unwrap(),
+      serde_json::json!({
+        "OTIO_SCHEMA": "TimeRange.1",
+        "start_time": {
+          "OTIO_SCHEMA": "RationalTime.1",
+          "value": 10.0,
+          "rate": 24.0
+        },
+        "duration": {
+          "OTIO_SCHEMA": "RationalTime.1",
+          "value": 48.0,
+          "rate": 24.0
+        }
+      })
+    );
+  }
+}
@@ -0,0 +1,446 @@
+use crate::{FrameRate, PRESETS, SnapProfile};
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+/// Parses `"num/den"` or a plain integer `"num"`, rejecting a zero
+/// denominator.
+#[cfg_attr(
+  not(any(feature = "async-graphql", feature = "redis")),
+  allow(dead_code)
+)]
+pub(crate) fn parse_ratio(value: &str) -> Option<FrameRate> {
+  ratio(value, trim(value, 0..value.len())).ok()
+}
+
+/// Parses every form printed by `Display`: `"29.97"`, `"24"`, `"48/1"` and
+/// `"1 frame / 10 s"`. Decimals are snapped to the standard rates.
+pub(crate) fn parse_display(value: &str) -> Option<FrameRate> {
+  parse_frame_rate(value).ok()
+}
+
+/// Reason a frame rate failed to parse.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseErrorKind {
+  /// Nothing but whitespace.
+  Empty,
+  /// A character that has no place in a frame rate.
+  InvalidCharacter,
+  /// A number is expected here.
+  MissingNumber,
+  /// A `s` unit is expected after the seconds of `"1 frame / 10 s"`.
+  MissingUnit,
+  /// The number does not fit in 32 bits.
+  Overflow,
+  /// The decimal is not close to any representable rate.
+  OutOfRange,
+  /// The denominator of `"num/den"` or the seconds of `"1 frame / 0 s"` is 0.
+  ZeroDenominator,
+}
+
+impl fmt::Display for ParseErrorKind {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(match self {
+      Self::Empty => "empty frame rate",
+      Self::InvalidCharacter => "invalid character",
+      Self::MissingNumber => "expected a number",
+      Self::MissingUnit => "expected `s`",
+      Self::Overflow => "number too large",
+      Self::OutOfRange => "frame rate out of range",
+      Self::ZeroDenominator => "zero denominator",
+    })
+  }
+}
+
+/// Error of [`parse_frame_rate`], pointing at the offending bytes of the
+/// input.
+#[derive(Clone, Debug, Eq, PartialEq, thiserror::Error)]
+#[error("{kind} at {}..{}", span.start, span.end)]
+pub struct FrameRateParseError {
+  /// Byte range within the parsed input, empty when something is missing.
+  pub span: Range<usize>,
+  pub kind: ParseErrorKind,
+}
+
+impl FrameRateParseError {
+  fn new(span: Range<usize>, kind: ParseErrorKind) -> Self {
+    Self { span, kind }
+  }
+
+  /// Offending part of `input`, the string given to [`parse_frame_rate`].
+  pub fn fragment<'a>(&self, input: &'a str) -> &'a str {
+    &input[self.span.clone()]
+  }
+}
+
+/// Parses every form printed by `Display` without allocating: `"29.97"`,
+/// `"24"`, `"48/1"`, `"1 frame / 10 s"` and the names of [`PRESETS`].
+/// Decimals are snapped to the standard rates, other decimals are exact.
+///
+/// The variant names printed by `{:#?}`, e.g. `"_29_97"` or
+/// `"FrameRate::_29_97"`, are accepted too, for configurations written from
+/// the `Debug` output.
+///
+/// A number may end with a scan suffix: `"50p"` is progressive, 50 fps, while
+/// `"59.94i"` counts fields, two per interlaced frame, hence 29.97 fps.
+///
+/// ```
+/// use frame_rate::{ParseErrorKind, parse_frame_rate};
+///
+/// let input = "30000/10x1";
+/// let error = parse_frame_rate(input).unwrap_err();
+/// assert_eq!(error.kind, ParseErrorKind::InvalidCharacter);
+/// assert_eq!(error.fragment(input), "x");
+/// ```
+pub fn parse_frame_rate(input: &str) -> Result<FrameRate, FrameRateParseError> {
+  let span = trim(input, 0..input.len());
+  if span.is_empty() {
+    return Err(FrameRateParseError::new(span, ParseErrorKind::Empty));
+  }
+  if let Some(frame_rate) =
+    variant_name(&input[span.clone()]).or_else(|| PRESETS.by_name(&input[span.clone()]))
+  {
+    return Ok(frame_rate);
+  }
+
+  let (span, interlaced) = match input[span.clone()].as_bytes().last() {
+    Some(b'p') => (span.start..span.end - 1, false),
+    Some(b'i') => (span.start..span.end - 1, true),
+    _ => (span, false),
+  };
+  let frame_rate = rate(input, span.clone())?;
+  if !interlaced {
+    return Ok(frame_rate);
+  }
+  let ratio = crate::Ratio::<u32>::from(frame_rate);
+  FrameRate::new_u64(u64::from(*ratio.numer()), u64::from(*ratio.denom()) * 2)
+    .map_err(|_| FrameRateParseError::new(span, ParseErrorKind::Overflow))
+}
+
+/// Parses a rate without name nor scan suffix.
+fn rate(input: &str, span: Range<usize>) -> Result<FrameRate, FrameRateParseError> {
+  let Some(slash) = input[span.clone()]
+    .find('/')
+    .map(|index| span.start + index)
+  else {
+    return match input[span.clone()].find('.') {
+      Some(dot) => parse_decimal(input, span.clone(), span.start + dot),
+      None => ratio(input, span),
+    };
+  };
+
+  let numerator = trim(input, span.start..slash);
+  let Some(frames) = strip_frames(&input[numerator.clone()]) else {
+    return ratio(input, span);
+  };
+  let denominator = trim(input, slash + 1..span.end);
+  let Some(seconds) = input[denominator.clone()].strip_suffix('s') else {
+    return Err(FrameRateParseError::new(
+      denominator.end..denominator.end,
+      ParseErrorKind::MissingUnit,
+    ));
+  };
+  fraction(
+    input,
+    trim(input, numerator.start..numerator.start + frames.len()),
+    trim(input, denominator.start..denominator.start + seconds.len()),
+  )
+}
+
+/// Same as [`parse_frame_rate`], so `"23.976".parse::<FrameRate>()` works.
+impl FromStr for FrameRate {
+  type Err = FrameRateParseError;
+
+  fn from_str(value: &str) -> Result<Self, Self::Err> {
+    parse_frame_rate(value)
+  }
+}
+
+/// Parses `"num/den"` or `"num"` within `span`.
+fn ratio(input: &str, span: Range<usize>) -> Result<FrameRate, FrameRateParseError> {
+  match input[span.clone()]
+    .find('/')
+    .map(|index| span.start + index)
+  {
+    Some(slash) => fraction(
+      input,
+      trim(input, span.start..slash),
+      trim(input, slash + 1..span.end),
+    ),
+    None => Ok(FrameRate::new(number(input, span)?, 1)),
+  }
+}
+
+fn fraction(
+  input: &str,
+  numerator: Range<usize>,
+  denominator: Range<usize>,
+) -> Result<FrameRate, FrameRateParseError> {
+  let num = number(input, numerator)?;
+  let den = number(input, denominator.clone())?;
+  if den == 0 {
+    return Err(FrameRateParseError::new(
+      denominator,
+      ParseErrorKind::ZeroDenominator,
+    ));
+  }
+  Ok(FrameRate::new(num, den))
+}
+
+/// Standard rate named after its variant, as in `"_29_97"`.
+fn variant_name(value: &str) -> Option<FrameRate> {
+  match value.strip_prefix("FrameRate::").unwrap_or(value) {
+    "_24_00" => Some(FrameRate::_24_00),
+    "_25_00" => Some(FrameRate::_25_00),
+    "_30_00" => Some(FrameRate::_30_00),
+    "_50_00" => Some(FrameRate::_50_00),
+    "_60_00" => Some(FrameRate::_60_00),
+    "_120_00" => Some(FrameRate::_120_00),
+    "_23_97" => Some(FrameRate::_23_97),
+    "_24_97" => Some(FrameRate::_24_97),
+    "_29_97" => Some(FrameRate::_29_97),
+    "_59_94" => Some(FrameRate::_59_94),
+    _ => None,
+  }
+}
+
+fn strip_frames(value: &str) -> Option<&str> {
+  value
+    .strip_suffix("frames")
+    .or_else(|| value.strip_suffix("frame"))
+}
+
+fn trim(input: &str, span: Range<usize>) -> Range<usize> {
+  let value = &input[span.clone()];
+  let start = span.start + value.len() - value.trim_start().len();
+  let end = span.end - (value.len() - value.trim_end().len());
+  start..end.max(start)
+}
+
+fn parse_decimal(
+  input: &str,
+  span: Range<usize>,
+  dot: usize,
+) -> Result<FrameRate, FrameRateParseError> {
+  digits(input, span.start..dot)?;
+  digits(input, dot + 1..span.end)?;
+  if let Some(frame_rate) = input[span.clone()]
+    .parse()
+    .ok()
+    .and_then(|value| FrameRate::snap(value, &SnapProfile::DEFAULT))
+  {
+    return Ok(frame_rate);
+  }
+
+  // Not a standard rate, so keep every digit: "12.3456" is 7716/625.
+  let fraction = input[dot + 1..span.end].trim_end_matches('0');
+  let num = input[span.start..dot]
+    .bytes()
+    .chain(fraction.bytes())
+    .try_fold(0u64, |num, digit| {
+      num.checked_mul(10)?.checked_add(u64::from(digit - b'0'))
+    });
+  let den = u32::try_from(fraction.len())
+    .ok()
+    .and_then(|digits| 10u64.checked_pow(digits));
+  let exact = num
+    .zip(den)
+    .and_then(|(num, den)| FrameRate::new_u64(num, den).ok());
+  exact.ok_or(FrameRateParseError::new(span, ParseErrorKind::OutOfRange))
+}
+
+fn number(input: &str, span: Range<usize>) -> Result<u32, FrameRateParseError> {
+  digits(input, span.clone())?;
+  input[span.clone()]
+    .parse()
+    .map_err(|_| FrameRateParseError::new(span, ParseErrorKind::Overflow))
+}
+
+fn digits(input: &str, span: Range<usize>) -> Result<(), FrameRateParseError> {
+  if span.is_empty() {
+    return Err(FrameRateParseError::new(
+      span,
+      ParseErrorKind::MissingNumber,
+    ));
+  }
+  match input[span.clone()]
+    .char_indices()
+    .find(|(_, c)| !c.is_ascii_digit())
+  {
+    Some((index, c)) => Err(FrameRateParseError::new(
+      span.start + index..span.start + index + c.len_utf8(),
+      ParseErrorKind::InvalidCharacter,
+    )),
+    None => Ok(()),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use proptest::prelude::*;
+
+  #[test]
+  fn ratio() {
+    assert_eq!(parse_ratio("30000/1001"), Some(FrameRate::_29_97));
+    assert_eq!(parse_ratio(" 25 / 1 "), Some(FrameRate::_25_00));
+    assert_eq!(parse_ratio("48"), Some(FrameRate::new(48, 1)));
+    assert_eq!(parse_ratio("25/0"), None);
+    assert_eq!(parse_ratio("+25"), None);
+    assert_eq!(parse_ratio("25/"), None);
+    assert_eq!(parse_ratio("29.97"), None);
+    assert_eq!(parse_ratio("4294967296"), None);
+  }
+
+  #[test]
+  fn display() {
+    assert_eq!(parse_display("29.97"), Some(FrameRate::_29_97));
+    assert_eq!(parse_display("23.976"), Some(FrameRate::_23_97));
+    assert_eq!(parse_display("24"), Some(FrameRate::_24_00));
+    assert_eq!(parse_display("12.5"), Some(FrameRate::new(25, 2)));
+    assert_eq!(parse_display("12.3456"), Some(FrameRate::new(7716, 625)));
+    assert_eq!(parse_display("48.000"), Some(FrameRate::new(48, 1)));
+    assert_eq!(parse_display("0.0"), Some(FrameRate::new(0, 1)));
+    assert_eq!(parse_display("1 frame / 10 s"), Some(FrameRate::new(1, 10)));
+    assert_eq!(
+      parse_display("2 frames / 25 s"),
+      Some(FrameRate::new(2, 25))
+    );
+    assert_eq!(parse_display("2 frames / 0 s"), None);
+    assert_eq!(parse_display("29."), None);
+    assert_eq!(parse_display("-29.97"), None);
+    assert_eq!(parse_display("fast"), None);
+  }
+
+  #[test]
+  fn variant_names() {
+    for frame_rate in crate::STANDARD_FRAME_RATES {
+      let name = format!("{frame_rate:#?}");
+      assert_eq!(parse_frame_rate(&name), Ok(frame_rate));
+      assert_eq!(
+        parse_frame_rate(&format!(" FrameRate::{name}")),
+        Ok(frame_rate)
+      );
+    }
+    assert_eq!(parse_display("_29_96"), None);
+    assert_eq!(parse_display("frame_rate::_29_97"), None);
+  }
+
+  #[test]
+  fn spans() {
+    let error = |input: &str| {
+      let error = parse_frame_rate(input).unwrap_err();
+      (error.kind, error.fragment(input).to_string(), error.span)
+    };
+
+    assert_eq!(error("  "), (ParseErrorKind::Empty, String::new(), 2..2));
+    assert_eq!(
+      error("30000/10x1"),
+      (ParseErrorKind::InvalidCharacter, "x".to_string(), 8..9)
+    );
+    assert_eq!(
+      error(" 25 / 0 "),
+      (ParseErrorKind::ZeroDenominator, "0".to_string(), 6..7)
+    );
+    assert_eq!(
+      error("25/"),
+      (ParseErrorKind::MissingNumber, String::new(), 3..3)
+    );
+    assert_eq!(
+      error("4294967296/1"),
+      (ParseErrorKind::Overflow, "4294967296".to_string(), 0..10)
+    );
+    assert_eq!(
+      error("1 frame / 10"),
+      (ParseErrorKind::MissingUnit, String::new(), 12..12)
+    );
+    assert_eq!(
+      error("2 frames / 1x s"),
+      (ParseErrorKind::InvalidCharacter, "x".to_string(), 12..13)
+    );
+    assert_eq!(
+      error("29,97"),
+      (ParseErrorKind::InvalidCharacter, ",".to_string(), 2..3)
+    );
+    assert_eq!(
+      error("24é"),
+      (ParseErrorKind::InvalidCharacter, "é".to_string(), 2..4)
+    );
+    assert_eq!(error("29.97.1").1, ".");
+    assert_eq!(
+      error("1.2345678901"),
+      (
+        ParseErrorKind::OutOfRange,
+        "1.2345678901".to_string(),
+        0..12
+      )
+    );
+    assert_eq!(
+      error("18446744073709551616.5").0,
+      ParseErrorKind::OutOfRange
+    );
+    assert_eq!(
+      error("1.00000000000000000001").0,
+      ParseErrorKind::OutOfRange
+    );
+    assert_eq!(
+      parse_frame_rate("30000/10x1").unwrap_err().to_string(),
+      "invalid character at 8..9"
+    );
+  }
+
+  #[test]
+  fn from_str() {
+    assert_eq!("23.976".parse(), Ok(FrameRate::_23_97));
+    assert_eq!("29.97".parse(), Ok(FrameRate::_29_97));
+    assert_eq!("24000/1001".parse(), Ok(FrameRate::_23_97));
+    assert_eq!("25".parse(), Ok(FrameRate::_25_00));
+    assert_eq!("50p".parse(), Ok(FrameRate::_50_00));
+    assert_eq!("59.94i".parse(), Ok(FrameRate::_29_97));
+    assert_eq!(" 50i ".parse(), Ok(FrameRate::_25_00));
+    assert_eq!("25i".parse(), Ok(FrameRate::new(25, 2)));
+    assert_eq!("24".parse::<FrameRate>().unwrap().to_string(), "24");
+    assert_eq!(FrameRate::new(48, 1).to_string(), "48/1");
+  }
+
+  #[test]
+  fn suffix_spans() {
+    let error = |input: &str| {
+      let error = input.parse::<FrameRate>().unwrap_err();
+      (error.kind, error.fragment(input).to_string())
+    };
+
+    assert_eq!(error("p"), (ParseErrorKind::MissingNumber, String::new()));
+    assert_eq!(
+      error("50 p"),
+      (ParseErrorKind::InvalidCharacter, " ".to_string())
+    );
+    assert_eq!(
+      error("50pi"),
+      (ParseErrorKind::InvalidCharacter, "p".to_string())
+    );
+    assert_eq!(
+      error("1/4294967295i"),
+      (ParseErrorKind::Overflow, "1/4294967295".to_string())
+    );
+  }
+
+  proptest! {
+    #[test]
+    fn display_round_trip(num in 0..=u32::MAX, den in 1..=u32::MAX) {
+      let frame_rate = FrameRate::new(num, den);
+      prop_assert_eq!(parse_display(&frame_rate.to_string()), Some(frame_rate));
+    }
+
+    #[test]
+    fn from_str_round_trip(frame_rate in crate::tests::any_frame_rate()) {
+      prop_assert_eq!(frame_rate.to_string().parse(), Ok(frame_rate));
+    }
+
+    #[test]
+    fn progressive_suffix(num in 0..=u32::MAX, den in 1..=u32::MAX) {
+      let frame_rate = FrameRate::new(num, den);
+      prop_assert_eq!(format!("{num}/{den}p").parse(), Ok(frame_rate));
+    }
+  }
+}
@@ -0,0 +1,206 @@
+//! [`PerRate`], a map keyed by frame rate.
+
+use crate::{FrameRate, Ratio, STANDARD_FRAME_RATES};
+use std::collections::HashMap;
+use std::collections::hash_map;
+
+const STANDARD_COUNT: usize = STANDARD_FRAME_RATES.len();
+
+/// Position in `STANDARD_FRAME_RATES`, or the ratio of a custom rate.
+fn standard_index(frame_rate: FrameRate) -> Result<usize, Ratio<u32>> {
+  match frame_rate {
+    FrameRate::_24_00 => Ok(0),
+    FrameRate::_25_00 => Ok(1),
+    FrameRate::_30_00 => Ok(2),
+    FrameRate::_50_00 => Ok(3),
+    FrameRate::_60_00 => Ok(4),
+    FrameRate::_120_00 => Ok(5),
+    FrameRate::_23_97 => Ok(6),
+    FrameRate::_24_97 => Ok(7),
+    FrameRate::_29_97 => Ok(8),
+    FrameRate::_59_94 => Ok(9),
+    FrameRate::FrCustom(ratio) => Err(ratio),
+  }
+}
+
+/// Map from frame rate to `T`, stored in a fixed array for the standard rates
+/// and in a `HashMap` for custom ones.
+#[derive(Clone, Debug)]
+pub struct PerRate<T> {
+  standard: [Option<T>; STANDARD_COUNT],
+  custom: HashMap<Ratio<u32>, T>,
+}
+
+impl<T> Default for PerRate<T> {
+  fn default() -> Self {
+    Self {
+      standard: std::array::from_fn(|_| None),
+      custom: HashMap::new(),
+    }
+  }
+}
+
+impl<T> PerRate<T> {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn get(&self, frame_rate: FrameRate) -> Option<&T> {
+    match standard_index(frame_rate) {
+      Ok(index) => self.standard[index].as_ref(),
+      Err(ratio) => self.custom.get(&ratio),
+    }
+  }
+
+  pub fn get_mut(&mut self, frame_rate: FrameRate) -> Option<&mut T> {
+    match standard_index(frame_rate) {
+      Ok(index) => self.standard[index].as_mut(),
+      Err(ratio) => self.custom.get_mut(&ratio),
+    }
+  }
+
+  /// Inserts `value`, returning the previous value for this rate.
+  pub fn insert(&mut self, frame_rate: FrameRate, value: T) -> Option<T> {
+    match standard_index(frame_rate) {
+      Ok(index) => self.standard[index].replace(value),
+      Err(ratio) => self.custom.insert(ratio, value),
+    }
+  }
+
+  pub fn remove(&mut self, frame_rate: FrameRate) -> Option<T> {
+    match standard_index(frame_rate) {
+      Ok(index) => self.standard[index].take(),
+      Err(ratio) => self.custom.remove(&ratio),
+    }
+  }
+
+  pub fn entry(&mut self, frame_rate: FrameRate) -> Entry<'_, T> {
+    match standard_index(frame_rate) {
+      Ok(index) => Entry(EntryInner::Standard(&mut self.standard[index])),
+      Err(ratio) => Entry(EntryInner::Custom(self.custom.entry(ratio))),
+    }
+  }
+
+  pub fn len(&self) -> usize {
+    self.standard.iter().flatten().count() + self.custom.len()
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.len() == 0
+  }
+
+  /// Standard rates first, then custom ones in arbitrary order.
+  pub fn iter(&self) -> impl Iterator<Item = (FrameRate, &T)> {
+    STANDARD_FRAME_RATES
+      .iter()
+      .zip(&self.standard)
+      .filter_map(|(frame_rate, value)| Some((*frame_rate, value.as_ref()?)))
+      .chain(
+        self
+          .custom
+          .iter()
+          .map(|(ratio, value)| (FrameRate::FrCustom(*ratio), value)),
+      )
+  }
+}
+
+impl<T> FromIterator<(FrameRate, T)> for PerRate<T> {
+  fn from_iter<I: IntoIterator<Item = (FrameRate, T)>>(iter: I) -> Self {
+    let mut map = Self::new();
+    for (frame_rate, value) in iter {
+      map.insert(frame_rate, value);
+    }
+    map
+  }
+}
+
+/// Slot of a [`PerRate`] map, see [`PerRate::entry`].
+pub struct Entry<'a, T>(EntryInner<'a, T>);
+
+enum EntryInner<'a, T> {
+  Standard(&'a mut Option<T>),
+  Custom(hash_map::Entry<'a, Ratio<u32>, T>),
+}
+
+impl<'a, T> Entry<'a, T> {
+  pub fn or_insert(self, default: T) -> &'a mut T {
+    self.or_insert_with(|| default)
+  }
+
+  pub fn or_insert_with<F: FnOnce() -> T>(self, default: F) -> &'a mut T {
+    match self.0 {
+      EntryInner::Standard(slot) => slot.get_or_insert_with(default),
+      EntryInner::Custom(entry) => entry.or_insert_with(default),
+    }
+  }
+
+  pub fn or_default(self) -> &'a mut T
+  where
+    T: Default,
+  {
+    self.or_insert_with(T::default)
+  }
+
+  pub fn and_modify<F: FnOnce(&mut T)>(self, f: F) -> Self {
+    match self.0 {
+      EntryInner::Standard(slot) => {
+        if let Some(value) = slot.as_mut() {
+          f(value);
+        }
+        Self(EntryInner::Standard(slot))
+      }
+      EntryInner::Custom(entry) => Self(EntryInner::Custom(entry.and_modify(f))),
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn insert_get_remove() {
+    let mut map = PerRate::new();
+    assert!(map.is_empty());
+
+    assert_eq!(map.insert(FrameRate::_29_97, "ntsc"), None);
+    assert_eq!(map.insert(FrameRate::new(48, 1), "hfr"), None);
+    assert_eq!(map.insert(FrameRate::_29_97, "29.97"), Some("ntsc"));
+    assert_eq!(map.len(), 2);
+
+    assert_eq!(map.get(FrameRate::new(30000, 1001)), Some(&"29.97"));
+    assert_eq!(map.get(FrameRate::new(96, 2)), Some(&"hfr"));
+    assert_eq!(map.get(FrameRate::_25_00), None);
+
+    assert_eq!(map.remove(FrameRate::new(48, 1)), Some("hfr"));
+    assert_eq!(map.len(), 1);
+  }
+
+  #[test]
+  fn entry() {
+    let mut counts = PerRate::<u32>::new();
+    for frame_rate in [FrameRate::_25_00, FrameRate::new(48, 1), FrameRate::_25_00] {
+      *counts.entry(frame_rate).or_default() += 1;
+    }
+    counts
+      .entry(FrameRate::new(48, 1))
+      .and_modify(|count| *count *= 10);
+
+    assert_eq!(counts.get(FrameRate::_25_00), Some(&2));
+    assert_eq!(counts.get(FrameRate::new(48, 1)), Some(&10));
+  }
+
+  #[test]
+  fn iter() {
+    let map: PerRate<_> = STANDARD_FRAME_RATES
+      .into_iter()
+      .chain([FrameRate::new(48, 1)])
+      .map(|frame_rate| (frame_rate, frame_rate.to_string()))
+      .collect();
+
+    assert_eq!(map.len(), 11);
+    for (frame_rate, name) in map.iter() {
+      assert_eq!(frame_rate.to_string(), *name);
+    }
+  }
+}
@@ -0,0 +1,100 @@
+use crate::{FrameRate, Ratio, Rounding};
+
+/// Adobe Premiere Pro timebase, divisible by the duration of a frame at every
+/// standard frame rate.
+pub const PREMIERE_TICKS_PER_SECOND: u64 = 254_016_000_000;
+
+impl FrameRate {
+  /// Number of Premiere ticks per frame, or `None` when a frame does not last
+  /// a whole number of ticks.
+  pub fn premiere_ticks_per_frame(&self) -> Option<u64> {
+    self.frames_to_premiere_ticks(1)
+  }
+
+  /// Exact number of Premiere ticks in `frames` frames, or `None` when it is
+  /// not a whole number of ticks or overflows.
+  pub fn frames_to_premiere_ticks(&self, frames: u64) -> Option<u64> {
+    let ratio = Ratio::<u32>::from(*self);
+    let numerator = u128::from(frames)
+      .checked_mul(u128::from(PREMIERE_TICKS_PER_SECOND))?
+      .checked_mul(u128::from(*ratio.denom()))?;
+    let denominator = u128::from(*ratio.numer());
+
+    if denominator == 0 || numerator % denominator != 0 {
+      return None;
+    }
+    u64::try_from(numerator / denominator).ok()
+  }
+
+  /// Frame index at a Premiere tick position, saturating at `u64::MAX`.
+  pub fn premiere_ticks_to_frames(&self, ticks: u64, rounding: Rounding) -> u64 {
+    let ratio = Ratio::<u32>::from(*self);
+    let frames = rounding.div(
+      u128::from(ticks) * u128::from(*ratio.numer()),
+      u128::from(PREMIERE_TICKS_PER_SECOND) * u128::from(*ratio.denom()),
+    );
+    u64::try_from(frames).unwrap_or(u64::MAX)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::STANDARD_FRAME_RATES;
+
+  #[test]
+  fn standard_rates_are_exact() {
+    for frame_rate in STANDARD_FRAME_RATES {
+      let ticks = frame_rate.premiere_ticks_per_frame().unwrap();
+      assert_eq!(
+        frame_rate.premiere_ticks_to_frames(ticks * 1000, Rounding::Floor),
+        1000
+      );
+    }
+
+    assert_eq!(
+      FrameRate::_23_97.premiere_ticks_per_frame(),
+      Some(10_594_584_000)
+    );
+    assert_eq!(
+      FrameRate::_25_00.premiere_ticks_per_frame(),
+      Some(10_160_640_000)
+    );
+    assert_eq!(
+      FrameRate::_29_97.premiere_ticks_per_frame(),
+      Some(8_475_667_200)
+    );
+  }
+
+  #[test]
+  fn inexact() {
+    assert_eq!(FrameRate::new(11, 1).premiere_ticks_per_frame(), None);
+    assert_eq!(
+      FrameRate::new(11, 1).frames_to_premiere_ticks(11),
+      Some(PREMIERE_TICKS_PER_SECOND)
+    );
+    assert_eq!(FrameRate::_25_00.frames_to_premiere_ticks(u64::MAX), None);
+    assert_eq!(
+      FrameRate::new(1, u32::MAX).frames_to_premiere_ticks(u64::MAX),
+      None
+    );
+    assert_eq!(FrameRate::new(0, 1).frames_to_premiere_ticks(1), None);
+  }
+
+  #[test]
+  fn ticks_to_frames() {
+    let half_frame = FrameRate::_29_97.premiere_ticks_per_frame().unwrap() / 2;
+    assert_eq!(
+      FrameRate::_29_97.premiere_ticks_to_frames(half_frame, Rounding::Floor),
+      0
+    );
+    assert_eq!(
+      FrameRate::_29_97.premiere_ticks_to_frames(half_frame, Rounding::Nearest),
+      1
+    );
+    assert_eq!(
+      FrameRate::_29_97.premiere_ticks_to_frames(PREMIERE_TICKS_PER_SECOND * 1001, Rounding::Floor),
+      30000
+    );
+  }
+}
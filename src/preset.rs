@@ -0,0 +1,176 @@
+use crate::{FrameRate, Ratio};
+use std::sync::{PoisonError, RwLock};
+
+/// A named frame rate registered at runtime.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Preset {
+  pub name: String,
+  pub frame_rate: FrameRate,
+  /// Maximum distance, in frames per second, at which a floating point
+  /// value snaps to this preset.
+  pub tolerance: f64,
+}
+
+/// Named rates added by the application on top of the standard ones, such
+/// as `"GoPro 240"` or lab specific rates.
+///
+/// Presets registered in [`PRESETS`] are parsed by [`parse_frame_rate`]
+/// (names are case insensitive), give their name to the `Display` of custom
+/// rates, and are snapped to by [`FrameRate::snap`] after the candidates of
+/// the profile.
+///
+/// [`parse_frame_rate`]: crate::parse_frame_rate
+///
+/// ```
+/// use frame_rate::{FrameRate, PRESETS, parse_frame_rate};
+///
+/// let gopro = FrameRate::new(240_000, 1001);
+/// PRESETS.register("GoPro 240", gopro, 0.01);
+/// assert_eq!(parse_frame_rate("gopro 240"), Ok(gopro));
+/// assert_eq!(gopro.to_string(), "GoPro 240");
+/// # PRESETS.unregister("GoPro 240");
+/// ```
+#[derive(Debug, Default)]
+pub struct PresetRegistry {
+  presets: RwLock<Vec<Preset>>,
+}
+
+/// Process wide registry consulted by parsing, `Display` and snapping.
+pub static PRESETS: PresetRegistry = PresetRegistry::new();
+
+impl PresetRegistry {
+  pub const fn new() -> Self {
+    Self {
+      presets: RwLock::new(Vec::new()),
+    }
+  }
+
+  /// Registers `frame_rate` under `name`, replacing a preset with the same
+  /// name.
+  pub fn register(&self, name: impl Into<String>, frame_rate: FrameRate, tolerance: f64) {
+    let preset = Preset {
+      name: name.into(),
+      frame_rate,
+      tolerance,
+    };
+    let mut presets = self.presets.write().unwrap_or_else(PoisonError::into_inner);
+    match presets
+      .iter_mut()
+      .find(|existing| existing.name.eq_ignore_ascii_case(&preset.name))
+    {
+      Some(existing) => *existing = preset,
+      None => presets.push(preset),
+    }
+  }
+
+  pub fn unregister(&self, name: &str) -> Option<Preset> {
+    let mut presets = self.presets.write().unwrap_or_else(PoisonError::into_inner);
+    let index = presets
+      .iter()
+      .position(|preset| preset.name.eq_ignore_ascii_case(name))?;
+    Some(presets.remove(index))
+  }
+
+  pub fn presets(&self) -> Vec<Preset> {
+    self.read(|presets| presets.to_vec())
+  }
+
+  /// Rate registered under `name`, ignoring ASCII case.
+  pub fn by_name(&self, name: &str) -> Option<FrameRate> {
+    self.read(|presets| {
+      presets
+        .iter()
+        .find(|preset| preset.name.eq_ignore_ascii_case(name))
+        .map(|preset| preset.frame_rate)
+    })
+  }
+
+  /// Name of the first preset registered for `frame_rate`.
+  pub fn name_of(&self, frame_rate: FrameRate) -> Option<String> {
+    let ratio = Ratio::<u32>::from(frame_rate);
+    self.read(|presets| {
+      presets
+        .iter()
+        .find(|preset| Ratio::<u32>::from(preset.frame_rate) == ratio)
+        .map(|preset| preset.name.clone())
+    })
+  }
+
+  /// Closest preset within its tolerance of `fps`.
+  pub fn snap(&self, fps: f64) -> Option<FrameRate> {
+    self.read(|presets| {
+      presets
+        .iter()
+        .map(|preset| (preset, (f64::from(&preset.frame_rate) - fps).abs()))
+        .filter(|(preset, distance)| *distance <= preset.tolerance)
+        .min_by(|(_, a), (_, b)| a.total_cmp(b))
+        .map(|(preset, _)| preset.frame_rate)
+    })
+  }
+
+  fn read<T>(&self, f: impl FnOnce(&[Preset]) -> T) -> T {
+    f(&self.presets.read().unwrap_or_else(PoisonError::into_inner))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::{FrameRateString, SnapProfile, parse_frame_rate};
+
+  #[test]
+  fn local_registry() {
+    let registry = PresetRegistry::new();
+    let lab = FrameRate::new(77, 1);
+    registry.register("Lab 77", lab, 0.1);
+    registry.register("lab 77", FrameRate::new(78, 1), 0.1);
+    assert_eq!(registry.presets().len(), 1);
+    assert_eq!(registry.by_name("LAB 77"), Some(FrameRate::new(78, 1)));
+    assert_eq!(registry.name_of(lab), None);
+
+    registry.register("Lab 77", lab, 0.1);
+    assert_eq!(
+      registry.name_of(FrameRate::new(154, 2)).as_deref(),
+      Some("Lab 77")
+    );
+    assert_eq!(registry.snap(77.05), Some(lab));
+    assert_eq!(registry.snap(77.2), None);
+    assert_eq!(
+      registry
+        .unregister("lab 77")
+        .map(|preset| preset.frame_rate),
+      Some(lab)
+    );
+    assert_eq!(registry.by_name("Lab 77"), None);
+  }
+
+  // Lib tests run in parallel and share `PRESETS`, so this test only uses
+  // names and rates that no other test formats, parses or snaps.
+  #[test]
+  fn global_registry() {
+    let lab = FrameRate::new(999_983, 1009);
+    assert_eq!(lab.to_string(), "999983/1009");
+    PRESETS.register("Global registry test", lab, 0.01);
+
+    assert_eq!(lab.to_string(), "Global registry test");
+    assert_eq!(
+      serde_json::to_value(FrameRateString(lab)).unwrap(),
+      "999983/1009"
+    );
+    assert_eq!(parse_frame_rate(" global registry TEST "), Ok(lab));
+    assert_eq!(FrameRate::snap(991.06, &SnapProfile::DEFAULT), Some(lab));
+    // Standard rates keep their name. No tolerance, so that snapping is left
+    // untouched.
+    PRESETS.register("Global registry test NTSC", FrameRate::_29_97, 0.0);
+    assert_eq!(FrameRate::_29_97.to_string(), "29.97");
+    assert_eq!(
+      parse_frame_rate("global registry test ntsc"),
+      Ok(FrameRate::_29_97)
+    );
+
+    PRESETS.unregister("Global registry test");
+    PRESETS.unregister("Global registry test NTSC");
+    assert_eq!(lab.to_string(), "999983/1009");
+    assert!(parse_frame_rate("Global registry test").is_err());
+  }
+}
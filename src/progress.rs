@@ -0,0 +1,120 @@
+use crate::{FrameRate, Rounding};
+use std::time::Duration;
+
+/// Progress of a job reading `total_frames` frames at the `source` rate and
+/// writing them at the `target` rate, so that percentages match whichever
+/// timeline a dashboard shows.
+///
+/// ```
+/// use frame_rate::{FrameRate, TranscodeProgress};
+/// use std::time::Duration;
+///
+/// let progress = TranscodeProgress::new(FrameRate::_50_00, FrameRate::_25_00, 3000);
+/// assert_eq!(progress.output_frames(750), 375);
+/// assert_eq!(progress.total_output_frames(), 1500);
+/// assert_eq!(progress.percent(750), 25.0);
+/// assert_eq!(progress.eta(750, Duration::from_secs(10)), Some(Duration::from_secs(30)));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TranscodeProgress {
+  source: FrameRate,
+  target: FrameRate,
+  total_frames: u64,
+}
+
+impl TranscodeProgress {
+  pub fn new(source: FrameRate, target: FrameRate, total_frames: u64) -> Self {
+    Self {
+      source,
+      target,
+      total_frames,
+    }
+  }
+
+  pub fn source(&self) -> FrameRate {
+    self.source
+  }
+
+  pub fn target(&self) -> FrameRate {
+    self.target
+  }
+
+  /// Number of source frames of the job.
+  pub fn total_frames(&self) -> u64 {
+    self.total_frames
+  }
+
+  /// Output frames completed once `processed` source frames were read.
+  pub fn output_frames(&self, processed: u64) -> u64 {
+    self.source.convert_frame(
+      processed.min(self.total_frames),
+      self.target,
+      Rounding::Floor,
+    )
+  }
+
+  pub fn total_output_frames(&self) -> u64 {
+    self.output_frames(self.total_frames)
+  }
+
+  /// Completion in percent, identical on the source and output timelines.
+  pub fn percent(&self, processed: u64) -> f64 {
+    if self.total_frames == 0 {
+      return 100.0;
+    }
+    processed.min(self.total_frames) as f64 * 100.0 / self.total_frames as f64
+  }
+
+  /// Processing speed relative to real time, e.g. `2.0` when `elapsed` is
+  /// half the duration of the processed source frames.
+  pub fn speed(&self, processed: u64, elapsed: Duration) -> f64 {
+    self.source.duration_of(processed).as_secs_f64() / elapsed.as_secs_f64()
+  }
+
+  /// Estimated time left assuming the throughput measured so far holds.
+  /// `None` until a frame was processed.
+  pub fn eta(&self, processed: u64, elapsed: Duration) -> Option<Duration> {
+    let processed = processed.min(self.total_frames);
+    if processed == 0 {
+      return None;
+    }
+    let remaining = u128::from(self.total_frames - processed);
+    let nanos = Rounding::Nearest.div(elapsed.as_nanos() * remaining, u128::from(processed));
+    Some(u64::try_from(nanos).map_or(Duration::MAX, Duration::from_nanos))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn cross_rate_progress() {
+    let progress = TranscodeProgress::new(FrameRate::_23_97, FrameRate::_29_97, 2400);
+    assert_eq!(progress.total_output_frames(), 3000);
+    assert_eq!(progress.output_frames(1), 1);
+    assert_eq!(progress.output_frames(1200), 1500);
+    assert_eq!(progress.output_frames(5000), 3000);
+    assert_eq!(progress.percent(600), 25.0);
+    assert_eq!(progress.percent(5000), 100.0);
+
+    let progress = TranscodeProgress::new(FrameRate::_25_00, FrameRate::_23_97, 0);
+    assert_eq!(progress.percent(0), 100.0);
+    assert_eq!(progress.eta(0, Duration::from_secs(1)), None);
+  }
+
+  #[test]
+  fn eta_and_speed() {
+    let progress = TranscodeProgress::new(FrameRate::_25_00, FrameRate::_50_00, 1000);
+    // 250 frames (10 s of media) in 4 s.
+    assert_eq!(progress.speed(250, Duration::from_secs(4)), 2.5);
+    assert_eq!(
+      progress.eta(250, Duration::from_secs(4)),
+      Some(Duration::from_secs(12))
+    );
+    assert_eq!(
+      progress.eta(1000, Duration::from_secs(16)),
+      Some(Duration::ZERO)
+    );
+  }
+}
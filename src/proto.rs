@@ -0,0 +1,71 @@
+use crate::{Error, FrameRate, Ratio};
+
+/// Source of `proto/frame_rate.proto`, defining the message mapped by
+/// [`FrameRateProto`].
+pub const FRAME_RATE_PROTO: &str = include_str!("../proto/frame_rate.proto");
+
+/// `frame_rate.FrameRate` protobuf message, same fields as the JSON form.
+#[derive(Clone, Copy, PartialEq, Eq, prost::Message)]
+pub struct FrameRateProto {
+  #[prost(uint32, tag = "1")]
+  pub num: u32,
+  #[prost(uint32, tag = "2")]
+  pub den: u32,
+}
+
+impl From<FrameRate> for FrameRateProto {
+  fn from(frame_rate: FrameRate) -> Self {
+    let ratio = Ratio::<u32>::from(frame_rate);
+    Self {
+      num: *ratio.numer(),
+      den: *ratio.denom(),
+    }
+  }
+}
+
+/// Fails on a zero denominator, which is also what an unset `den` decodes to.
+impl TryFrom<FrameRateProto> for FrameRate {
+  type Error = Error;
+
+  fn try_from(message: FrameRateProto) -> Result<Self, Error> {
+    Self::try_new(message.num, message.den)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use prost::Message;
+
+  #[test]
+  fn round_trip() {
+    let message = FrameRateProto::from(FrameRate::_29_97);
+    assert_eq!(
+      message,
+      FrameRateProto {
+        num: 30000,
+        den: 1001
+      }
+    );
+
+    let bytes = message.encode_to_vec();
+    let decoded = FrameRateProto::decode(bytes.as_slice()).unwrap();
+    assert_eq!(FrameRate::try_from(decoded), Ok(FrameRate::_29_97));
+    assert_eq!(
+      FrameRate::try_from(FrameRateProto { num: 50, den: 2 }),
+      Ok(FrameRate::_25_00)
+    );
+  }
+
+  #[test]
+  fn zero_denominator() {
+    let decoded = FrameRateProto::decode(&[][..]).unwrap();
+    assert_eq!(FrameRate::try_from(decoded), Err(Error::ZeroDenominator));
+  }
+
+  #[test]
+  fn proto_file() {
+    assert!(FRAME_RATE_PROTO.contains("uint32 num = 1;"));
+    assert!(FRAME_RATE_PROTO.contains("uint32 den = 2;"));
+  }
+}
@@ -0,0 +1,153 @@
+use crate::{FrameRate, Rounding};
+use std::time::Duration;
+
+/// Telecine cadence: number of video fields each film frame of a cycle is
+/// shown for.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cadence {
+  pub name: &'static str,
+  pub fields: &'static [u32],
+}
+
+impl Cadence {
+  /// 2:3 pulldown, 23.976 fps film to 59.94 fields per second.
+  pub const PULLDOWN_2_3: Self = Self {
+    name: "2:3",
+    fields: &[2, 3, 2, 3],
+  };
+  /// 2:3:3:2 advanced pulldown, leaving a single mixed video frame per cycle.
+  pub const PULLDOWN_2_3_3_2: Self = Self {
+    name: "2:3:3:2",
+    fields: &[2, 3, 3, 2],
+  };
+  /// 2:2:2:4 pulldown of DVCPRO HD 24pN recordings.
+  pub const PULLDOWN_2_2_2_4: Self = Self {
+    name: "2:2:2:4",
+    fields: &[2, 2, 2, 4],
+  };
+
+  /// Video fields per cycle, 10 for 2:3 pulldown.
+  pub fn cycle_fields(&self) -> u32 {
+    self.fields.iter().sum()
+  }
+
+  /// Shortest repetition of the field counts, 2 for 2:3 whose two halves
+  /// only differ by field parity.
+  pub fn period(&self) -> usize {
+    let len = self.fields.len();
+    (1..len)
+      .find(|period| {
+        len.is_multiple_of(*period)
+          && (0..len).all(|index| self.fields[index] == self.fields[(index + period) % len])
+      })
+      .unwrap_or(len)
+  }
+
+  /// Position within the cadence period of the first of `fields`, the field
+  /// counts of consecutive film frames. `None` when no phase or more than one
+  /// phase matches, e.g. with too few frames or a broken cadence.
+  pub fn phase_from_fields(&self, fields: &[u32]) -> Option<usize> {
+    let period = self.period();
+    let mut phases = (0..period).filter(|phase| {
+      fields
+        .iter()
+        .enumerate()
+        .all(|(index, count)| self.fields[(phase + index) % period] == *count)
+    });
+    let phase = phases.next()?;
+    phases.next().is_none().then_some(phase)
+  }
+
+  /// Phase from the MPEG-2 `repeat_first_field` flags of consecutive frames,
+  /// set on film frames shown for three fields.
+  pub fn phase_from_repeat_flags(&self, flags: &[bool]) -> Option<usize> {
+    let fields: Vec<u32> = flags.iter().map(|flag| 2 + u32::from(*flag)).collect();
+    self.phase_from_fields(&fields)
+  }
+
+  /// Phase from presentation times of consecutive film frames, counted in
+  /// fields at `field_rate`, e.g. 59.94.
+  pub fn phase_from_timestamps(
+    &self,
+    timestamps: &[Duration],
+    field_rate: FrameRate,
+  ) -> Option<usize> {
+    let fields: Vec<u32> = timestamps
+      .windows(2)
+      .map(|pair| {
+        field_rate.duration_to_frames(pair[1].saturating_sub(pair[0]), Rounding::Nearest) as u32
+      })
+      .collect();
+    self.phase_from_fields(&fields)
+  }
+}
+
+/// Every known cadence.
+pub static CADENCES: [Cadence; 3] = [
+  Cadence::PULLDOWN_2_3,
+  Cadence::PULLDOWN_2_3_3_2,
+  Cadence::PULLDOWN_2_2_2_4,
+];
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn phase_from_fields() {
+    let cadence = Cadence::PULLDOWN_2_3;
+    assert_eq!(cadence.cycle_fields(), 10);
+    assert_eq!(cadence.period(), 2);
+    assert_eq!(cadence.phase_from_fields(&[2, 3, 2]), Some(0));
+    assert_eq!(cadence.phase_from_fields(&[3]), Some(1));
+    assert_eq!(cadence.phase_from_fields(&[2, 3, 3]), None);
+    assert_eq!(cadence.phase_from_fields(&[]), None);
+
+    let cadence = Cadence::PULLDOWN_2_3_3_2;
+    assert_eq!(cadence.period(), 4);
+    assert_eq!(cadence.phase_from_fields(&[3, 3]), Some(1));
+    assert_eq!(cadence.phase_from_fields(&[2, 2, 3]), Some(3));
+    assert_eq!(
+      Cadence::PULLDOWN_2_2_2_4.phase_from_fields(&[4, 2]),
+      Some(3)
+    );
+    assert_eq!(Cadence::PULLDOWN_2_2_2_4.phase_from_fields(&[2, 2]), None);
+  }
+
+  #[test]
+  fn phase_from_repeat_flags() {
+    assert_eq!(
+      Cadence::PULLDOWN_2_3_3_2.phase_from_repeat_flags(&[true, false, false]),
+      Some(2)
+    );
+    assert_eq!(
+      Cadence::PULLDOWN_2_3.phase_from_repeat_flags(&[true, false, true, false]),
+      Some(1)
+    );
+    assert_eq!(
+      Cadence::PULLDOWN_2_3.phase_from_repeat_flags(&[true, true]),
+      None
+    );
+  }
+
+  #[test]
+  fn phase_from_timestamps() {
+    let field_rate = FrameRate::_59_94;
+    // Film frames starting on fields 0, 3, 5, 8 and 10: 3:2 starting at
+    // phase 1.
+    let timestamps = [0, 3, 5, 8, 10].map(|field| field_rate.duration_of(field));
+    assert_eq!(
+      Cadence::PULLDOWN_2_3.phase_from_timestamps(&timestamps, field_rate),
+      Some(1)
+    );
+    assert_eq!(
+      Cadence::PULLDOWN_2_3_3_2.phase_from_timestamps(&timestamps, field_rate),
+      None
+    );
+    assert_eq!(
+      Cadence::PULLDOWN_2_2_2_4.phase_from_timestamps(&timestamps, field_rate),
+      None
+    );
+    assert_eq!(CADENCES.len(), 3);
+  }
+}
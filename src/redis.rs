@@ -0,0 +1,53 @@
+use crate::parse::parse_ratio;
+use crate::{FrameRate, Ratio};
+use redis::{FromRedisValue, ParsingError, RedisWrite, ToRedisArgs, ToSingleRedisArg, Value};
+
+/// Written as a `"num/den"` string, e.g. `"30000/1001"`.
+impl ToRedisArgs for FrameRate {
+  fn write_redis_args<W>(&self, out: &mut W)
+  where
+    W: ?Sized + RedisWrite,
+  {
+    let ratio = Ratio::<u32>::from(*self);
+    out.write_arg_fmt(format_args!("{}/{}", ratio.numer(), ratio.denom()));
+  }
+}
+
+impl ToSingleRedisArg for FrameRate {}
+
+/// Reads a `"num/den"` or integer string.
+impl FromRedisValue for FrameRate {
+  fn from_redis_value(value: Value) -> Result<Self, ParsingError> {
+    let string = String::from_redis_value(value)?;
+    parse_ratio(&string)
+      .ok_or_else(|| format!("invalid frame rate {string:?}, expected \"num/den\"").into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn to_redis_args() {
+    assert_eq!(
+      FrameRate::_29_97.to_redis_args(),
+      vec![b"30000/1001".to_vec()]
+    );
+    assert_eq!(FrameRate::_25_00.to_redis_args(), vec![b"25/1".to_vec()]);
+  }
+
+  #[test]
+  fn from_redis_value() {
+    assert_eq!(
+      FrameRate::from_redis_value(Value::BulkString(b"60000/1001".to_vec())).unwrap(),
+      FrameRate::_59_94
+    );
+    assert_eq!(
+      FrameRate::from_redis_value(Value::SimpleString("24".to_string())).unwrap(),
+      FrameRate::_24_00
+    );
+    assert!(FrameRate::from_redis_value(Value::BulkString(b"25/0".to_vec())).is_err());
+    assert!(FrameRate::from_redis_value(Value::Nil).is_err());
+  }
+}
@@ -0,0 +1,144 @@
+use crate::parse::parse_display;
+use crate::{Error, FrameRate, Ratio};
+
+/// Parses a retime notation into the resulting frame rate and its speed factor
+/// relative to the base rate.
+///
+/// Accepted forms, with optional whitespace:
+/// - `200%` or `2x`: speed relative to `base`,
+/// - `48fps`: absolute rate, compared to `base`,
+/// - `48fps @ 24 base`: absolute rate with its own base, taking precedence
+///   over `base`.
+///
+/// Rates use any `Display` form (`23.976`, `30000/1001`, ...), percentages and
+/// factors may be decimal.
+pub fn parse_relative(
+  notation: &str,
+  base: Option<FrameRate>,
+) -> Result<(FrameRate, Ratio<u32>), Error> {
+  let invalid = || Error::InvalidRelativeRate(notation.to_string());
+  let value = notation.trim();
+
+  if let Some((rate, base)) = value.split_once('@') {
+    let base = base.trim().strip_suffix("base").ok_or_else(invalid)?;
+    let base = parse_rate(base).ok_or_else(invalid)?;
+    let frame_rate = parse_rate(rate).ok_or_else(invalid)?;
+    return Ok((frame_rate, speed(frame_rate, base).ok_or_else(invalid)?));
+  }
+
+  if let Some(percent) = value.strip_suffix('%') {
+    let speed = parse_decimal(percent).ok_or_else(invalid)? / Ratio::from_integer(100);
+    return relative_to(base.ok_or_else(invalid)?, speed).ok_or_else(invalid);
+  }
+  if let Some(factor) = value.strip_suffix('x') {
+    let speed = parse_decimal(factor).ok_or_else(invalid)?;
+    return relative_to(base.ok_or_else(invalid)?, speed).ok_or_else(invalid);
+  }
+
+  let frame_rate = parse_rate(value).ok_or_else(invalid)?;
+  let base = base.ok_or_else(invalid)?;
+  Ok((frame_rate, speed(frame_rate, base).ok_or_else(invalid)?))
+}
+
+fn parse_rate(value: &str) -> Option<FrameRate> {
+  let value = value.trim();
+  parse_display(value.strip_suffix("fps").unwrap_or(value))
+}
+
+/// Exact value of a decimal number such as `"41.5"`.
+fn parse_decimal(value: &str) -> Option<Ratio<u64>> {
+  let value = value.trim();
+  let (integer, fraction) = match value.split_once('.') {
+    Some((_, "")) => return None,
+    Some(parts) => parts,
+    None => (value, ""),
+  };
+  if integer.is_empty() || fraction.len() > 9 {
+    return None;
+  }
+  let digits = format!("{integer}{fraction}");
+  if !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+    return None;
+  }
+  Some(Ratio::new(
+    digits.parse().ok()?,
+    10u64.pow(fraction.len() as u32),
+  ))
+}
+
+fn narrow(ratio: Ratio<u64>) -> Option<Ratio<u32>> {
+  Some(Ratio::new(
+    u32::try_from(*ratio.numer()).ok()?,
+    u32::try_from(*ratio.denom()).ok()?,
+  ))
+}
+
+fn speed(frame_rate: FrameRate, base: FrameRate) -> Option<Ratio<u32>> {
+  narrow(base.playback_speed(frame_rate)?)
+}
+
+fn relative_to(base: FrameRate, speed: Ratio<u64>) -> Option<(FrameRate, Ratio<u32>)> {
+  let speed = narrow(speed)?;
+  let base = Ratio::<u32>::from(base);
+  let frame_rate = narrow(Ratio::new(
+    u64::from(*base.numer()) * u64::from(*speed.numer()),
+    u64::from(*base.denom()) * u64::from(*speed.denom()),
+  ))?;
+  Some((frame_rate.into(), speed))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn speed_notations() {
+    let base = Some(FrameRate::_24_00);
+    assert_eq!(
+      parse_relative("200%", base),
+      Ok((FrameRate::new(48, 1), Ratio::from_integer(2)))
+    );
+    assert_eq!(
+      parse_relative("50 %", base),
+      Ok((FrameRate::new(12, 1), Ratio::new(1, 2)))
+    );
+    assert_eq!(
+      parse_relative("1.25x", Some(FrameRate::_24_00)),
+      Ok((FrameRate::_30_00, Ratio::new(5, 4)))
+    );
+  }
+
+  #[test]
+  fn rate_notations() {
+    assert_eq!(
+      parse_relative("48fps @ 24 base", None),
+      Ok((FrameRate::new(48, 1), Ratio::from_integer(2)))
+    );
+    assert_eq!(
+      parse_relative("48fps@24fps base", Some(FrameRate::_25_00)),
+      Ok((FrameRate::new(48, 1), Ratio::from_integer(2)))
+    );
+    assert_eq!(
+      parse_relative("59.94fps", Some(FrameRate::_29_97)),
+      Ok((FrameRate::_59_94, Ratio::from_integer(2)))
+    );
+    assert_eq!(
+      parse_relative("24 fps", Some(FrameRate::_23_97)),
+      Ok((FrameRate::_24_00, Ratio::new(1001, 1000)))
+    );
+  }
+
+  #[test]
+  fn invalid() {
+    for notation in ["200%", "48fps", "2x"] {
+      assert_eq!(
+        parse_relative(notation, None),
+        Err(Error::InvalidRelativeRate(notation.to_string()))
+      );
+    }
+    let base = Some(FrameRate::_24_00);
+    for notation in ["", "-50%", "fast", "48fps @ 24", "48fps @ 0 base", "1.x"] {
+      assert!(parse_relative(notation, base).is_err(), "{notation}");
+    }
+  }
+}
@@ -0,0 +1,161 @@
+use crate::{Error, FrameRate, Ratio};
+
+/// Playback speed at an output time, in seconds, of a [`TimeRemap`] curve.
+/// Negative speeds play backwards.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SpeedKeyframe {
+  pub time: Ratio<u64>,
+  pub speed: Ratio<i64>,
+}
+
+/// Piecewise-linear speed curve mapping output times to source times, as
+/// exported by editing applications for retimed clips.
+///
+/// Speed is linearly interpolated between keyframes and held before the
+/// first and after the last one. Source time 0 is shown at output time 0.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TimeRemap {
+  keyframes: Vec<SpeedKeyframe>,
+}
+
+impl TimeRemap {
+  /// Curve through `keyframes`, which must be non empty and in strictly
+  /// increasing time order.
+  pub fn new(keyframes: Vec<SpeedKeyframe>) -> Result<Self, Error> {
+    if keyframes.is_empty() {
+      return Err(Error::InvalidTimeRemap("no keyframes"));
+    }
+    if keyframes
+      .windows(2)
+      .any(|pair| pair[0].time >= pair[1].time)
+    {
+      return Err(Error::InvalidTimeRemap(
+        "keyframes are not in increasing time order",
+      ));
+    }
+    Ok(Self { keyframes })
+  }
+
+  pub fn keyframes(&self) -> &[SpeedKeyframe] {
+    &self.keyframes
+  }
+
+  /// Exact source time in seconds shown at output time `time` seconds.
+  ///
+  /// # Panics
+  ///
+  /// Panics if intermediate values overflow `Ratio<i128>`.
+  pub fn source_time(&self, time: Ratio<u64>) -> Ratio<i128> {
+    let time = widen(time.into_raw());
+    let first = &self.keyframes[0];
+    let mut previous = (Ratio::from_integer(0), widen(first.speed.into_raw()));
+    let mut source = Ratio::from_integer(0);
+
+    for keyframe in &self.keyframes {
+      let next = (
+        widen(keyframe.time.into_raw()),
+        widen(keyframe.speed.into_raw()),
+      );
+      let end = if time < next.0 { time } else { next.0 };
+      source += integrate(previous, next, end);
+      if time <= next.0 {
+        return source;
+      }
+      previous = next;
+    }
+    source + previous.1 * (time - previous.0)
+  }
+
+  /// Exact source time shown at the start of each of the first `frames`
+  /// output frames at `frame_rate`.
+  ///
+  /// # Panics
+  ///
+  /// Panics if intermediate values overflow `Ratio<i128>`.
+  pub fn source_times(&self, frame_rate: FrameRate, frames: u64) -> Vec<Ratio<i128>> {
+    (0..frames)
+      .map(|frame| self.source_time(frame_rate.time_after(frame)))
+      .collect()
+  }
+}
+
+fn widen<T: Into<i128>>((numer, denom): (T, T)) -> Ratio<i128> {
+  Ratio::new(numer.into(), denom.into())
+}
+
+/// Integral from `start.0` to `end` of the speed interpolated linearly from
+/// `start` to `stop`, both `(time, speed)`.
+fn integrate(
+  (start_time, start_speed): (Ratio<i128>, Ratio<i128>),
+  (stop_time, stop_speed): (Ratio<i128>, Ratio<i128>),
+  end: Ratio<i128>,
+) -> Ratio<i128> {
+  let elapsed = end - start_time;
+  if stop_time == start_time {
+    return start_speed * elapsed;
+  }
+  let acceleration = (stop_speed - start_speed) / (stop_time - start_time);
+  start_speed * elapsed + acceleration * elapsed * elapsed / 2
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn keyframe(time: u64, speed: i64) -> SpeedKeyframe {
+    SpeedKeyframe {
+      time: Ratio::from_integer(time),
+      speed: Ratio::from_integer(speed),
+    }
+  }
+
+  #[test]
+  fn constant() {
+    let remap = TimeRemap::new(vec![keyframe(0, 2)]).unwrap();
+    assert_eq!(remap.source_time(Ratio::new(3, 2)), Ratio::from_integer(3));
+    assert_eq!(
+      remap.source_times(FrameRate::_29_97, 3),
+      [
+        Ratio::from_integer(0),
+        Ratio::new(1001, 15000),
+        Ratio::new(1001, 7500)
+      ]
+    );
+  }
+
+  #[test]
+  fn ramp() {
+    // Ramp from normal speed to 3x over 2 s, then back to reverse.
+    let remap = TimeRemap::new(vec![keyframe(1, 1), keyframe(3, 3), keyframe(4, -1)]).unwrap();
+    assert_eq!(
+      remap.source_time(Ratio::from_integer(1)),
+      Ratio::from_integer(1)
+    );
+    assert_eq!(remap.source_time(Ratio::from_integer(2)), Ratio::new(5, 2));
+    assert_eq!(
+      remap.source_time(Ratio::from_integer(3)),
+      Ratio::from_integer(5)
+    );
+    assert_eq!(
+      remap.source_time(Ratio::from_integer(4)),
+      Ratio::from_integer(6)
+    );
+    assert_eq!(
+      remap.source_time(Ratio::from_integer(6)),
+      Ratio::from_integer(4)
+    );
+    assert_eq!(
+      remap.source_times(FrameRate::_25_00, 51)[50],
+      Ratio::new(5, 2)
+    );
+  }
+
+  #[test]
+  fn invalid() {
+    assert_eq!(
+      TimeRemap::new(vec![]),
+      Err(Error::InvalidTimeRemap("no keyframes"))
+    );
+    assert!(TimeRemap::new(vec![keyframe(1, 1), keyframe(1, 2)]).is_err());
+  }
+}
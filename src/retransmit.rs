@@ -0,0 +1,106 @@
+use crate::{FrameRate, Rounding};
+use std::time::Duration;
+
+/// Exponential backoff of retransmission requests for a lost frame, bounded
+/// by a repair deadline in frame periods, e.g. "repair must arrive within 2
+/// frame periods".
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RetransmitBackoff {
+  frame_rate: FrameRate,
+  deadline_frames: u64,
+  first_retry: Duration,
+}
+
+impl RetransmitBackoff {
+  /// Backoff whose first request is sent half a frame period after the loss.
+  pub fn new(frame_rate: FrameRate, deadline_frames: u64) -> Self {
+    let first_retry = frame_rate.frames_to_duration(1, Rounding::Ceil) / 2;
+    Self {
+      frame_rate,
+      deadline_frames,
+      first_retry,
+    }
+  }
+
+  /// Sends the first request `first_retry` after the loss instead.
+  pub fn with_first_retry(mut self, first_retry: Duration) -> Self {
+    self.first_retry = first_retry;
+    self
+  }
+
+  pub fn frame_rate(&self) -> FrameRate {
+    self.frame_rate
+  }
+
+  pub fn deadline_frames(&self) -> u64 {
+    self.deadline_frames
+  }
+
+  /// Time after the loss past which a repair is useless, rounded down so a
+  /// late repair is never accepted.
+  pub fn deadline(&self) -> Duration {
+    self
+      .frame_rate
+      .frames_to_duration(self.deadline_frames, Rounding::Floor)
+  }
+
+  /// Whether a repair arriving `elapsed` after the loss is too late.
+  pub fn is_expired(&self, elapsed: Duration) -> bool {
+    elapsed >= self.deadline()
+  }
+
+  /// Times after the loss at which to request a retransmission: the first
+  /// retry, then doubling intervals, as long as before the deadline.
+  pub fn retry_times(&self) -> impl Iterator<Item = Duration> + use<> {
+    let deadline = self.deadline();
+    let mut interval = self.first_retry;
+    let mut time = Duration::ZERO;
+    std::iter::from_fn(move || {
+      if interval.is_zero() {
+        return None;
+      }
+      time = time.checked_add(interval)?;
+      interval = interval.saturating_mul(2);
+      (time < deadline).then_some(time)
+    })
+  }
+
+  /// Next retransmission request strictly after `elapsed`, `None` once no
+  /// request can be answered before the deadline.
+  pub fn next_retry(&self, elapsed: Duration) -> Option<Duration> {
+    self.retry_times().find(|time| *time > elapsed)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn deadline() {
+    let backoff = RetransmitBackoff::new(FrameRate::_29_97, 2);
+    assert_eq!(backoff.deadline(), Duration::from_nanos(66_733_333));
+    assert!(!backoff.is_expired(Duration::from_nanos(66_733_332)));
+    assert!(backoff.is_expired(Duration::from_nanos(66_733_333)));
+  }
+
+  #[test]
+  fn retry_times() {
+    let backoff = RetransmitBackoff::new(FrameRate::_25_00, 4);
+    let times: Vec<_> = backoff.retry_times().collect();
+    assert_eq!(times, [20, 60, 140].map(Duration::from_millis));
+    assert_eq!(
+      backoff.next_retry(Duration::from_millis(20)),
+      Some(Duration::from_millis(60))
+    );
+    assert_eq!(backoff.next_retry(Duration::from_millis(140)), None);
+
+    let backoff = backoff.with_first_retry(Duration::from_millis(5));
+    assert_eq!(
+      backoff.retry_times().collect::<Vec<_>>(),
+      [5, 15, 35, 75, 155].map(Duration::from_millis)
+    );
+    let backoff = backoff.with_first_retry(Duration::ZERO);
+    assert_eq!(backoff.retry_times().count(), 0);
+  }
+}
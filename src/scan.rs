@@ -0,0 +1,160 @@
+use crate::{Error, FrameRate, Rounding, Timecode};
+use std::ops::Range;
+use std::time::Duration;
+
+/// Whether frames are transmitted whole or as two interlaced fields.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ScanMode {
+  Progressive,
+  Interlaced,
+}
+
+impl ScanMode {
+  /// Pictures per frame: 1 progressive, 2 interlaced.
+  pub fn fields_per_frame(&self) -> u32 {
+    match self {
+      Self::Progressive => 1,
+      Self::Interlaced => 2,
+    }
+  }
+
+  /// Frame containing field `field`.
+  pub fn frame_of_field(&self, field: u64) -> u64 {
+    field / u64::from(self.fields_per_frame())
+  }
+
+  /// Fields of frame `frame`, in temporal order.
+  pub fn fields_of_frame(&self, frame: u64) -> Range<u64> {
+    let fields = u64::from(self.fields_per_frame());
+    frame * fields..(frame + 1) * fields
+  }
+}
+
+/// Field of an interlaced frame.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Field {
+  Top,
+  Bottom,
+}
+
+/// Field dominance: which field of a frame comes first in time.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FieldOrder {
+  /// Usual for HD and PAL DV.
+  TopFieldFirst,
+  /// Usual for NTSC DV.
+  BottomFieldFirst,
+}
+
+impl FieldOrder {
+  /// First field of each frame in time.
+  pub fn dominant(&self) -> Field {
+    match self {
+      Self::TopFieldFirst => Field::Top,
+      Self::BottomFieldFirst => Field::Bottom,
+    }
+  }
+
+  /// Parity of interlaced field `field`, counted from the first field of the
+  /// content.
+  pub fn field(&self, field: u64) -> Field {
+    match (self.dominant(), field % 2) {
+      (dominant, 0) => dominant,
+      (Field::Top, _) => Field::Bottom,
+      (Field::Bottom, _) => Field::Top,
+    }
+  }
+}
+
+impl FrameRate {
+  /// Pictures per second with `scan`, e.g. 59.94 fields for 29.97 fps
+  /// interlaced.
+  ///
+  /// # Panics
+  ///
+  /// Panics if the result does not fit in a `Ratio<u32>`.
+  pub fn field_rate(&self, scan: ScanMode) -> FrameRate {
+    *self * scan.fields_per_frame()
+  }
+
+  /// Field on screen at `time`.
+  pub fn field_at(&self, scan: ScanMode, time: Duration) -> u64 {
+    self
+      .field_rate(scan)
+      .duration_to_frames(time, Rounding::Floor)
+  }
+
+  /// Start time of field `field`, rounded to the nearest nanosecond.
+  pub fn field_start(&self, scan: ScanMode, field: u64) -> Duration {
+    self.field_rate(scan).duration_of(field)
+  }
+
+  /// Timecode of the frame holding interlaced field `field`, and the parity
+  /// of that field given the field dominance `order`.
+  pub fn field_timecode(
+    &self,
+    order: FieldOrder,
+    field: u64,
+    drop_frame: bool,
+  ) -> Result<(Timecode, Field), Error> {
+    let frame = ScanMode::Interlaced.frame_of_field(field);
+    let timecode = Timecode::with_drop_frame(*self, frame, drop_frame)?;
+    Ok((timecode, order.field(field)))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn field_indices() {
+    assert_eq!(ScanMode::Interlaced.frame_of_field(5), 2);
+    assert_eq!(ScanMode::Interlaced.fields_of_frame(2), 4..6);
+    assert_eq!(ScanMode::Progressive.frame_of_field(5), 5);
+    assert_eq!(ScanMode::Progressive.fields_of_frame(2), 2..3);
+  }
+
+  #[test]
+  fn field_order() {
+    assert_eq!(FieldOrder::TopFieldFirst.field(0), Field::Top);
+    assert_eq!(FieldOrder::TopFieldFirst.field(3), Field::Bottom);
+    assert_eq!(FieldOrder::BottomFieldFirst.field(0), Field::Bottom);
+    assert_eq!(FieldOrder::BottomFieldFirst.field(7), Field::Top);
+  }
+
+  #[test]
+  fn field_timing() {
+    let scan = ScanMode::Interlaced;
+    assert_eq!(FrameRate::_29_97.field_rate(scan), FrameRate::_59_94);
+    assert_eq!(
+      FrameRate::_25_00.field_at(scan, Duration::from_millis(30)),
+      1
+    );
+    assert_eq!(
+      FrameRate::_25_00.field_at(scan, Duration::from_millis(40)),
+      2
+    );
+    assert_eq!(
+      FrameRate::_29_97.field_start(scan, 3),
+      Duration::from_nanos(50_050_000)
+    );
+    assert_eq!(
+      FrameRate::_25_00.field_at(ScanMode::Progressive, Duration::from_millis(30)),
+      0
+    );
+  }
+
+  #[test]
+  fn field_timecode() {
+    let (timecode, field) = FrameRate::_29_97
+      .field_timecode(FieldOrder::BottomFieldFirst, 3601, true)
+      .unwrap();
+    assert_eq!(timecode.to_string(), "00:01:00;02");
+    assert_eq!(field, Field::Top);
+    assert_eq!(
+      FrameRate::_25_00.field_timecode(FieldOrder::TopFieldFirst, 0, true),
+      Err(Error::NoDropFrame(FrameRate::_25_00))
+    );
+  }
+}
@@ -0,0 +1,107 @@
+use crate::{FrameRate, Ratio, Rounding};
+use std::time::Duration;
+
+/// Where to start decoding to display a requested time in a stream with a
+/// fixed GOP length.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SeekTarget {
+  /// Index of the IDR frame to start decoding from.
+  pub idr_frame: u64,
+  /// Exact presentation time of the IDR frame, in seconds.
+  pub idr_time: Ratio<u64>,
+  /// Frame displayed at the requested time.
+  pub frame: u64,
+  /// Frames to decode and discard before `frame`.
+  pub preroll_frames: u64,
+}
+
+impl FrameRate {
+  /// Seek target for `time` in a stream with an IDR frame every `gop_frames`
+  /// frames, starting at frame 0. `None` when the time of the IDR frame does
+  /// not fit in a `Ratio<u64>`, see [`FrameRate::checked_time_after`]. At
+  /// 0 fps, frame 0 shows forever.
+  ///
+  /// ```
+  /// use frame_rate::{FrameRate, Ratio};
+  /// use std::time::Duration;
+  ///
+  /// let target = FrameRate::_29_97.seek_target(15, Duration::from_secs(1)).unwrap();
+  /// assert_eq!(target.frame, 29);
+  /// assert_eq!(target.idr_frame, 15);
+  /// assert_eq!(target.idr_time, Ratio::new(1001, 2000));
+  /// assert_eq!(target.preroll_frames, 14);
+  /// ```
+  ///
+  /// # Panics
+  ///
+  /// Panics if `gop_frames` is zero.
+  pub fn seek_target(&self, gop_frames: u64, time: Duration) -> Option<SeekTarget> {
+    assert_ne!(gop_frames, 0, "GOP length must not be zero");
+    let frame = self.duration_to_frames(time, Rounding::Floor);
+    let preroll_frames = frame % gop_frames;
+    let idr_frame = frame - preroll_frames;
+    Some(SeekTarget {
+      idr_frame,
+      idr_time: self.checked_time_after(idr_frame)?,
+      frame,
+      preroll_frames,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn seek_target() {
+    let target = FrameRate::_25_00.seek_target(50, Duration::from_millis(4039));
+    assert_eq!(
+      target,
+      Some(SeekTarget {
+        idr_frame: 100,
+        idr_time: Ratio::from_integer(4),
+        frame: 100,
+        preroll_frames: 0,
+      })
+    );
+
+    let target = FrameRate::_25_00
+      .seek_target(50, Duration::from_millis(3999))
+      .unwrap();
+    assert_eq!(target.frame, 99);
+    assert_eq!(target.idr_frame, 50);
+    assert_eq!(target.idr_time, Ratio::from_integer(2));
+    assert_eq!(target.preroll_frames, 49);
+
+    let target = FrameRate::_59_94
+      .seek_target(1, Duration::from_secs(10))
+      .unwrap();
+    assert_eq!(target.idr_frame, 599);
+    assert_eq!(target.preroll_frames, 0);
+  }
+
+  #[test]
+  fn out_of_range() {
+    let target = FrameRate::new(0, 1).seek_target(50, Duration::MAX);
+    assert_eq!(
+      target,
+      Some(SeekTarget {
+        idr_frame: 0,
+        idr_time: Ratio::from_integer(0),
+        frame: 0,
+        preroll_frames: 0,
+      })
+    );
+    assert_eq!(
+      FrameRate::new(u32::MAX, u32::MAX - 1).seek_target(2, Duration::MAX),
+      None
+    );
+  }
+
+  #[test]
+  #[should_panic(expected = "GOP length must not be zero")]
+  fn zero_gop() {
+    FrameRate::_25_00.seek_target(0, Duration::ZERO);
+  }
+}
@@ -0,0 +1,139 @@
+use crate::{Error, FrameRate, total_duration};
+use std::time::Duration;
+
+/// Frame rate of a recording whose rate changed along the way, as
+/// `(start_frame, frame_rate)` pairs. The last rate runs on, so the file can
+/// keep growing.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SegmentedFrameRate {
+  segments: Vec<(u64, FrameRate)>,
+}
+
+impl SegmentedFrameRate {
+  pub fn new(frame_rate: FrameRate) -> Self {
+    Self {
+      segments: vec![(0, frame_rate)],
+    }
+  }
+
+  /// Switches to `frame_rate` from frame `start` on, which must come after
+  /// the previous change.
+  pub fn change_at(&mut self, start: u64, frame_rate: FrameRate) -> Result<(), Error> {
+    let (previous, _) = self.segments[self.segments.len() - 1];
+    if start <= previous {
+      return Err(Error::UnorderedRateChange { previous, start });
+    }
+    self.segments.push((start, frame_rate));
+    Ok(())
+  }
+
+  /// `(start_frame, frame_rate)` pairs, the first one starting at frame 0.
+  pub fn segments(&self) -> &[(u64, FrameRate)] {
+    &self.segments
+  }
+
+  /// Frame rate of frame `frame`.
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(self), ret)
+  )]
+  pub fn rate_at(&self, frame: u64) -> FrameRate {
+    let index = self.segments.partition_point(|(start, _)| *start <= frame);
+    self.segments[index - 1].1
+  }
+
+  /// Start time of frame `frame`, i.e. duration of the first `frame` frames,
+  /// rounded once to the nearest nanosecond. Frames after a change to 0 fps
+  /// never start, at `Duration::MAX`.
+  pub fn time_of(&self, frame: u64) -> Duration {
+    total_duration(
+      self
+        .segments
+        .iter()
+        .enumerate()
+        .take_while(|(_, (start, _))| *start < frame)
+        .map(|(index, (start, frame_rate))| {
+          let end = self
+            .segments
+            .get(index + 1)
+            .map_or(frame, |(next, _)| (*next).min(frame));
+          (*frame_rate, end - start)
+        }),
+    )
+  }
+
+  /// Duration of a recording of `frames` frames.
+  pub fn duration(&self, frames: u64) -> Duration {
+    self.time_of(frames)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn recording() -> SegmentedFrameRate {
+    let mut frame_rate = SegmentedFrameRate::new(FrameRate::_25_00);
+    frame_rate.change_at(250, FrameRate::_50_00).unwrap();
+    frame_rate.change_at(750, FrameRate::_29_97).unwrap();
+    frame_rate
+  }
+
+  #[test]
+  fn rate_at() {
+    let frame_rate = recording();
+    assert_eq!(frame_rate.rate_at(0), FrameRate::_25_00);
+    assert_eq!(frame_rate.rate_at(249), FrameRate::_25_00);
+    assert_eq!(frame_rate.rate_at(250), FrameRate::_50_00);
+    assert_eq!(frame_rate.rate_at(1_000_000), FrameRate::_29_97);
+  }
+
+  #[test]
+  fn duration() {
+    let frame_rate = recording();
+    assert_eq!(frame_rate.time_of(0), Duration::ZERO);
+    assert_eq!(frame_rate.time_of(100), Duration::from_secs(4));
+    assert_eq!(frame_rate.time_of(500), Duration::from_secs(15));
+    assert_eq!(frame_rate.duration(750), Duration::from_secs(20));
+    assert_eq!(
+      frame_rate.duration(750 + 30_000),
+      Duration::from_secs(20 + 1001)
+    );
+  }
+
+  #[test]
+  fn zero_frame_rate() {
+    let mut frame_rate = recording();
+    frame_rate.change_at(1000, FrameRate::new(0, 1)).unwrap();
+    assert_eq!(frame_rate.time_of(500), Duration::from_secs(15));
+    assert_eq!(
+      frame_rate.time_of(1000),
+      frame_rate.time_of(999) + Duration::from_nanos(33_366_667)
+    );
+    assert_eq!(frame_rate.time_of(1001), Duration::MAX);
+
+    frame_rate.change_at(1010, FrameRate::_25_00).unwrap();
+    assert_eq!(frame_rate.time_of(1000), recording().time_of(1000));
+    assert_eq!(frame_rate.time_of(2000), Duration::MAX);
+  }
+
+  #[test]
+  fn unordered() {
+    let mut frame_rate = recording();
+    assert_eq!(
+      frame_rate.change_at(750, FrameRate::_24_00),
+      Err(Error::UnorderedRateChange {
+        previous: 750,
+        start: 750
+      })
+    );
+    assert_eq!(
+      SegmentedFrameRate::new(FrameRate::_24_00).change_at(0, FrameRate::_25_00),
+      Err(Error::UnorderedRateChange {
+        previous: 0,
+        start: 0
+      })
+    );
+    assert_eq!(frame_rate.segments().len(), 3);
+  }
+}
@@ -0,0 +1,164 @@
+use crate::FrameRate;
+use std::ops::Range;
+use std::time::Duration;
+
+/// A frame-numbered image sequence (e.g. `shot.0001.exr`, `shot.0002.exr`, …)
+/// played at a frame rate.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImageSequence {
+  frame_rate: FrameRate,
+  frame_numbers: Vec<u64>,
+}
+
+impl ImageSequence {
+  pub fn new(frame_rate: FrameRate, frame_numbers: impl IntoIterator<Item = u64>) -> Self {
+    let mut frame_numbers: Vec<u64> = frame_numbers.into_iter().collect();
+    frame_numbers.sort_unstable();
+    Self {
+      frame_rate,
+      frame_numbers,
+    }
+  }
+
+  /// Build a sequence from file paths, ignoring paths without a frame number.
+  pub fn from_paths<'a>(frame_rate: FrameRate, paths: impl IntoIterator<Item = &'a str>) -> Self {
+    Self::new(frame_rate, paths.into_iter().filter_map(frame_number))
+  }
+
+  pub fn frame_rate(&self) -> FrameRate {
+    self.frame_rate
+  }
+
+  pub fn first(&self) -> Option<u64> {
+    self.frame_numbers.first().copied()
+  }
+
+  pub fn last(&self) -> Option<u64> {
+    self.frame_numbers.last().copied()
+  }
+
+  /// Timestamp of each frame, relative to the first frame of the sequence.
+  pub fn timestamps(&self) -> impl Iterator<Item = (u64, Duration)> + '_ {
+    let first = self.first().unwrap_or_default();
+    self
+      .frame_numbers
+      .iter()
+      .map(move |number| (*number, self.frame_rate.duration_of(number - first)))
+  }
+
+  /// Duration from the start of the first frame to the end of the last one,
+  /// saturating at `Duration::MAX`.
+  pub fn duration(&self) -> Duration {
+    match (self.first(), self.last()) {
+      (Some(first), Some(last)) => self
+        .frame_rate
+        .duration_of((last - first).saturating_add(1)),
+      _ => Duration::ZERO,
+    }
+  }
+
+  /// Ranges of frame numbers absent from the sequence.
+  pub fn missing(&self) -> Vec<Range<u64>> {
+    self
+      .frame_numbers
+      .windows(2)
+      .filter_map(|pair| {
+        let next = pair[0].checked_add(1)?;
+        (next < pair[1]).then_some(next..pair[1])
+      })
+      .collect()
+  }
+
+  /// Frame numbers present more than once in the sequence.
+  pub fn duplicates(&self) -> Vec<u64> {
+    let mut duplicates: Vec<u64> = self
+      .frame_numbers
+      .windows(2)
+      .filter(|pair| pair[0] == pair[1])
+      .map(|pair| pair[0])
+      .collect();
+    duplicates.dedup();
+    duplicates
+  }
+}
+
+/// Frame number of an image sequence file: the last group of digits of its file
+/// name, extension excluded.
+pub fn frame_number(path: &str) -> Option<u64> {
+  let file_name = path.rsplit(['/', '\\']).next()?;
+  let stem = file_name
+    .rsplit_once('.')
+    .map_or(file_name, |(stem, _)| stem);
+  let digits = stem.len() - stem.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+  stem[stem.len() - digits..].parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn parse_frame_number() {
+    assert_eq!(frame_number("shot.0001.exr"), Some(1));
+    assert_eq!(frame_number("/renders/v2/shot_v2.1001.dpx"), Some(1001));
+    assert_eq!(frame_number("C:\\renders\\shot_1001.tif"), Some(1001));
+    assert_eq!(frame_number("frame42"), Some(42));
+    assert_eq!(frame_number("/renders/v2/shot.exr"), None);
+    assert_eq!(frame_number("shot.1001/"), None);
+  }
+
+  #[test]
+  fn timestamps_and_duration() {
+    let sequence = ImageSequence::new(FrameRate::_25_00, [1003, 1001, 1002]);
+    assert_eq!(
+      sequence.timestamps().collect::<Vec<_>>(),
+      vec![
+        (1001, Duration::ZERO),
+        (1002, Duration::from_millis(40)),
+        (1003, Duration::from_millis(80)),
+      ]
+    );
+    assert_eq!(sequence.duration(), Duration::from_millis(120));
+
+    let empty = ImageSequence::new(FrameRate::_25_00, []);
+    assert_eq!(empty.duration(), Duration::ZERO);
+    assert!(empty.missing().is_empty());
+  }
+
+  #[test]
+  fn missing_and_duplicates() {
+    let sequence = ImageSequence::from_paths(
+      FrameRate::_24_00,
+      [
+        "shot.0001.exr",
+        "shot.0002.exr",
+        "shot.0002.exr",
+        "shot.0005.exr",
+        "shot.0007.exr",
+        "shot.0007.exr",
+        "shot.0007.exr",
+        "thumbnail.jpg",
+      ],
+    );
+    assert_eq!(sequence.first(), Some(1));
+    assert_eq!(sequence.last(), Some(7));
+    assert_eq!(sequence.missing(), vec![3..5, 6..7]);
+    assert_eq!(sequence.duplicates(), vec![2, 7]);
+    assert_eq!(sequence.duration(), Duration::from_nanos(291_666_667));
+  }
+
+  #[test]
+  fn largest_frame_numbers() {
+    let sequence = ImageSequence::new(FrameRate::_25_00, [0, u64::MAX - 2, u64::MAX, u64::MAX]);
+    assert_eq!(
+      sequence.missing(),
+      vec![1..u64::MAX - 2, u64::MAX - 1..u64::MAX]
+    );
+    assert_eq!(sequence.duplicates(), vec![u64::MAX]);
+    assert_eq!(sequence.duration(), FrameRate::_25_00.duration_of(u64::MAX));
+    assert_eq!(
+      ImageSequence::new(FrameRate::new(0, 1), [1, 2]).duration(),
+      Duration::MAX
+    );
+  }
+}
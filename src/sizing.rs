@@ -0,0 +1,112 @@
+use crate::{FrameRate, Ratio, Rounding};
+use std::time::Duration;
+
+impl FrameRate {
+  /// Number of frames to buffer to cover `latency`, rounded up.
+  ///
+  /// One second at 29.97 spans 29.97 frames, so 30 frames are needed.
+  pub fn frames_for_latency(&self, latency: Duration) -> u64 {
+    self.duration_to_frames(latency, Rounding::Ceil)
+  }
+
+  /// Size of `frames` frames of `bytes_per_frame` bytes, or `None` on
+  /// overflow.
+  pub fn bytes_for_frames(&self, frames: u64, bytes_per_frame: u64) -> Option<u64> {
+    frames.checked_mul(bytes_per_frame)
+  }
+
+  /// Storage needed to record `duration` of frames of `bytes_per_frame`
+  /// bytes, counting a partially covered last frame, or `None` on overflow,
+  /// including of the frame count.
+  pub fn storage_for(&self, duration: Duration, bytes_per_frame: u64) -> Option<u64> {
+    self.bytes_for_frames(
+      self.checked_duration_to_frames(duration, Rounding::Ceil)?,
+      bytes_per_frame,
+    )
+  }
+
+  /// Exact bitrate in bits per second of a stream of `bytes_per_frame` byte
+  /// frames, or `None` on overflow.
+  pub fn bandwidth_for(&self, bytes_per_frame: u64) -> Option<Ratio<u64>> {
+    let ratio = Ratio::<u32>::from(*self);
+    let bits = Ratio::new(
+      u128::from(bytes_per_frame) * 8 * u128::from(*ratio.numer()),
+      u128::from(*ratio.denom()),
+    );
+    Some(Ratio::new(
+      u64::try_from(*bits.numer()).ok()?,
+      u64::try_from(*bits.denom()).ok()?,
+    ))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn frames_for_latency() {
+    assert_eq!(
+      FrameRate::_29_97.frames_for_latency(Duration::from_secs(1)),
+      30
+    );
+    assert_eq!(
+      FrameRate::_30_00.frames_for_latency(Duration::from_secs(1)),
+      30
+    );
+    assert_eq!(
+      FrameRate::_25_00.frames_for_latency(Duration::from_millis(120)),
+      3
+    );
+    assert_eq!(
+      FrameRate::_25_00.frames_for_latency(Duration::from_millis(121)),
+      4
+    );
+    assert_eq!(FrameRate::_25_00.frames_for_latency(Duration::ZERO), 0);
+  }
+
+  #[test]
+  fn bytes_for_frames() {
+    let frame_size = 1920 * 1080 * 2;
+    assert_eq!(
+      FrameRate::_25_00.bytes_for_frames(3, frame_size),
+      Some(3 * frame_size)
+    );
+    assert_eq!(FrameRate::_25_00.bytes_for_frames(u64::MAX, 2), None);
+  }
+
+  #[test]
+  fn storage_for() {
+    let frame_size = 1920 * 1080 * 2;
+    assert_eq!(
+      FrameRate::_25_00.storage_for(Duration::from_secs(60), frame_size),
+      Some(1500 * frame_size)
+    );
+    // 1 s at 29.97 starts 30 frames.
+    assert_eq!(
+      FrameRate::_29_97.storage_for(Duration::from_secs(1), frame_size),
+      Some(30 * frame_size)
+    );
+    assert_eq!(
+      FrameRate::_120_00.storage_for(Duration::from_secs(u64::MAX / 1000), u64::MAX),
+      None
+    );
+    assert_eq!(FrameRate::_120_00.storage_for(Duration::MAX, 1), None);
+    assert_eq!(FrameRate::_120_00.storage_for(Duration::MAX, 0), None);
+    assert_eq!(FrameRate::new(0, 1).storage_for(Duration::MAX, 1), Some(0));
+  }
+
+  #[test]
+  fn bandwidth_for() {
+    // 1080p 4:2:2 10-bit frames at 59.94, about 2.49 Gb/s.
+    assert_eq!(
+      FrameRate::_59_94.bandwidth_for(5_184_000),
+      Some(Ratio::new(2_488_320_000_000, 1001))
+    );
+    assert_eq!(
+      FrameRate::_25_00.bandwidth_for(1000),
+      Some(Ratio::from_integer(200_000))
+    );
+    assert_eq!(FrameRate::_120_00.bandwidth_for(u64::MAX), None);
+  }
+}
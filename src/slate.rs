@@ -0,0 +1,239 @@
+use crate::{Breakdown, FrameRate, Ratio, Timecode};
+
+/// Interpretation of an OCR'd slate timecode at a candidate frame rate, see
+/// [`read_slate`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SlateReading {
+  pub hours: u8,
+  pub minutes: u8,
+  pub seconds: u8,
+  pub frames: u8,
+  pub drop_frame: bool,
+  pub frame_rate: FrameRate,
+  /// Characters replaced to read the text as digits, e.g. `O` read as `0`.
+  pub corrections: usize,
+}
+
+impl SlateReading {
+  /// Timecode read, `None` below 1 fps when no frame starts in the second
+  /// read.
+  pub fn timecode(&self) -> Option<Timecode> {
+    let breakdown = Breakdown {
+      hours: self.hours.into(),
+      minutes: self.minutes,
+      seconds: self.seconds,
+      frames: self.frames.into(),
+    };
+    Timecode::from_breakdown(self.frame_rate, breakdown, self.drop_frame).ok()
+  }
+}
+
+/// Digit most likely meant by a character misread by OCR.
+fn digit(character: char) -> Option<(u8, bool)> {
+  let corrected = match character {
+    '0'..='9' => return Some((character as u8 - b'0', false)),
+    'O' | 'o' | 'D' | 'Q' => 0,
+    'I' | 'l' | 'i' | '|' | '!' => 1,
+    'Z' | 'z' => 2,
+    'A' => 4,
+    'S' | 's' => 5,
+    'G' | 'b' => 6,
+    'T' => 7,
+    'B' => 8,
+    'g' | 'q' => 9,
+    _ => return None,
+  };
+  Some((corrected, true))
+}
+
+/// Reads `HH:MM:SS:FF` fields from OCR output, returning them, the number of
+/// corrected characters and whether the frames separator denotes drop-frame
+/// (`;`, `,` or `.`).
+fn fields(text: &str) -> Option<([u8; 4], usize, bool)> {
+  let mut groups: Vec<Vec<u8>> = vec![Vec::new()];
+  let mut corrections = 0;
+  let mut drop_frame = false;
+
+  for character in text.trim().chars() {
+    if let Some((value, corrected)) = digit(character) {
+      groups.last_mut()?.push(value);
+      corrections += usize::from(corrected);
+    } else if matches!(character, ':' | ';' | ',' | '.' | ' ' | '-' | '_') {
+      if groups.last().is_some_and(|group| !group.is_empty()) {
+        drop_frame = matches!(character, ';' | ',' | '.');
+        groups.push(Vec::new());
+      }
+    } else {
+      return None;
+    }
+  }
+  groups.retain(|group| !group.is_empty());
+
+  // Without separators, split eight digits in pairs.
+  if let [digits] = groups.as_slice()
+    && digits.len() == 8
+  {
+    groups = digits.chunks(2).map(<[u8]>::to_vec).collect();
+    drop_frame = false;
+  }
+
+  let [hours, minutes, seconds, frames] = groups.as_slice() else {
+    return None;
+  };
+  let value = |group: &Vec<u8>| {
+    (1..=2)
+      .contains(&group.len())
+      .then(|| group.iter().fold(0, |value, digit| value * 10 + digit))
+  };
+  Some((
+    [
+      value(hours)?,
+      value(minutes)?,
+      value(seconds)?,
+      value(frames)?,
+    ],
+    corrections,
+    drop_frame,
+  ))
+}
+
+/// Plausible interpretations of an OCR'd slate timecode at each of the
+/// `candidates` frame rates, most plausible first.
+///
+/// Common OCR confusions (`O`/`0`, `l`/`1`, `S`/`5`, ...) are corrected.
+/// Readings are dropped when a field is out of range at a rate, such as
+/// frame 24 at 24 fps, or when they name a frame skipped by drop-frame
+/// timecode. Readings whose separator agrees with the rate supporting
+/// drop-frame rank first, then candidates keep their order.
+pub fn read_slate(text: &str, candidates: &[FrameRate]) -> Vec<SlateReading> {
+  let Some(([hours, minutes, seconds, frames], corrections, separator_drop_frame)) = fields(text)
+  else {
+    return Vec::new();
+  };
+  if hours >= 24 || minutes >= 60 || seconds >= 60 {
+    return Vec::new();
+  }
+
+  let mut readings: Vec<_> = candidates
+    .iter()
+    .enumerate()
+    .filter_map(|(index, frame_rate)| {
+      let supports_drop_frame = frame_rate
+        .metadata()
+        .is_some_and(|metadata| metadata.drop_frame);
+      let drop_frame = separator_drop_frame && supports_drop_frame;
+
+      let nominal = Ratio::<u32>::from(*frame_rate).ceil().to_integer();
+      if u32::from(frames) >= nominal {
+        return None;
+      }
+      let dropped = nominal / 15;
+      if drop_frame && seconds == 0 && minutes % 10 != 0 && u32::from(frames) < dropped {
+        return None;
+      }
+
+      let reading = SlateReading {
+        hours,
+        minutes,
+        seconds,
+        frames,
+        drop_frame,
+        frame_rate: *frame_rate,
+        corrections,
+      };
+      Some((separator_drop_frame != supports_drop_frame, index, reading))
+    })
+    .collect();
+
+  readings.sort_by_key(|(mismatch, index, _)| (*mismatch, *index));
+  readings
+    .into_iter()
+    .map(|(_, _, reading)| reading)
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const CANDIDATES: [FrameRate; 4] = [
+    FrameRate::_23_97,
+    FrameRate::_24_00,
+    FrameRate::_25_00,
+    FrameRate::_29_97,
+  ];
+
+  #[test]
+  fn corrections() {
+    let readings = read_slate("O1:2S:l3:1O", &CANDIDATES);
+    assert_eq!(readings.len(), 4);
+    assert_eq!(
+      readings[0],
+      SlateReading {
+        hours: 1,
+        minutes: 25,
+        seconds: 13,
+        frames: 10,
+        drop_frame: false,
+        frame_rate: FrameRate::_23_97,
+        corrections: 4,
+      }
+    );
+    // 29.97 is ranked last, a non drop-frame separator hinting otherwise.
+    assert_eq!(readings[3].frame_rate, FrameRate::_29_97);
+  }
+
+  #[test]
+  fn frames_out_of_range() {
+    let rates: Vec<_> = read_slate("10 00 00 27", &CANDIDATES)
+      .iter()
+      .map(|reading| reading.frame_rate)
+      .collect();
+    assert_eq!(rates, [FrameRate::_29_97]);
+    assert!(read_slate("10:00:00:30", &CANDIDATES).is_empty());
+    assert!(read_slate("25:00:00:00", &CANDIDATES).is_empty());
+  }
+
+  #[test]
+  fn drop_frame() {
+    let readings = read_slate("01:23:45;12", &CANDIDATES);
+    assert_eq!(readings[0].frame_rate, FrameRate::_29_97);
+    assert!(readings[0].drop_frame);
+    assert!(readings[1..].iter().all(|reading| !reading.drop_frame));
+
+    // Frames 0 and 1 do not exist at the start of minute 1 in drop-frame.
+    let rates: Vec<_> = read_slate("00:01:00;01", &CANDIDATES)
+      .iter()
+      .map(|reading| reading.frame_rate)
+      .collect();
+    assert_eq!(
+      rates,
+      [FrameRate::_23_97, FrameRate::_24_00, FrameRate::_25_00]
+    );
+  }
+
+  #[test]
+  fn unseparated() {
+    let readings = read_slate("01020304", &[FrameRate::_25_00]);
+    assert_eq!(
+      (
+        readings[0].hours,
+        readings[0].minutes,
+        readings[0].seconds,
+        readings[0].frames
+      ),
+      (1, 2, 3, 4)
+    );
+    assert!(read_slate("0102030", &CANDIDATES).is_empty());
+    assert!(read_slate("01:02:03:04 take 2", &CANDIDATES).is_empty());
+  }
+
+  #[test]
+  fn timecode() {
+    let readings = read_slate("00:01:00;02", &CANDIDATES);
+    let timecode = readings[0].timecode().unwrap();
+    assert!(timecode.is_drop_frame());
+    assert_eq!(timecode.to_frames(), 1800);
+    assert_eq!(readings[1].timecode().unwrap().to_string(), "00:01:00:02");
+  }
+}
@@ -0,0 +1,66 @@
+use crate::{FrameRate, Rounding};
+use std::time::{Duration, Instant};
+
+impl FrameRate {
+  /// Instant frame `frame_index` is due, counting from frame 0 at `start`,
+  /// rounded up to the nanosecond so it is never early.
+  pub fn frame_deadline(&self, start: Instant, frame_index: u64) -> Instant {
+    start + self.frames_to_duration(frame_index, Rounding::Ceil)
+  }
+
+  /// Blocks the thread until frame `frame_index` is due, see
+  /// [`FrameRate::frame_deadline`]. Sleeps until `spin_threshold` before the
+  /// deadline, then spins to make up for the coarse OS timer.
+  ///
+  /// Returns how late the deadline was met, zero unless the deadline had
+  /// already passed or the thread was preempted.
+  pub fn sleep_until_next_frame(
+    &self,
+    start: Instant,
+    frame_index: u64,
+    spin_threshold: Duration,
+  ) -> Duration {
+    let deadline = self.frame_deadline(start, frame_index);
+    let sleep = deadline
+      .saturating_duration_since(Instant::now())
+      .saturating_sub(spin_threshold);
+    if !sleep.is_zero() {
+      std::thread::sleep(sleep);
+    }
+    loop {
+      let now = Instant::now();
+      if now >= deadline {
+        return now - deadline;
+      }
+      std::hint::spin_loop();
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn frame_deadline() {
+    let start = Instant::now();
+    assert_eq!(
+      FrameRate::_29_97.frame_deadline(start, 1) - start,
+      Duration::from_nanos(33_366_667)
+    );
+    assert_eq!(FrameRate::_25_00.frame_deadline(start, 0), start);
+  }
+
+  #[test]
+  fn sleep_until_next_frame() {
+    let frame_rate = FrameRate::new(200, 1);
+    let start = Instant::now();
+    for frame_index in 1..=3 {
+      frame_rate.sleep_until_next_frame(start, frame_index, Duration::from_micros(500));
+      assert!(start.elapsed() >= Duration::from_millis(5 * frame_index));
+    }
+
+    let late = frame_rate.sleep_until_next_frame(start, 0, Duration::ZERO);
+    assert!(late >= Duration::from_millis(15));
+  }
+}
@@ -0,0 +1,200 @@
+use crate::{FrameRate, PRESETS};
+
+/// A frame rate accepted by a [`SnapProfile`], with the maximum distance (in
+/// frames per second) at which a floating point value snaps to it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SnapCandidate {
+  pub frame_rate: FrameRate,
+  pub tolerance: f64,
+}
+
+/// Tolerances used to map floating point frame rates to known frame rates.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SnapProfile<'a> {
+  pub candidates: &'a [SnapCandidate],
+}
+
+impl SnapProfile<'static> {
+  /// Standard rates, with a tolerance covering values rounded to two decimals
+  /// (e.g. "23.98" or "59.94").
+  pub const DEFAULT: Self = Self {
+    candidates: &[
+      SnapCandidate::new(FrameRate::_23_97, 0.005),
+      SnapCandidate::new(FrameRate::_24_00, 0.005),
+      SnapCandidate::new(FrameRate::_24_97, 0.005),
+      SnapCandidate::new(FrameRate::_25_00, 0.005),
+      SnapCandidate::new(FrameRate::_29_97, 0.005),
+      SnapCandidate::new(FrameRate::_30_00, 0.005),
+      SnapCandidate::new(FrameRate::_50_00, 0.005),
+      SnapCandidate::new(FrameRate::_59_94, 0.005),
+      SnapCandidate::new(FrameRate::_60_00, 0.005),
+      SnapCandidate::new(FrameRate::_120_00, 0.005),
+    ],
+  };
+
+  /// Tuned for the values written by NLEs (DaVinci Resolve `video_fps`, Avid
+  /// ALE `FPS`, …).
+  ///
+  /// | Rate   | Tolerance | Accepted spellings                |
+  /// |--------|-----------|-----------------------------------|
+  /// | 23.976 | 0.005     | `23.976024`, `23.976`, `23.98`    |
+  /// | 24.975 | 0.001     | `24.975025`, `24.975`             |
+  /// | 29.97  | 0.0005    | `29.970030`, `29.97`              |
+  /// | 59.94  | 0.0005    | `59.940060`, `59.94`              |
+  /// | others | 0.0001    | the integer value, e.g. `25.000`  |
+  ///
+  /// Integer rates get a very small tolerance so that a drifting value (e.g.
+  /// `24.0012` measured from a capture) is not mistaken for an exact one.
+  pub const NLE: Self = Self {
+    candidates: &[
+      SnapCandidate::new(FrameRate::_23_97, 0.005),
+      SnapCandidate::new(FrameRate::_24_00, 0.0001),
+      SnapCandidate::new(FrameRate::_24_97, 0.001),
+      SnapCandidate::new(FrameRate::_25_00, 0.0001),
+      SnapCandidate::new(FrameRate::_29_97, 0.0005),
+      SnapCandidate::new(FrameRate::_30_00, 0.0001),
+      SnapCandidate::new(FrameRate::_50_00, 0.0001),
+      SnapCandidate::new(FrameRate::_59_94, 0.0005),
+      SnapCandidate::new(FrameRate::_60_00, 0.0001),
+      SnapCandidate::new(FrameRate::_120_00, 0.0001),
+    ],
+  };
+}
+
+impl Default for SnapProfile<'static> {
+  fn default() -> Self {
+    Self::DEFAULT
+  }
+}
+
+impl SnapCandidate {
+  pub const fn new(frame_rate: FrameRate, tolerance: f64) -> Self {
+    Self {
+      frame_rate,
+      tolerance,
+    }
+  }
+}
+
+impl FrameRate {
+  /// Closest candidate of `profile` within its tolerance of `fps`, or else
+  /// closest preset of [`PRESETS`].
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip(profile), ret)
+  )]
+  pub fn snap(fps: f64, profile: &SnapProfile) -> Option<Self> {
+    profile
+      .candidates
+      .iter()
+      .map(|candidate| {
+        let distance = (f64::from(&candidate.frame_rate) - fps).abs();
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+          candidate = %candidate.frame_rate,
+          distance,
+          tolerance = candidate.tolerance,
+          "snap candidate"
+        );
+        (candidate, distance)
+      })
+      .filter(|(candidate, distance)| *distance <= candidate.tolerance)
+      .min_by(|(_, a), (_, b)| a.total_cmp(b))
+      .map(|(candidate, _)| candidate.frame_rate)
+      .or_else(|| PRESETS.snap(fps))
+  }
+
+  /// Snap `fps` with `profile`, falling back to a custom rate with a
+  /// millisecond precision denominator.
+  ///
+  /// Returns `None` for values that are not finite, not strictly positive, or
+  /// too large to be represented.
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "debug", skip(profile), ret)
+  )]
+  pub fn from_f64(fps: f64, profile: &SnapProfile) -> Option<Self> {
+    if !fps.is_finite() || fps <= 0.0 {
+      return None;
+    }
+
+    if let Some(frame_rate) = Self::snap(fps, profile) {
+      return Some(frame_rate);
+    }
+
+    let num = (fps * 1000.0).round();
+    (num >= 1.0 && num <= f64::from(u32::MAX)).then(|| Self::new(num as u32, 1000))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn snap() {
+    let profile = SnapProfile::DEFAULT;
+    assert_eq!(FrameRate::snap(23.976, &profile), Some(FrameRate::_23_97));
+    assert_eq!(FrameRate::snap(23.98, &profile), Some(FrameRate::_23_97));
+    assert_eq!(FrameRate::snap(24.0, &profile), Some(FrameRate::_24_00));
+    assert_eq!(FrameRate::snap(29.97, &profile), Some(FrameRate::_29_97));
+    assert_eq!(FrameRate::snap(59.94, &profile), Some(FrameRate::_59_94));
+    assert_eq!(FrameRate::snap(59.97, &profile), None);
+    assert_eq!(FrameRate::snap(12.0, &profile), None);
+    assert_eq!(FrameRate::snap(f64::NAN, &profile), None);
+
+    let custom = [SnapCandidate::new(FrameRate::_25_00, 1.0)];
+    let profile = SnapProfile {
+      candidates: &custom,
+    };
+    assert_eq!(FrameRate::snap(24.2, &profile), Some(FrameRate::_25_00));
+  }
+
+  #[test]
+  fn nle_profile() {
+    let profile = SnapProfile::NLE;
+    for (fps, frame_rate) in [
+      (23.976024, FrameRate::_23_97),
+      (23.976, FrameRate::_23_97),
+      (23.98, FrameRate::_23_97),
+      (24.0, FrameRate::_24_00),
+      (24.975025, FrameRate::_24_97),
+      (25.0, FrameRate::_25_00),
+      (29.97003, FrameRate::_29_97),
+      (29.97, FrameRate::_29_97),
+      (59.94006, FrameRate::_59_94),
+      (59.94, FrameRate::_59_94),
+      (60.0, FrameRate::_60_00),
+    ] {
+      assert_eq!(FrameRate::snap(fps, &profile), Some(frame_rate), "{fps}");
+    }
+
+    assert_eq!(FrameRate::snap(24.0012, &profile), None);
+    assert_eq!(FrameRate::snap(29.98, &profile), None);
+    assert_eq!(
+      FrameRate::snap(24.0012, &SnapProfile::DEFAULT),
+      Some(FrameRate::_24_00)
+    );
+  }
+
+  #[test]
+  fn from_f64() {
+    let profile = SnapProfile::default();
+    assert_eq!(
+      FrameRate::from_f64(29.970_029_97, &profile),
+      Some(FrameRate::_29_97)
+    );
+    assert_eq!(
+      FrameRate::from_f64(12.5, &profile),
+      Some(FrameRate::new(25, 2))
+    );
+    assert_eq!(
+      FrameRate::from_f64(16.0, &profile),
+      Some(FrameRate::new(16, 1))
+    );
+    assert_eq!(FrameRate::from_f64(0.0, &profile), None);
+    assert_eq!(FrameRate::from_f64(-25.0, &profile), None);
+    assert_eq!(FrameRate::from_f64(f64::INFINITY, &profile), None);
+    assert_eq!(FrameRate::from_f64(1e12, &profile), None);
+  }
+}
@@ -0,0 +1,328 @@
+//! SMPTE ST 2110-21 sender pacing and ST 2110-20 packet estimates.
+
+use crate::{FrameRate, Ratio};
+
+/// Line structure of a progressive video format and its packet count.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VideoFormat {
+  pub active_lines: u32,
+  pub total_lines: u32,
+  /// Packets carrying one frame, `N_PACKETS`.
+  pub packets_per_frame: u32,
+}
+
+impl VideoFormat {
+  /// 1080p: 1080 active lines out of 1125.
+  pub const fn hd_1080p(packets_per_frame: u32) -> Self {
+    Self {
+      active_lines: 1080,
+      total_lines: 1125,
+      packets_per_frame,
+    }
+  }
+
+  /// 720p: 720 active lines out of 750.
+  pub const fn hd_720p(packets_per_frame: u32) -> Self {
+    Self {
+      active_lines: 720,
+      total_lines: 750,
+      packets_per_frame,
+    }
+  }
+}
+
+/// Distribution of packets over the frame period.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Pacing {
+  /// Packets only during active lines, leaving the vertical blanking empty.
+  Gapped,
+  /// Packets spread evenly over the whole frame period.
+  Linear,
+}
+
+/// Sender type, bounding burstiness.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SenderType {
+  /// Type N, for hardware senders.
+  Narrow,
+  /// Type W, for software senders.
+  Wide,
+}
+
+/// ST 2110-21 timing model parameters of a sender, times in seconds.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SenderTiming {
+  /// `T_FRAME`, the frame period.
+  pub t_frame: Ratio<u64>,
+  /// `TRS`, the time between two packets.
+  pub trs: Ratio<u64>,
+  /// `TRO_DEFAULT`, the offset of the first packet from the frame start.
+  pub tro_default: Ratio<u64>,
+  /// `CMAX`, the network compatibility bucket size in packets.
+  pub c_max: u64,
+  /// `VRX_FULL`, the receiver buffer size in packets.
+  pub vrx_full: u64,
+}
+
+/// Timing parameters of a sender of `format` at `frame_rate`.
+///
+/// `TRO_DEFAULT` generalizes the 43/1125 and 28/750 offsets of 1080 and 720
+/// line formats to the vertical blanking minus two lines.
+///
+/// # Panics
+///
+/// Panics if `format` has no packets or no active lines, or more active lines
+/// than total lines.
+pub fn sender_timing(
+  frame_rate: FrameRate,
+  format: &VideoFormat,
+  pacing: Pacing,
+  sender_type: SenderType,
+) -> SenderTiming {
+  assert!(
+    format.packets_per_frame != 0
+      && format.active_lines != 0
+      && format.active_lines <= format.total_lines,
+    "invalid video format"
+  );
+  let ratio = Ratio::<u32>::from(frame_rate);
+  let (num, den) = (u64::from(*ratio.numer()), u64::from(*ratio.denom()));
+  let packets = u64::from(format.packets_per_frame);
+  let total_lines = u64::from(format.total_lines);
+
+  let t_frame = Ratio::new(den, num);
+  let r_active = match pacing {
+    Pacing::Gapped => Ratio::new(u64::from(format.active_lines), total_lines),
+    Pacing::Linear => Ratio::from_integer(1),
+  };
+  let blanking = total_lines - u64::from(format.active_lines);
+
+  // N_PACKETS / (scale × R_ACTIVE × T_FRAME), rounded down.
+  let per_second = |scale: u64, r_active: Ratio<u64>| {
+    let numerator = u128::from(packets) * u128::from(*r_active.denom()) * u128::from(num);
+    let denominator = u128::from(scale) * u128::from(*r_active.numer()) * u128::from(den);
+    (numerator / denominator) as u64
+  };
+  let (c_max, vrx_full) = match sender_type {
+    SenderType::Narrow => (
+      per_second(43_200, r_active).max(4),
+      per_second(27_000, Ratio::from_integer(1)).max(8),
+    ),
+    SenderType::Wide => (
+      per_second(21_600, r_active).max(16),
+      per_second(300, Ratio::from_integer(1)).max(720),
+    ),
+  };
+
+  SenderTiming {
+    t_frame,
+    trs: t_frame * r_active / Ratio::from_integer(packets),
+    tro_default: t_frame * Ratio::new(blanking.saturating_sub(2), total_lines),
+    c_max,
+    vrx_full,
+  }
+}
+
+/// Color sampling of an ST 2110-20 raster.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Sampling {
+  /// 4:2:2 Y'Cb'Cr'.
+  Ycbcr422,
+  /// 4:4:4 Y'Cb'Cr' or RGB.
+  Ycbcr444,
+}
+
+/// Uncompressed progressive raster carried by ST 2110-20.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct RasterFormat {
+  pub width: u32,
+  pub height: u32,
+  pub sampling: Sampling,
+  pub bit_depth: u8,
+}
+
+impl RasterFormat {
+  /// Size in bytes and in pixels of the pixel group (`pgroup`), the unit a
+  /// packet payload is made of. `None` for bit depths other than 8, 10, 12
+  /// and 16.
+  pub fn pgroup(&self) -> Option<(u32, u32)> {
+    match (self.sampling, self.bit_depth) {
+      (Sampling::Ycbcr422, 8) => Some((4, 2)),
+      (Sampling::Ycbcr422, 10) => Some((5, 2)),
+      (Sampling::Ycbcr422, 12) => Some((6, 2)),
+      (Sampling::Ycbcr422, 16) => Some((8, 2)),
+      (Sampling::Ycbcr444, 8) => Some((3, 1)),
+      (Sampling::Ycbcr444, 10) => Some((15, 4)),
+      (Sampling::Ycbcr444, 12) => Some((9, 2)),
+      (Sampling::Ycbcr444, 16) => Some((6, 1)),
+      _ => None,
+    }
+  }
+
+  /// Bytes of active video in a frame, each line padded to whole pgroups.
+  pub fn bytes_per_frame(&self) -> Option<u64> {
+    let (bytes, pixels) = self.pgroup()?;
+    let line = u64::from(self.width.div_ceil(pixels)) * u64::from(bytes);
+    Some(line * u64::from(self.height))
+  }
+}
+
+/// Maximum UDP datagram size of ST 2110-10.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum UdpSize {
+  /// 1460 bytes, the standard UDP size limit.
+  Standard,
+  /// 8960 bytes, the extended UDP size limit of jumbo frame networks.
+  Extended,
+}
+
+impl UdpSize {
+  pub const fn bytes(self) -> u32 {
+    match self {
+      Self::Standard => 1460,
+      Self::Extended => 8960,
+    }
+  }
+}
+
+/// Packet counts of an ST 2110-20 stream.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PacketEstimate {
+  /// Bytes of video carried by a full packet.
+  pub payload_bytes: u32,
+  pub packets_per_frame: u64,
+  /// Exact packet rate, in packets per second.
+  pub packets_per_second: Ratio<u64>,
+}
+
+/// Packets needed to send `format` at `frame_rate` in datagrams of at most
+/// `udp_size` bytes, with one sample row data header per packet and packets
+/// spanning lines (general packing mode).
+///
+/// `None` for unsupported bit depths, see [`RasterFormat::pgroup`].
+pub fn estimate_packets(
+  frame_rate: FrameRate,
+  format: &RasterFormat,
+  udp_size: UdpSize,
+) -> Option<PacketEstimate> {
+  // RTP header, extended sequence number and one sample row data header.
+  const HEADERS: u32 = 12 + 2 + 6;
+  let (pgroup_bytes, _) = format.pgroup()?;
+  let payload_bytes = (udp_size.bytes() - HEADERS) / pgroup_bytes * pgroup_bytes;
+  let packets_per_frame = format.bytes_per_frame()?.div_ceil(u64::from(payload_bytes));
+
+  let ratio = Ratio::<u32>::from(frame_rate);
+  let per_second = Ratio::new(
+    u128::from(packets_per_frame) * u128::from(*ratio.numer()),
+    u128::from(*ratio.denom()),
+  );
+  Some(PacketEstimate {
+    payload_bytes,
+    packets_per_frame,
+    packets_per_second: Ratio::new(
+      u64::try_from(*per_second.numer()).ok()?,
+      u64::try_from(*per_second.denom()).ok()?,
+    ),
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn gapped_narrow_1080p() {
+    // 1080p59.94 4:2:2 10-bit, 4320 packets per frame.
+    let timing = sender_timing(
+      FrameRate::_59_94,
+      &VideoFormat::hd_1080p(4320),
+      Pacing::Gapped,
+      SenderType::Narrow,
+    );
+
+    assert_eq!(timing.t_frame, Ratio::new(1001, 60000));
+    assert_eq!(
+      timing.trs,
+      Ratio::new(1001, 60000) * Ratio::new(1080, 1125) / Ratio::from_integer(4320)
+    );
+    assert_eq!(
+      timing.tro_default,
+      Ratio::new(1001, 60000) * Ratio::new(43, 1125)
+    );
+    // 4320 / (43200 × 0.96 × 16.683 ms) = 6.24
+    assert_eq!(timing.c_max, 6);
+    // 4320 / (27000 × 16.683 ms) = 9.59
+    assert_eq!(timing.vrx_full, 9);
+  }
+
+  #[test]
+  fn linear_wide_720p() {
+    let timing = sender_timing(
+      FrameRate::_50_00,
+      &VideoFormat::hd_720p(1920),
+      Pacing::Linear,
+      SenderType::Wide,
+    );
+
+    assert_eq!(timing.trs, Ratio::new(1, 50 * 1920));
+    assert_eq!(timing.tro_default, Ratio::new(1, 50) * Ratio::new(28, 750));
+    assert_eq!(timing.c_max, 16);
+    assert_eq!(timing.vrx_full, 720);
+  }
+
+  #[test]
+  #[should_panic(expected = "invalid video format")]
+  fn invalid_format() {
+    sender_timing(
+      FrameRate::_25_00,
+      &VideoFormat::hd_1080p(0),
+      Pacing::Gapped,
+      SenderType::Narrow,
+    );
+  }
+
+  #[test]
+  fn packet_estimates() {
+    let format = RasterFormat {
+      width: 1920,
+      height: 1080,
+      sampling: Sampling::Ycbcr422,
+      bit_depth: 10,
+    };
+    assert_eq!(format.bytes_per_frame(), Some(5_184_000));
+
+    let estimate = estimate_packets(FrameRate::_59_94, &format, UdpSize::Standard).unwrap();
+    assert_eq!(estimate.payload_bytes, 1440);
+    assert_eq!(estimate.packets_per_frame, 3600);
+    assert_eq!(estimate.packets_per_second, Ratio::new(3600 * 60000, 1001));
+
+    let estimate = estimate_packets(FrameRate::_50_00, &format, UdpSize::Extended).unwrap();
+    // 8940 bytes rounded down to whole 5 byte pgroups.
+    assert_eq!(estimate.payload_bytes, 8940);
+    assert_eq!(estimate.packets_per_frame, 580);
+    assert_eq!(estimate.packets_per_second, Ratio::from_integer(29_000));
+  }
+
+  #[test]
+  fn pgroup_padding() {
+    let format = RasterFormat {
+      width: 1366,
+      height: 768,
+      sampling: Sampling::Ycbcr444,
+      bit_depth: 10,
+    };
+    // 342 pgroups of 4 pixels per line.
+    assert_eq!(format.bytes_per_frame(), Some(342 * 15 * 768));
+    assert_eq!(
+      estimate_packets(
+        FrameRate::_25_00,
+        &RasterFormat {
+          bit_depth: 9,
+          ..format
+        },
+        UdpSize::Standard
+      ),
+      None
+    );
+  }
+}
@@ -0,0 +1,117 @@
+use crate::parse::parse_display;
+use crate::{FrameRate, FrameRateKind, Ratio};
+use serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+/// [`FrameRate`] serialized as a string, e.g. `"29.97"`, for schemas
+/// expecting a plain string instead of the `{num, den}` object.
+///
+/// Standard rates are serialized as their `Display` string and other rates
+/// as `"num/den"`, so that the output does not depend on registered
+/// [`Preset`](crate::Preset) names.
+///
+/// Deserialization also accepts `"num/den"`, decimal values snapped to the
+/// standard rates, and variant names such as `"_29_97"`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FrameRateString(pub FrameRate);
+
+impl From<FrameRate> for FrameRateString {
+  fn from(frame_rate: FrameRate) -> Self {
+    Self(frame_rate)
+  }
+}
+
+impl From<FrameRateString> for FrameRate {
+  fn from(frame_rate: FrameRateString) -> Self {
+    frame_rate.0
+  }
+}
+
+impl utoipa::ToSchema for FrameRateString {
+  fn name() -> std::borrow::Cow<'static, str> {
+    std::borrow::Cow::Borrowed("FrameRateString")
+  }
+}
+
+impl utoipa::PartialSchema for FrameRateString {
+  fn schema() -> utoipa::openapi::RefOr<utoipa::openapi::schema::Schema> {
+    use utoipa::openapi::{ObjectBuilder, Type};
+
+    ObjectBuilder::new()
+      .schema_type(Type::String)
+      .examples(["29.97", "25", "30000/1001"])
+      .into()
+  }
+}
+
+impl Serialize for FrameRateString {
+  fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    if self.0.kind() != FrameRateKind::Custom {
+      return serializer.collect_str(&self.0);
+    }
+    let ratio = Ratio::<u32>::from(self.0);
+    serializer.collect_str(&format_args!("{}/{}", ratio.numer(), ratio.denom()))
+  }
+}
+
+impl<'de> Deserialize<'de> for FrameRateString {
+  fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    let value = String::deserialize(deserializer)?;
+    parse_display(&value)
+      .map(Self)
+      .ok_or_else(|| de::Error::custom(format!("invalid frame rate {value:?}")))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::json;
+
+  #[test]
+  fn serialize() {
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Dto {
+      frame_rate: FrameRateString,
+    }
+
+    assert_eq!(
+      serde_json::to_value(Dto {
+        frame_rate: FrameRate::_29_97.into()
+      })
+      .unwrap(),
+      json!({"frameRate": "29.97"})
+    );
+    assert_eq!(
+      serde_json::to_value(FrameRateString(FrameRate::new(48, 1))).unwrap(),
+      json!("48/1")
+    );
+    assert_eq!(
+      serde_json::to_value(FrameRateString(FrameRate::new(1, 10))).unwrap(),
+      json!("1/10")
+    );
+    assert_eq!(
+      serde_json::to_value(FrameRateString(FrameRate::new(0, 1))).unwrap(),
+      json!("0/1")
+    );
+  }
+
+  #[test]
+  fn deserialize() {
+    for (value, frame_rate) in [
+      ("29.97", FrameRate::_29_97),
+      ("30000/1001", FrameRate::_29_97),
+      ("25", FrameRate::_25_00),
+      ("1 frame / 10 s", FrameRate::new(1, 10)),
+      ("_29_97", FrameRate::_29_97),
+      ("FrameRate::_24_00", FrameRate::_24_00),
+    ] {
+      assert_eq!(
+        serde_json::from_value::<FrameRateString>(json!(value)).unwrap(),
+        FrameRateString(frame_rate)
+      );
+    }
+    assert!(serde_json::from_value::<FrameRateString>(json!("25/0")).is_err());
+    assert!(serde_json::from_value::<FrameRateString>(json!(25)).is_err());
+  }
+}
@@ -0,0 +1,164 @@
+use crate::{FrameRate, Ratio, Rounding};
+
+/// A tick count in a timebase of `timebase` ticks per second.
+///
+/// Backed by a `u128` so that long timelines in fine timebases (days at
+/// nanosecond resolution) can be converted to frames without overflowing.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Ticks {
+  value: u128,
+  timebase: u64,
+}
+
+impl Ticks {
+  pub const NANOSECONDS: u64 = 1_000_000_000;
+
+  /// # Panics
+  ///
+  /// Panics if `timebase` is zero.
+  pub fn new(value: u128, timebase: u64) -> Self {
+    assert!(timebase != 0, "ticks timebase must not be zero");
+    Self { value, timebase }
+  }
+
+  pub fn value(&self) -> u128 {
+    self.value
+  }
+
+  pub fn timebase(&self) -> u64 {
+    self.timebase
+  }
+
+  /// Ticks at the start of `frame` at `frame_rate`, or `None` on overflow or
+  /// at 0 fps.
+  pub fn checked_from_frames(
+    frame: u128,
+    frame_rate: FrameRate,
+    timebase: u64,
+    rounding: Rounding,
+  ) -> Option<Self> {
+    let ratio = Ratio::<u32>::from(frame_rate);
+    if *ratio.numer() == 0 {
+      return None;
+    }
+    let numerator = frame
+      .checked_mul(u128::from(*ratio.denom()))?
+      .checked_mul(u128::from(timebase))?;
+    Some(Self::new(
+      rounding.div(numerator, u128::from(*ratio.numer())),
+      timebase,
+    ))
+  }
+
+  /// Index of the frame at `frame_rate` starting at these ticks, or `None` on
+  /// overflow.
+  pub fn checked_to_frames(&self, frame_rate: FrameRate, rounding: Rounding) -> Option<u128> {
+    let ratio = Ratio::<u32>::from(frame_rate);
+    let numerator = self.value.checked_mul(u128::from(*ratio.numer()))?;
+    let denominator = u128::from(self.timebase) * u128::from(*ratio.denom());
+    Some(rounding.div(numerator, denominator))
+  }
+
+  /// Same as [`Ticks::checked_to_frames`], clamping to `u128::MAX` on overflow.
+  pub fn saturating_to_frames(&self, frame_rate: FrameRate, rounding: Rounding) -> u128 {
+    self
+      .checked_to_frames(frame_rate, rounding)
+      .unwrap_or(u128::MAX)
+  }
+
+  /// # Panics
+  ///
+  /// Panics on overflow.
+  pub fn to_frames(&self, frame_rate: FrameRate, rounding: Rounding) -> u128 {
+    self
+      .checked_to_frames(frame_rate, rounding)
+      .expect("overflow converting ticks to frames")
+  }
+
+  pub fn checked_add(self, ticks: u128) -> Option<Self> {
+    Some(Self::new(self.value.checked_add(ticks)?, self.timebase))
+  }
+
+  pub fn saturating_add(self, ticks: u128) -> Self {
+    Self::new(self.value.saturating_add(ticks), self.timebase)
+  }
+
+  pub fn checked_sub(self, ticks: u128) -> Option<Self> {
+    Some(Self::new(self.value.checked_sub(ticks)?, self.timebase))
+  }
+
+  pub fn saturating_sub(self, ticks: u128) -> Self {
+    Self::new(self.value.saturating_sub(ticks), self.timebase)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn multi_day_timeline() {
+    let three_days = Ticks::new(3 * 24 * 3600 * 1_000_000_000, Ticks::NANOSECONDS);
+    assert_eq!(
+      three_days.to_frames(FrameRate::_59_94, Rounding::Floor),
+      15_536_463
+    );
+    assert_eq!(
+      three_days.to_frames(FrameRate::_59_94, Rounding::Ceil),
+      15_536_464
+    );
+    assert_eq!(
+      three_days.to_frames(FrameRate::_25_00, Rounding::Floor),
+      6_480_000
+    );
+  }
+
+  #[test]
+  fn from_frames() {
+    assert_eq!(
+      Ticks::checked_from_frames(30, FrameRate::_29_97, 90_000, Rounding::Floor),
+      Some(Ticks::new(90_090, 90_000))
+    );
+    assert_eq!(
+      Ticks::checked_from_frames(1, FrameRate::_23_97, Ticks::NANOSECONDS, Rounding::Floor),
+      Some(Ticks::new(41_708_333, Ticks::NANOSECONDS))
+    );
+    assert_eq!(
+      Ticks::checked_from_frames(1, FrameRate::_23_97, Ticks::NANOSECONDS, Rounding::Ceil),
+      Some(Ticks::new(41_708_334, Ticks::NANOSECONDS))
+    );
+    assert_eq!(
+      Ticks::checked_from_frames(u128::MAX, FrameRate::_29_97, 90_000, Rounding::Floor),
+      None
+    );
+    assert_eq!(
+      Ticks::checked_from_frames(1, FrameRate::new(0, 1), 90_000, Rounding::Nearest),
+      None
+    );
+  }
+
+  #[test]
+  fn overflow() {
+    let ticks = Ticks::new(u128::MAX, 1);
+    assert_eq!(
+      ticks.checked_to_frames(FrameRate::_25_00, Rounding::Floor),
+      None
+    );
+    assert_eq!(
+      ticks.saturating_to_frames(FrameRate::_25_00, Rounding::Floor),
+      u128::MAX
+    );
+    assert_eq!(
+      ticks.checked_to_frames(FrameRate::new(1, 1), Rounding::Floor),
+      Some(u128::MAX)
+    );
+    assert_eq!(
+      ticks.checked_to_frames(FrameRate::new(1, 2), Rounding::Nearest),
+      Some(u128::MAX / 2 + 1)
+    );
+    assert_eq!(ticks.checked_add(1), None);
+    assert_eq!(ticks.saturating_add(1), ticks);
+    assert_eq!(Ticks::new(0, 1).checked_sub(1), None);
+    assert_eq!(Ticks::new(0, 1).saturating_sub(1), Ticks::new(0, 1));
+  }
+}
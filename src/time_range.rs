@@ -0,0 +1,236 @@
+use crate::{ClipRange, Error, FrameRate, RangeRounding, Ratio, Rounding, Timecode};
+use std::time::Duration;
+
+/// A range of exact times in seconds, independent of any frame rate.
+///
+/// The start is inclusive and the end is exclusive. Unlike [`ClipRange`],
+/// ranges built from different units or frame rates can be combined.
+///
+/// ```
+/// use frame_rate::{FrameRate, TimeRange};
+/// use std::time::Duration;
+///
+/// let clip = TimeRange::from_frames(FrameRate::_29_97, 0, 300).unwrap();
+/// let request = TimeRange::from_durations(Duration::from_secs(5), Duration::from_secs(20)).unwrap();
+/// let clipped = clip.intersection(&request).unwrap();
+/// assert_eq!(clipped.start(), 5.into());
+/// assert_eq!(clipped.end(), (1001, 100).into());
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct TimeRange {
+  start: Ratio<u64>,
+  end: Ratio<u64>,
+}
+
+impl TimeRange {
+  pub fn new(start: Ratio<u64>, end: Ratio<u64>) -> Result<Self, Error> {
+    if start >= end {
+      return Err(Error::InvalidTimeRange { start, end });
+    }
+    Ok(Self { start, end })
+  }
+
+  /// Range covering frames `start..end` at `frame_rate`, failing when a
+  /// boundary has no time in a `Ratio<u64>`, see
+  /// [`FrameRate::checked_time_after`].
+  pub fn from_frames(frame_rate: FrameRate, start: u64, end: u64) -> Result<Self, Error> {
+    let time_after = |frames| {
+      frame_rate
+        .checked_time_after(frames)
+        .ok_or(Error::TimeOutOfRange { frame_rate, frames })
+    };
+    Self::new(time_after(start)?, time_after(end)?)
+  }
+
+  pub fn from_durations(start: Duration, end: Duration) -> Result<Self, Error> {
+    Self::new(seconds(start), seconds(end))
+  }
+
+  /// Range from the `start` timecode inclusive to the `end` timecode
+  /// exclusive, `;` separated timecodes being drop-frame.
+  pub fn from_timecodes(frame_rate: FrameRate, start: &str, end: &str) -> Result<Self, Error> {
+    Self::from_frames(
+      frame_rate,
+      Timecode::parse(frame_rate, start)?.to_frames(),
+      Timecode::parse(frame_rate, end)?.to_frames(),
+    )
+  }
+
+  /// Start time in seconds.
+  pub fn start(&self) -> Ratio<u64> {
+    self.start
+  }
+
+  /// End time in seconds.
+  pub fn end(&self) -> Ratio<u64> {
+    self.end
+  }
+
+  /// Length in seconds.
+  pub fn duration(&self) -> Ratio<u64> {
+    self.end - self.start
+  }
+
+  pub fn contains(&self, time: Ratio<u64>) -> bool {
+    (self.start..self.end).contains(&time)
+  }
+
+  pub fn overlaps(&self, other: &Self) -> bool {
+    self.start < other.end && other.start < self.end
+  }
+
+  pub fn intersection(&self, other: &Self) -> Option<Self> {
+    self.overlaps(other).then(|| Self {
+      start: self.start.max(other.start),
+      end: self.end.min(other.end),
+    })
+  }
+
+  /// Smallest range covering both ranges, if they overlap or are adjacent.
+  pub fn union(&self, other: &Self) -> Option<Self> {
+    (self.start <= other.end && other.start <= self.end).then(|| Self {
+      start: self.start.min(other.start),
+      end: self.end.max(other.end),
+    })
+  }
+
+  /// Frames of `frame_rate` matching the range.
+  ///
+  /// Fails when the frame range would be empty, which can happen with
+  /// [`RangeRounding::Inward`] or [`RangeRounding::Nearest`].
+  pub fn to_clip_range(
+    &self,
+    frame_rate: FrameRate,
+    rounding: RangeRounding,
+  ) -> Result<ClipRange, Error> {
+    let (start_rounding, end_rounding) = rounding.boundaries();
+    let ratio = Ratio::<u32>::from(frame_rate);
+    let frames = |time: Ratio<u64>, rounding: Rounding| {
+      let frames = rounding.div(
+        u128::from(*time.numer()) * u128::from(*ratio.numer()),
+        u128::from(*time.denom()) * u128::from(*ratio.denom()),
+      );
+      u64::try_from(frames).unwrap_or(u64::MAX)
+    };
+    ClipRange::new(
+      frame_rate,
+      frames(self.start, start_rounding),
+      frames(self.end, end_rounding),
+    )
+  }
+}
+
+impl TryFrom<ClipRange> for TimeRange {
+  type Error = Error;
+
+  fn try_from(range: ClipRange) -> Result<Self, Error> {
+    Self::from_frames(range.frame_rate(), range.start(), range.end())
+  }
+}
+
+fn seconds(duration: Duration) -> Ratio<u64> {
+  Ratio::from_integer(duration.as_secs())
+    + Ratio::new(duration.subsec_nanos().into(), 1_000_000_000)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn constructors() {
+    let from_frames = TimeRange::from_frames(FrameRate::_25_00, 25, 50).unwrap();
+    let from_durations =
+      TimeRange::from_durations(Duration::from_secs(1), Duration::from_secs(2)).unwrap();
+    let from_timecodes =
+      TimeRange::from_timecodes(FrameRate::_25_00, "00:00:01:00", "00:00:02:00").unwrap();
+    assert_eq!(from_frames, from_durations);
+    assert_eq!(from_frames, from_timecodes);
+    assert_eq!(
+      TimeRange::try_from(ClipRange::new(FrameRate::_25_00, 25, 50).unwrap()),
+      Ok(from_frames)
+    );
+
+    assert_eq!(
+      TimeRange::from_timecodes(FrameRate::_29_97, "00:01:00;02", "00:01:00;04")
+        .unwrap()
+        .start(),
+      FrameRate::_29_97.time_after(1800)
+    );
+    assert_eq!(
+      TimeRange::from_timecodes(FrameRate::_25_00, "00:00:01:00", "1s"),
+      Err(Error::InvalidTimecode("1s".to_string()))
+    );
+    assert_eq!(
+      TimeRange::from_frames(FrameRate::_25_00, 50, 50),
+      Err(Error::InvalidTimeRange {
+        start: 2.into(),
+        end: 2.into()
+      })
+    );
+  }
+
+  #[test]
+  fn time_out_of_range() {
+    let still = FrameRate::new(0, 1);
+    assert_eq!(
+      TimeRange::from_frames(still, 0, 1),
+      Err(Error::TimeOutOfRange {
+        frame_rate: still,
+        frames: 1
+      })
+    );
+    assert_eq!(
+      TimeRange::try_from(ClipRange::new(still, 0, 1).unwrap()),
+      Err(Error::TimeOutOfRange {
+        frame_rate: still,
+        frames: 1
+      })
+    );
+    let slow = FrameRate::new(1, u32::MAX);
+    assert_eq!(
+      TimeRange::from_frames(slow, 0, u64::MAX),
+      Err(Error::TimeOutOfRange {
+        frame_rate: slow,
+        frames: u64::MAX
+      })
+    );
+  }
+
+  #[test]
+  fn mixed_rates() {
+    let ntsc = TimeRange::from_frames(FrameRate::_29_97, 0, 30).unwrap();
+    let pal = TimeRange::from_frames(FrameRate::_25_00, 25, 50).unwrap();
+    assert!(ntsc.overlaps(&pal));
+    assert!(ntsc.contains(1.into()));
+    assert!(!pal.contains(2.into()));
+
+    let intersection = ntsc.intersection(&pal).unwrap();
+    assert_eq!(intersection.start(), 1.into());
+    assert_eq!(intersection.end(), (1001, 1000).into());
+    assert_eq!(intersection.duration(), (1, 1000).into());
+
+    let union = ntsc.union(&pal).unwrap();
+    assert_eq!((union.start(), union.end()), (0.into(), 2.into()));
+
+    let later = TimeRange::from_frames(FrameRate::_25_00, 75, 100).unwrap();
+    assert_eq!(ntsc.intersection(&later), None);
+    assert_eq!(ntsc.union(&later), None);
+    assert!(pal.union(&later).is_none());
+    let adjacent = TimeRange::from_frames(FrameRate::_25_00, 50, 75).unwrap();
+    assert_eq!(pal.union(&adjacent).unwrap().end(), 3.into());
+  }
+
+  #[test]
+  fn to_clip_range() {
+    let range = TimeRange::from_frames(FrameRate::_25_00, 10, 35).unwrap();
+    let outward = range
+      .to_clip_range(FrameRate::_23_97, RangeRounding::Outward)
+      .unwrap();
+    assert_eq!((outward.start(), outward.end()), (9, 34));
+    let inward = range
+      .to_clip_range(FrameRate::_23_97, RangeRounding::Inward)
+      .unwrap();
+    assert_eq!((inward.start(), inward.end()), (10, 33));
+  }
+}
@@ -0,0 +1,324 @@
+use crate::{Breakdown, Error, FrameRate};
+use std::fmt;
+
+/// SMPTE timecode label of a frame, `HH:MM:SS:FF` or `HH:MM:SS;FF` when
+/// drop-frame.
+///
+/// Drop-frame timecode, only defined at 29.97 and 59.94, skips the first 2
+/// labels of every minute (4 at 59.94) except every tenth minute so that the
+/// labels keep up with the wall clock. Hours are not wrapped at 24.
+///
+/// ```
+/// use frame_rate::{FrameRate, Timecode};
+///
+/// let timecode = Timecode::new_drop_frame(FrameRate::_29_97, 1800).unwrap();
+/// assert_eq!(timecode.to_string(), "00:01:00;02");
+/// assert_eq!(Timecode::parse(FrameRate::_29_97, "00:01:00;02"), Ok(timecode));
+/// assert_eq!(timecode.to_frames(), 1800);
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Timecode {
+  frame_rate: FrameRate,
+  frames: u64,
+  drop_frame: bool,
+}
+
+impl Timecode {
+  /// Non drop-frame timecode of frame `frames`, counted from `00:00:00:00`,
+  /// failing for frames without a label, such as any frame after the first at
+  /// 0 fps.
+  pub fn new(frame_rate: FrameRate, frames: u64) -> Result<Self, Error> {
+    Self::with_drop_frame(frame_rate, frames, false)
+  }
+
+  /// Timecode with fields already checked, for [`timecode!`](crate::timecode).
+  pub(crate) const fn from_checked(frame_rate: FrameRate, frames: u64, drop_frame: bool) -> Self {
+    Self {
+      frame_rate,
+      frames,
+      drop_frame,
+    }
+  }
+
+  /// Drop-frame timecode of frame `frames`, failing at rates without
+  /// drop-frame timecode.
+  pub fn new_drop_frame(frame_rate: FrameRate, frames: u64) -> Result<Self, Error> {
+    Self::with_drop_frame(frame_rate, frames, true)
+  }
+
+  /// Drop-frame or non drop-frame timecode of frame `frames`.
+  pub fn with_drop_frame(
+    frame_rate: FrameRate,
+    frames: u64,
+    drop_frame: bool,
+  ) -> Result<Self, Error> {
+    if drop_frame
+      && !frame_rate
+        .metadata()
+        .is_some_and(|metadata| metadata.drop_frame)
+    {
+      return Err(Error::NoDropFrame(frame_rate));
+    }
+    let timecode = Self {
+      frame_rate,
+      frames,
+      drop_frame,
+    };
+    frame_rate
+      .breakdown(frames, drop_frame)
+      .map(|_| timecode)
+      .ok_or_else(|| Error::InvalidTimecode(frames.to_string()))
+  }
+
+  /// Timecode labelled by `breakdown`, failing for out of range fields and
+  /// labels skipped by drop-frame.
+  pub fn from_breakdown(
+    frame_rate: FrameRate,
+    breakdown: Breakdown,
+    drop_frame: bool,
+  ) -> Result<Self, Error> {
+    if drop_frame
+      && !frame_rate
+        .metadata()
+        .is_some_and(|metadata| metadata.drop_frame)
+    {
+      return Err(Error::NoDropFrame(frame_rate));
+    }
+    let frames = frame_rate
+      .from_breakdown(breakdown, drop_frame)
+      .ok_or_else(|| {
+        Error::InvalidTimecode(format_breakdown(frame_rate, &breakdown, drop_frame))
+      })?;
+    Ok(Self {
+      frame_rate,
+      frames,
+      drop_frame,
+    })
+  }
+
+  /// Parses `HH:MM:SS:FF`, drop-frame when a `;` separates the fields, as in
+  /// `HH:MM:SS;FF`. Hours may have any number of digits.
+  pub fn parse(frame_rate: FrameRate, value: &str) -> Result<Self, Error> {
+    let invalid = || Error::InvalidTimecode(value.to_string());
+    let mut fields = value.trim().split([':', ';']).map(|field| {
+      (!field.is_empty() && field.bytes().all(|byte| byte.is_ascii_digit()))
+        .then(|| field.parse::<u64>().ok())
+        .flatten()
+    });
+    let mut field = || fields.next().flatten().ok_or_else(invalid);
+    let breakdown = Breakdown {
+      hours: field()?,
+      minutes: field()?.try_into().map_err(|_| invalid())?,
+      seconds: field()?.try_into().map_err(|_| invalid())?,
+      frames: field()?.try_into().map_err(|_| invalid())?,
+    };
+    if fields.next().is_some() {
+      return Err(invalid());
+    }
+    let drop_frame = value.contains(';');
+    Self::from_breakdown(frame_rate, breakdown, drop_frame).map_err(|error| match error {
+      Error::InvalidTimecode(_) => invalid(),
+      error => error,
+    })
+  }
+
+  pub fn frame_rate(&self) -> FrameRate {
+    self.frame_rate
+  }
+
+  /// Frame labelled by this timecode, counted from `00:00:00:00`.
+  pub fn to_frames(&self) -> u64 {
+    self.frames
+  }
+
+  pub fn is_drop_frame(&self) -> bool {
+    self.drop_frame
+  }
+
+  /// Hours, minutes, seconds and frames fields.
+  pub fn breakdown(&self) -> Breakdown {
+    self
+      .frame_rate
+      .breakdown(self.frames, self.drop_frame)
+      .expect("checked when built")
+  }
+
+  /// Same frame labelled with drop-frame timecode.
+  pub fn to_drop_frame(&self) -> Result<Self, Error> {
+    Self::new_drop_frame(self.frame_rate, self.frames)
+  }
+
+  /// Same frame labelled with non drop-frame timecode.
+  pub fn to_non_drop_frame(&self) -> Self {
+    // Every frame labelled with drop-frame is also labelled without.
+    Self {
+      drop_frame: false,
+      ..*self
+    }
+  }
+
+  /// Timecode `frames` frames later, or earlier when negative. `None` before
+  /// `00:00:00:00` or on overflow.
+  pub fn checked_add_frames(&self, frames: i64) -> Option<Self> {
+    let frames = self.frames.checked_add_signed(frames)?;
+    Self::with_drop_frame(self.frame_rate, frames, self.drop_frame).ok()
+  }
+}
+
+impl fmt::Display for Timecode {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.write_str(&format_breakdown(
+      self.frame_rate,
+      &self.breakdown(),
+      self.drop_frame,
+    ))
+  }
+}
+
+/// Frames are printed with as many digits as the largest label, at least 2.
+fn format_breakdown(frame_rate: FrameRate, breakdown: &Breakdown, drop_frame: bool) -> String {
+  let width = (frame_rate.nominal_fps() - 1).to_string().len().max(2);
+  let separator = if drop_frame { ';' } else { ':' };
+  format!(
+    "{:02}:{:02}:{:02}{separator}{:0width$}",
+    breakdown.hours, breakdown.minutes, breakdown.seconds, breakdown.frames
+  )
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::STANDARD_FRAME_RATES;
+
+  #[test]
+  fn display() {
+    assert_eq!(
+      Timecode::new(FrameRate::_25_00, 137).unwrap().to_string(),
+      "00:00:05:12"
+    );
+    assert_eq!(
+      Timecode::new(FrameRate::_29_97, 1800).unwrap().to_string(),
+      "00:01:00:00"
+    );
+    assert_eq!(
+      Timecode::new_drop_frame(FrameRate::_59_94, 3600)
+        .unwrap()
+        .to_string(),
+      "00:01:00;04"
+    );
+    assert_eq!(
+      Timecode::new(FrameRate::_120_00, 119).unwrap().to_string(),
+      "00:00:00:119"
+    );
+    assert_eq!(
+      Timecode::new(FrameRate::_24_00, 24 * 3600 * 100)
+        .unwrap()
+        .to_string(),
+      "100:00:00:00"
+    );
+    assert_eq!(
+      Timecode::new(FrameRate::new(1, 10), 7).unwrap().to_string(),
+      "00:01:10:00"
+    );
+  }
+
+  #[test]
+  fn unlabelled_frames() {
+    let still = FrameRate::new(0, 1);
+    assert_eq!(Timecode::new(still, 0).unwrap().to_string(), "00:00:00:00");
+    assert_eq!(
+      Timecode::new(still, 5),
+      Err(Error::InvalidTimecode("5".to_string()))
+    );
+    assert!(Timecode::new(FrameRate::new(1, u32::MAX), u64::MAX).is_err());
+  }
+
+  #[test]
+  fn parse() {
+    assert_eq!(
+      Timecode::parse(FrameRate::_25_00, " 01:00:00:00 ").map(|timecode| timecode.to_frames()),
+      Ok(90_000)
+    );
+    assert_eq!(
+      Timecode::parse(FrameRate::_29_97, "00:10:00;00").map(|timecode| timecode.to_frames()),
+      Ok(17_982)
+    );
+    assert_eq!(
+      Timecode::parse(FrameRate::new(1, 10), "00:01:10:00").map(|timecode| timecode.to_frames()),
+      Ok(7)
+    );
+    for value in [
+      "",
+      "00:00:05",
+      "00:00:05:25",
+      "00:60:00:00",
+      "1:2:3:x",
+      "00:00:00:00:00",
+    ] {
+      assert_eq!(
+        Timecode::parse(FrameRate::_25_00, value),
+        Err(Error::InvalidTimecode(value.to_string())),
+        "{value:?}"
+      );
+    }
+    assert_eq!(
+      Timecode::parse(FrameRate::_29_97, "00:01:00;01"),
+      Err(Error::InvalidTimecode("00:01:00;01".to_string()))
+    );
+    assert_eq!(
+      Timecode::parse(FrameRate::_25_00, "00:00:00;00"),
+      Err(Error::NoDropFrame(FrameRate::_25_00))
+    );
+  }
+
+  #[test]
+  fn round_trip() {
+    for frame_rate in STANDARD_FRAME_RATES {
+      let drop_frame = frame_rate.metadata().unwrap().drop_frame;
+      for frames in (0..500_000).step_by(101) {
+        for drop_frame in [false, drop_frame] {
+          let timecode = Timecode::with_drop_frame(frame_rate, frames, drop_frame).unwrap();
+          assert_eq!(
+            Timecode::parse(frame_rate, &timecode.to_string()),
+            Ok(timecode)
+          );
+        }
+      }
+    }
+  }
+
+  #[test]
+  fn conversions() {
+    let timecode = Timecode::new(FrameRate::_29_97, 1800).unwrap();
+    assert_eq!(timecode.to_drop_frame().unwrap().to_string(), "00:01:00;02");
+    assert_eq!(
+      timecode.to_drop_frame().unwrap().to_non_drop_frame(),
+      timecode
+    );
+    assert_eq!(
+      Timecode::new(FrameRate::_25_00, 0).unwrap().to_drop_frame(),
+      Err(Error::NoDropFrame(FrameRate::_25_00))
+    );
+
+    let timecode = Timecode::new_drop_frame(FrameRate::_29_97, 1799).unwrap();
+    assert_eq!(timecode.to_string(), "00:00:59;29");
+    assert_eq!(
+      timecode.checked_add_frames(1).unwrap().to_string(),
+      "00:01:00;02"
+    );
+    assert_eq!(timecode.checked_add_frames(-1800), None);
+    assert_eq!(
+      Timecode::from_breakdown(
+        FrameRate::_29_97,
+        Breakdown {
+          hours: 0,
+          minutes: 1,
+          seconds: 0,
+          frames: 0
+        },
+        true
+      ),
+      Err(Error::InvalidTimecode("00:01:00;00".to_string()))
+    );
+  }
+}
@@ -0,0 +1,162 @@
+use crate::{FrameRate, Rounding};
+use std::time::Duration;
+
+/// Run of `frames` frames at `frame_rate` in a [`Timeline`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TimelineSegment {
+  pub frame_rate: FrameRate,
+  pub frames: u64,
+}
+
+/// Sequence of segments, each at its own frame rate, played back to back.
+///
+/// Segment start times are the sum of the previous segment durations, each
+/// rounded to the nearest nanosecond, saturating at `Duration::MAX` like after
+/// frames at 0 fps.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Timeline {
+  segments: Vec<TimelineSegment>,
+  starts: Vec<Duration>,
+  duration: Duration,
+}
+
+impl Timeline {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  /// Appends `frames` frames at `frame_rate`.
+  pub fn push(&mut self, frame_rate: FrameRate, frames: u64) {
+    self.segments.push(TimelineSegment { frame_rate, frames });
+    self.starts.push(self.duration);
+    self.duration = self.duration.saturating_add(frame_rate.duration_of(frames));
+  }
+
+  pub fn segments(&self) -> &[TimelineSegment] {
+    &self.segments
+  }
+
+  /// Total number of frames, all rates mixed, saturating at `u64::MAX`.
+  pub fn frames(&self) -> u64 {
+    self
+      .segments
+      .iter()
+      .fold(0, |total, segment| total.saturating_add(segment.frames))
+  }
+
+  pub fn duration(&self) -> Duration {
+    self.duration
+  }
+
+  /// Start time of `segment`.
+  pub fn segment_start(&self, segment: usize) -> Option<Duration> {
+    self.starts.get(segment).copied()
+  }
+
+  /// Start time of `frame` within `segment`, rounded up to the nanosecond so
+  /// that [`Timeline::locate`] maps it back to the same frame. `None` when out
+  /// of range.
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(self), ret)
+  )]
+  pub fn time_of(&self, segment: usize, frame: u64) -> Option<Duration> {
+    let start = self.segment_start(segment)?;
+    let TimelineSegment { frame_rate, frames } = self.segments[segment];
+    (frame < frames)
+      .then(|| start.saturating_add(frame_rate.frames_to_duration(frame, Rounding::Ceil)))
+  }
+
+  /// Segment and frame within that segment showing at `time`, or `None` past
+  /// the end of the timeline.
+  #[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(level = "trace", skip(self), ret)
+  )]
+  pub fn locate(&self, time: Duration) -> Option<(usize, u64)> {
+    if time >= self.duration {
+      return None;
+    }
+    let segment = self.starts.partition_point(|start| *start <= time) - 1;
+    let TimelineSegment { frame_rate, frames } = self.segments[segment];
+    let frame = frame_rate.duration_to_frames(time - self.starts[segment], Rounding::Floor);
+    Some((segment, frame.min(frames - 1)))
+  }
+}
+
+impl FromIterator<(FrameRate, u64)> for Timeline {
+  fn from_iter<I: IntoIterator<Item = (FrameRate, u64)>>(iter: I) -> Self {
+    let mut timeline = Self::new();
+    for (frame_rate, frames) in iter {
+      timeline.push(frame_rate, frames);
+    }
+    timeline
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn mixed_rates() {
+    let timeline: Timeline = [
+      (FrameRate::_25_00, 50),
+      (FrameRate::_50_00, 0),
+      (FrameRate::_50_00, 100),
+      (FrameRate::_29_97, 30),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(timeline.frames(), 180);
+    assert_eq!(
+      timeline.duration(),
+      Duration::from_secs(4) + Duration::from_nanos(1_001_000_000)
+    );
+
+    assert_eq!(timeline.locate(Duration::ZERO), Some((0, 0)));
+    assert_eq!(timeline.locate(Duration::from_millis(1999)), Some((0, 49)));
+    assert_eq!(timeline.locate(Duration::from_secs(2)), Some((2, 0)));
+    assert_eq!(timeline.locate(Duration::from_millis(3010)), Some((2, 50)));
+    assert_eq!(timeline.locate(Duration::from_secs(4)), Some((3, 0)));
+    assert_eq!(timeline.locate(timeline.duration()), None);
+
+    assert_eq!(timeline.time_of(2, 50), Some(Duration::from_secs(3)));
+    assert_eq!(timeline.time_of(1, 0), None);
+    assert_eq!(timeline.time_of(4, 0), None);
+  }
+
+  #[test]
+  fn saturation() {
+    let timeline: Timeline = [
+      (FrameRate::_25_00, 25),
+      (FrameRate::new(0, 1), 2),
+      (FrameRate::new(1, u32::MAX), u64::MAX),
+    ]
+    .into_iter()
+    .collect();
+
+    assert_eq!(timeline.frames(), u64::MAX);
+    assert_eq!(timeline.duration(), Duration::MAX);
+    assert_eq!(timeline.segment_start(2), Some(Duration::MAX));
+    assert_eq!(timeline.time_of(1, 0), Some(Duration::from_secs(1)));
+    assert_eq!(timeline.time_of(1, 1), Some(Duration::MAX));
+    assert_eq!(timeline.time_of(2, 10), Some(Duration::MAX));
+    assert_eq!(timeline.locate(Duration::from_secs(2)), Some((1, 0)));
+  }
+
+  #[test]
+  fn round_trip() {
+    let timeline: Timeline = [(FrameRate::_23_97, 7), (FrameRate::_59_94, 11)]
+      .into_iter()
+      .collect();
+
+    for (index, segment) in timeline.segments().iter().enumerate() {
+      for frame in 0..segment.frames {
+        let time = timeline.time_of(index, frame).unwrap();
+        assert_eq!(timeline.locate(time), Some((index, frame)));
+      }
+    }
+  }
+}
@@ -0,0 +1,97 @@
+use crate::{FrameRate, Rounding};
+use std::time::Duration;
+
+impl FrameRate {
+  /// Time covered by `frames` frames, rounded up to the next nanosecond so a
+  /// timeout never fires before the last frame was due.
+  pub fn frames_to_timeout(&self, frames: u64) -> Duration {
+    self.frames_to_duration(frames, Rounding::Ceil)
+  }
+
+  /// Number of whole frames elapsed during `duration`.
+  pub fn timeout_to_frames(&self, duration: Duration) -> u64 {
+    self.duration_to_frames(duration, Rounding::Floor)
+  }
+}
+
+/// Deadline expressed in frames, e.g. "give up after 5 missed frames".
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct GracePeriod {
+  frame_rate: FrameRate,
+  frames: u64,
+}
+
+impl GracePeriod {
+  pub fn new(frame_rate: FrameRate, frames: u64) -> Self {
+    Self { frame_rate, frames }
+  }
+
+  pub fn frame_rate(&self) -> FrameRate {
+    self.frame_rate
+  }
+
+  pub fn frames(&self) -> u64 {
+    self.frames
+  }
+
+  /// Wall-clock length of the grace period.
+  pub fn timeout(&self) -> Duration {
+    self.frame_rate.frames_to_timeout(self.frames)
+  }
+
+  /// Number of frames missed after `elapsed` without receiving any.
+  pub fn missed_frames(&self, elapsed: Duration) -> u64 {
+    self.frame_rate.timeout_to_frames(elapsed)
+  }
+
+  /// Whether `elapsed` without a frame exhausts the grace period.
+  pub fn is_expired(&self, elapsed: Duration) -> bool {
+    self.missed_frames(elapsed) >= self.frames
+  }
+
+  /// Time left before the grace period expires, zero once expired.
+  pub fn remaining(&self, elapsed: Duration) -> Duration {
+    self.timeout().saturating_sub(elapsed)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn timeout_round_trip() {
+    assert_eq!(
+      FrameRate::_25_00.frames_to_timeout(5),
+      Duration::from_millis(200)
+    );
+    // 1001/30000 s = 33_366_666.67 ns, rounded up.
+    assert_eq!(
+      FrameRate::_29_97.frames_to_timeout(1),
+      Duration::from_nanos(33_366_667)
+    );
+    for frames in [0, 1, 5, 29, 30_000] {
+      let timeout = FrameRate::_29_97.frames_to_timeout(frames);
+      assert_eq!(FrameRate::_29_97.timeout_to_frames(timeout), frames);
+    }
+    assert_eq!(
+      FrameRate::_25_00.timeout_to_frames(Duration::from_millis(199)),
+      4
+    );
+    assert_eq!(FrameRate::_25_00.timeout_to_frames(Duration::MAX), u64::MAX);
+  }
+
+  #[test]
+  fn grace_period() {
+    let grace = GracePeriod::new(FrameRate::_50_00, 5);
+    assert_eq!(grace.timeout(), Duration::from_millis(100));
+    assert!(!grace.is_expired(Duration::from_millis(99)));
+    assert!(grace.is_expired(Duration::from_millis(100)));
+    assert_eq!(grace.missed_frames(Duration::from_millis(65)), 3);
+    assert_eq!(
+      grace.remaining(Duration::from_millis(65)),
+      Duration::from_millis(35)
+    );
+    assert_eq!(grace.remaining(Duration::from_secs(1)), Duration::ZERO);
+  }
+}
@@ -0,0 +1,58 @@
+use crate::{FrameRate, SerializeRational};
+use ts_rs::{Config, TS, TypeVisitor};
+
+/// TypeScript type of the serde representation, `{ num: number, den: number }`.
+impl TS for FrameRate {
+  type WithoutGenerics = Self;
+  type OptionInnerType = Self;
+
+  fn name(config: &Config) -> String {
+    SerializeRational::name(config)
+  }
+
+  fn inline(config: &Config) -> String {
+    SerializeRational::inline(config)
+  }
+
+  fn inline_flattened(config: &Config) -> String {
+    SerializeRational::inline_flattened(config)
+  }
+
+  fn visit_dependencies(visitor: &mut impl TypeVisitor)
+  where
+    Self: 'static,
+  {
+    SerializeRational::visit_dependencies(visitor)
+  }
+
+  fn decl(config: &Config) -> String {
+    SerializeRational::decl(config)
+  }
+
+  fn decl_concrete(config: &Config) -> String {
+    SerializeRational::decl_concrete(config)
+  }
+
+  fn output_path() -> Option<std::path::PathBuf> {
+    SerializeRational::output_path()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn declaration() {
+    let config = Config::default();
+    assert_eq!(FrameRate::name(&config), "FrameRate");
+    assert_eq!(
+      FrameRate::decl(&config),
+      "type FrameRate = { num: number, den: number, };"
+    );
+    assert_eq!(
+      FrameRate::output_path(),
+      Some(std::path::PathBuf::from("FrameRate.ts"))
+    );
+  }
+}
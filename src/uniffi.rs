@@ -0,0 +1,172 @@
+//! Swift and Kotlin bindings generated with UniFFI, so mobile apps share the
+//! frame math of the backend.
+
+use crate::{Breakdown, Error, FrameRate, Ratio, Rounding, Timecode, parse_frame_rate};
+use std::time::Duration;
+
+/// `{num, den}` record standing for a [`FrameRate`] across the FFI boundary.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, uniffi::Record)]
+pub struct FrameRateRecord {
+  pub num: u32,
+  pub den: u32,
+}
+
+impl From<FrameRate> for FrameRateRecord {
+  fn from(frame_rate: FrameRate) -> Self {
+    let ratio = Ratio::<u32>::from(frame_rate);
+    Self {
+      num: *ratio.numer(),
+      den: *ratio.denom(),
+    }
+  }
+}
+
+impl TryFrom<FrameRateRecord> for FrameRate {
+  type Error = Error;
+
+  fn try_from(record: FrameRateRecord) -> Result<Self, Error> {
+    Self::try_new(record.num, record.den)
+  }
+}
+
+/// Reduced record, standard rates snapped, or `None` when `value` is not a
+/// frame rate, see [`parse_frame_rate`].
+#[uniffi::export]
+pub fn frame_rate_parse(value: String) -> Option<FrameRateRecord> {
+  parse_frame_rate(&value).ok().map(Into::into)
+}
+
+#[uniffi::export]
+pub fn frame_rate_display(frame_rate: FrameRateRecord) -> Result<String, Error> {
+  Ok(FrameRate::try_from(frame_rate)?.to_string())
+}
+
+#[uniffi::export]
+pub fn frame_rate_frames_to_duration(
+  frame_rate: FrameRateRecord,
+  frames: u64,
+  rounding: Rounding,
+) -> Result<Duration, Error> {
+  Ok(FrameRate::try_from(frame_rate)?.frames_to_duration(frames, rounding))
+}
+
+#[uniffi::export]
+pub fn frame_rate_duration_to_frames(
+  frame_rate: FrameRateRecord,
+  duration: Duration,
+  rounding: Rounding,
+) -> Result<u64, Error> {
+  Ok(FrameRate::try_from(frame_rate)?.duration_to_frames(duration, rounding))
+}
+
+/// Index at `target` of the frame `frame` at `frame_rate`.
+#[uniffi::export]
+pub fn frame_rate_convert_frame(
+  frame_rate: FrameRateRecord,
+  frame: u64,
+  target: FrameRateRecord,
+  rounding: Rounding,
+) -> Result<u64, Error> {
+  Ok(FrameRate::try_from(frame_rate)?.convert_frame(frame, target.try_into()?, rounding))
+}
+
+#[uniffi::export]
+pub fn frame_rate_breakdown(
+  frame_rate: FrameRateRecord,
+  frames: u64,
+  drop_frame: bool,
+) -> Result<Option<Breakdown>, Error> {
+  Ok(FrameRate::try_from(frame_rate)?.breakdown(frames, drop_frame))
+}
+
+#[uniffi::export]
+pub fn frame_rate_from_breakdown(
+  frame_rate: FrameRateRecord,
+  breakdown: Breakdown,
+  drop_frame: bool,
+) -> Result<Option<u64>, Error> {
+  Ok(FrameRate::try_from(frame_rate)?.from_breakdown(breakdown, drop_frame))
+}
+
+/// `HH:MM:SS:FF` label of frame `frames`, or `HH:MM:SS;FF` when
+/// `drop_frame`.
+#[uniffi::export]
+pub fn timecode_format(
+  frame_rate: FrameRateRecord,
+  frames: u64,
+  drop_frame: bool,
+) -> Result<String, Error> {
+  Ok(Timecode::with_drop_frame(frame_rate.try_into()?, frames, drop_frame)?.to_string())
+}
+
+/// Frame labelled by `value`, see [`Timecode::parse`].
+#[uniffi::export]
+pub fn timecode_parse(frame_rate: FrameRateRecord, value: String) -> Result<u64, Error> {
+  Ok(Timecode::parse(frame_rate.try_into()?, &value)?.to_frames())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  const NTSC: FrameRateRecord = FrameRateRecord {
+    num: 30000,
+    den: 1001,
+  };
+
+  #[test]
+  fn records() {
+    assert_eq!(frame_rate_parse("29.97".to_string()), Some(NTSC));
+    assert_eq!(frame_rate_parse("fast".to_string()), None);
+    assert_eq!(frame_rate_display(NTSC), Ok("29.97".to_string()));
+    assert_eq!(
+      frame_rate_display(FrameRateRecord { num: 25, den: 0 }),
+      Err(Error::ZeroDenominator)
+    );
+  }
+
+  #[test]
+  fn conversions() {
+    assert_eq!(
+      frame_rate_frames_to_duration(NTSC, 30000, Rounding::Nearest),
+      Ok(Duration::from_secs(1001))
+    );
+    assert_eq!(
+      frame_rate_duration_to_frames(NTSC, Duration::from_secs(1), Rounding::Floor),
+      Ok(29)
+    );
+    assert_eq!(
+      frame_rate_convert_frame(
+        NTSC,
+        30,
+        FrameRateRecord {
+          num: 60000,
+          den: 1001
+        },
+        Rounding::Floor
+      ),
+      Ok(60)
+    );
+
+    let still = FrameRateRecord { num: 0, den: 1 };
+    assert_eq!(
+      frame_rate_frames_to_duration(still, 1, Rounding::Nearest),
+      Ok(Duration::MAX)
+    );
+    assert_eq!(
+      frame_rate_convert_frame(still, 1, NTSC, Rounding::Nearest),
+      Ok(u64::MAX)
+    );
+    let breakdown = frame_rate_breakdown(NTSC, 1800, true).unwrap().unwrap();
+    assert_eq!((breakdown.minutes, breakdown.frames), (1, 2));
+    assert_eq!(
+      frame_rate_from_breakdown(NTSC, breakdown, true),
+      Ok(Some(1800))
+    );
+    assert_eq!(
+      timecode_format(NTSC, 1800, true),
+      Ok("00:01:00;02".to_string())
+    );
+    assert_eq!(timecode_parse(NTSC, "00:01:00;02".to_string()), Ok(1800));
+  }
+}
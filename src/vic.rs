@@ -0,0 +1,186 @@
+use crate::{FrameRate, FrameRate as F, Ratio, ScanMode};
+use ScanMode::{Interlaced as I, Progressive as P};
+
+/// CTA-861 Video Identification Code.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Vic {
+  pub code: u8,
+  pub width: u16,
+  pub height: u16,
+  pub scan: ScanMode,
+  pub aspect_ratio: (u16, u16),
+  /// Integer frame rate, e.g. 30 for 1080i60 whose field rate is 60.
+  pub frame_rate: FrameRate,
+  /// Whether the 1000/1001 variant of `frame_rate` also uses this code.
+  pub fractional: bool,
+}
+
+impl Vic {
+  /// Frame rates carried by this code, integer one first.
+  pub fn frame_rates(&self) -> impl Iterator<Item = FrameRate> {
+    let fractional = self.fractional.then(|| self.frame_rate.apply_ntsc_factor());
+    std::iter::once(self.frame_rate).chain(fractional)
+  }
+
+  pub fn carries(&self, frame_rate: FrameRate) -> bool {
+    self.frame_rates().any(|carried| carried == frame_rate)
+  }
+}
+
+const fn vic(
+  code: u8,
+  width: u16,
+  height: u16,
+  scan: ScanMode,
+  aspect_ratio: (u16, u16),
+  frame_rate: FrameRate,
+  fractional: bool,
+) -> Vic {
+  Vic {
+    code,
+    width,
+    height,
+    scan,
+    aspect_ratio,
+    frame_rate,
+    fractional,
+  }
+}
+
+/// Common CTA-861 video formats, by code.
+pub static VICS: [Vic; 35] = [
+  vic(1, 640, 480, P, (4, 3), F::_60_00, true),
+  vic(2, 720, 480, P, (4, 3), F::_60_00, true),
+  vic(3, 720, 480, P, (16, 9), F::_60_00, true),
+  vic(4, 1280, 720, P, (16, 9), F::_60_00, true),
+  vic(5, 1920, 1080, I, (16, 9), F::_30_00, true),
+  vic(6, 720, 480, I, (4, 3), F::_30_00, true),
+  vic(7, 720, 480, I, (16, 9), F::_30_00, true),
+  vic(16, 1920, 1080, P, (16, 9), F::_60_00, true),
+  vic(17, 720, 576, P, (4, 3), F::_50_00, false),
+  vic(18, 720, 576, P, (16, 9), F::_50_00, false),
+  vic(19, 1280, 720, P, (16, 9), F::_50_00, false),
+  vic(20, 1920, 1080, I, (16, 9), F::_25_00, false),
+  vic(21, 720, 576, I, (4, 3), F::_25_00, false),
+  vic(22, 720, 576, I, (16, 9), F::_25_00, false),
+  vic(31, 1920, 1080, P, (16, 9), F::_50_00, false),
+  vic(32, 1920, 1080, P, (16, 9), F::_24_00, true),
+  vic(33, 1920, 1080, P, (16, 9), F::_25_00, false),
+  vic(34, 1920, 1080, P, (16, 9), F::_30_00, true),
+  vic(60, 1280, 720, P, (16, 9), F::_24_00, true),
+  vic(61, 1280, 720, P, (16, 9), F::_25_00, false),
+  vic(62, 1280, 720, P, (16, 9), F::_30_00, true),
+  vic(63, 1920, 1080, P, (16, 9), F::_120_00, true),
+  vic(
+    64,
+    1920,
+    1080,
+    P,
+    (16, 9),
+    F::FrCustom(Ratio::new_raw(100, 1)),
+    false,
+  ),
+  vic(93, 3840, 2160, P, (16, 9), F::_24_00, true),
+  vic(94, 3840, 2160, P, (16, 9), F::_25_00, false),
+  vic(95, 3840, 2160, P, (16, 9), F::_30_00, true),
+  vic(96, 3840, 2160, P, (16, 9), F::_50_00, false),
+  vic(97, 3840, 2160, P, (16, 9), F::_60_00, true),
+  vic(98, 4096, 2160, P, (256, 135), F::_24_00, true),
+  vic(99, 4096, 2160, P, (256, 135), F::_25_00, false),
+  vic(100, 4096, 2160, P, (256, 135), F::_30_00, true),
+  vic(101, 4096, 2160, P, (256, 135), F::_50_00, false),
+  vic(102, 4096, 2160, P, (256, 135), F::_60_00, true),
+  vic(
+    117,
+    3840,
+    2160,
+    P,
+    (16, 9),
+    F::FrCustom(Ratio::new_raw(100, 1)),
+    false,
+  ),
+  vic(118, 3840, 2160, P, (16, 9), F::_120_00, true),
+];
+
+impl Vic {
+  pub fn from_code(code: u8) -> Option<&'static Self> {
+    VICS.iter().find(|vic| vic.code == code)
+  }
+
+  /// First code matching a format, the 16:9 one when several aspect ratios
+  /// share a resolution.
+  pub fn find(
+    width: u16,
+    height: u16,
+    scan: ScanMode,
+    frame_rate: FrameRate,
+  ) -> Option<&'static Self> {
+    let mut matching = VICS.iter().filter(|vic| {
+      vic.width == width && vic.height == height && vic.scan == scan && vic.carries(frame_rate)
+    });
+    let first = matching.next()?;
+    Some(
+      std::iter::once(first)
+        .chain(matching)
+        .find(|vic| vic.aspect_ratio == (16, 9))
+        .unwrap_or(first),
+    )
+  }
+}
+
+impl FrameRate {
+  /// CTA-861 codes carrying this frame rate.
+  pub fn vics(&self) -> impl Iterator<Item = &'static Vic> + '_ {
+    VICS.iter().filter(|vic| vic.carries(*self))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn table() {
+    assert!(VICS.windows(2).all(|pair| pair[0].code < pair[1].code));
+    for vic in &VICS {
+      assert_eq!(vic.frame_rate, FrameRate::from(Ratio::from(vic.frame_rate)));
+    }
+  }
+
+  #[test]
+  fn lookup() {
+    let vic = Vic::find(1920, 1080, ScanMode::Progressive, FrameRate::_59_94).unwrap();
+    assert_eq!(vic.code, 16);
+    assert_eq!(
+      Vic::find(720, 576, ScanMode::Interlaced, FrameRate::_25_00).map(|vic| vic.code),
+      Some(22)
+    );
+    assert_eq!(
+      Vic::find(
+        1920,
+        1080,
+        ScanMode::Progressive,
+        FrameRate::new(120000, 1001)
+      )
+      .map(|vic| vic.code),
+      Some(63)
+    );
+    assert_eq!(
+      Vic::find(1920, 1080, ScanMode::Progressive, FrameRate::_24_97),
+      None
+    );
+
+    assert_eq!(
+      Vic::from_code(5).unwrap().frame_rates().collect::<Vec<_>>(),
+      [FrameRate::_30_00, FrameRate::_29_97]
+    );
+    assert_eq!(Vic::from_code(8), None);
+  }
+
+  #[test]
+  fn vics() {
+    let codes: Vec<_> = FrameRate::_23_97.vics().map(|vic| vic.code).collect();
+    assert_eq!(codes, [32, 60, 93, 98]);
+    assert_eq!(FrameRate::new(100, 1).vics().count(), 2);
+  }
+}
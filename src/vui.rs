@@ -0,0 +1,146 @@
+use crate::{Error, FrameRate, Ratio};
+
+/// Codec of a VUI `timing_info`, deciding how ticks map to frames.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VuiCodec {
+  /// H.264 ticks are fields, a frame lasts two ticks.
+  H264,
+  /// H.265 ticks are pictures.
+  H265,
+}
+
+impl VuiCodec {
+  fn ticks_per_frame(self) -> u64 {
+    match self {
+      Self::H264 => 2,
+      Self::H265 => 1,
+    }
+  }
+}
+
+/// `timing_info` fields of an H.264 or H.265 VUI.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct VuiTiming {
+  pub num_units_in_tick: u32,
+  pub time_scale: u32,
+  /// Only signalled by H.264, `false` when absent.
+  pub fixed_frame_rate_flag: bool,
+}
+
+impl VuiTiming {
+  /// Reads the fields from a byte-aligned slice starting at
+  /// `num_units_in_tick`: two big-endian `u32`, then `fixed_frame_rate_flag`
+  /// in the most significant bit of an optional ninth byte.
+  pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+    let field = |offset: usize| {
+      bytes
+        .get(offset..offset + 4)
+        .map(|field| u32::from_be_bytes(field.try_into().unwrap()))
+        .ok_or(Error::InvalidVuiTiming("truncated timing_info"))
+    };
+
+    Ok(Self {
+      num_units_in_tick: field(0)?,
+      time_scale: field(4)?,
+      fixed_frame_rate_flag: bytes.get(8).is_some_and(|byte| byte & 0x80 != 0),
+    })
+  }
+
+  /// Frame rate signalled by these fields.
+  #[cfg_attr(feature = "tracing", tracing::instrument(level = "debug", ret))]
+  pub fn frame_rate(&self, codec: VuiCodec) -> Result<FrameRate, Error> {
+    if self.num_units_in_tick == 0 || self.time_scale == 0 {
+      return Err(Error::InvalidVuiTiming(
+        "num_units_in_tick and time_scale must not be zero",
+      ));
+    }
+    FrameRate::new_u64(
+      u64::from(self.time_scale),
+      u64::from(self.num_units_in_tick) * codec.ticks_per_frame(),
+    )
+  }
+
+  /// Smallest fields signalling `frame_rate` at a fixed rate, or `None` when
+  /// they do not fit.
+  pub fn from_frame_rate(frame_rate: FrameRate, codec: VuiCodec) -> Option<Self> {
+    let ratio = Ratio::<u32>::from(frame_rate);
+    Some(Self {
+      num_units_in_tick: *ratio.denom(),
+      time_scale: ratio.numer().checked_mul(codec.ticks_per_frame() as u32)?,
+      fixed_frame_rate_flag: true,
+    })
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn from_bytes() {
+    let bytes = [0, 0, 0x03, 0xe9, 0, 0, 0xea, 0x60, 0x80];
+    let timing = VuiTiming::from_bytes(&bytes).unwrap();
+    assert_eq!(
+      timing,
+      VuiTiming {
+        num_units_in_tick: 1001,
+        time_scale: 60000,
+        fixed_frame_rate_flag: true,
+      }
+    );
+    assert_eq!(timing.frame_rate(VuiCodec::H264), Ok(FrameRate::_29_97));
+    assert_eq!(timing.frame_rate(VuiCodec::H265), Ok(FrameRate::_59_94));
+
+    assert!(
+      !VuiTiming::from_bytes(&bytes[..8])
+        .unwrap()
+        .fixed_frame_rate_flag
+    );
+    assert_eq!(
+      VuiTiming::from_bytes(&bytes[..7]),
+      Err(Error::InvalidVuiTiming("truncated timing_info"))
+    );
+  }
+
+  #[test]
+  fn frame_rate() {
+    let timing = VuiTiming {
+      num_units_in_tick: 1,
+      time_scale: 50,
+      fixed_frame_rate_flag: false,
+    };
+    assert_eq!(timing.frame_rate(VuiCodec::H264), Ok(FrameRate::_25_00));
+    assert!(
+      VuiTiming {
+        num_units_in_tick: 0,
+        ..timing
+      }
+      .frame_rate(VuiCodec::H265)
+      .is_err()
+    );
+
+    let timing = VuiTiming {
+      num_units_in_tick: u32::MAX,
+      time_scale: 1,
+      fixed_frame_rate_flag: false,
+    };
+    assert!(matches!(
+      timing.frame_rate(VuiCodec::H264),
+      Err(Error::FrameRateOutOfRange { .. })
+    ));
+  }
+
+  #[test]
+  fn round_trip() {
+    for frame_rate in FrameRate::standard_rates() {
+      for codec in [VuiCodec::H264, VuiCodec::H265] {
+        let timing = VuiTiming::from_frame_rate(frame_rate, codec).unwrap();
+        assert_eq!(timing.frame_rate(codec), Ok(frame_rate));
+      }
+    }
+    assert_eq!(
+      VuiTiming::from_frame_rate(FrameRate::new(u32::MAX, 1), VuiCodec::H264),
+      None
+    );
+  }
+}
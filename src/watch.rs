@@ -0,0 +1,97 @@
+use crate::{FrameRate, RateChange};
+use tokio::sync::watch;
+
+/// Broadcasts frame rate changes to any number of subscribers.
+#[derive(Debug)]
+pub struct FrameRateWatch {
+  sender: watch::Sender<RateChange>,
+}
+
+impl FrameRateWatch {
+  pub fn new(frame_rate: FrameRate) -> Self {
+    let (sender, _) = watch::channel(RateChange::new(frame_rate, frame_rate, 0));
+    Self { sender }
+  }
+
+  pub fn current(&self) -> FrameRate {
+    self.sender.borrow().to
+  }
+
+  pub fn last_change(&self) -> RateChange {
+    *self.sender.borrow()
+  }
+
+  /// Switch to `frame_rate` starting at `effective_frame`.
+  ///
+  /// Subscribers are only notified when the rate actually changes; the
+  /// broadcast change is returned in that case. After frames at 0 fps, the
+  /// effective time saturates at `Duration::MAX`.
+  pub fn change(&self, frame_rate: FrameRate, effective_frame: u64) -> Option<RateChange> {
+    let mut change = None;
+    self.sender.send_if_modified(|last| {
+      if last.to == frame_rate {
+        return false;
+      }
+      *last = RateChange {
+        from: last.to,
+        to: frame_rate,
+        effective_frame,
+        effective_time: last.time_of(effective_frame),
+      };
+      change = Some(*last);
+      true
+    });
+    change
+  }
+
+  pub fn subscribe(&self) -> watch::Receiver<RateChange> {
+    self.sender.subscribe()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::time::Duration;
+
+  #[test]
+  fn broadcast_changes() {
+    let watch = FrameRateWatch::new(FrameRate::_50_00);
+    let mut receiver = watch.subscribe();
+    assert!(!receiver.has_changed().unwrap());
+
+    let change = RateChange::new(FrameRate::_50_00, FrameRate::_25_00, 1500);
+    assert_eq!(watch.change(FrameRate::_25_00, 1500), Some(change));
+    assert!(receiver.has_changed().unwrap());
+    assert_eq!(*receiver.borrow_and_update(), change);
+    assert_eq!(watch.current(), FrameRate::_25_00);
+
+    // 30 s at 50 fps, then 10 s at 25 fps.
+    let change = watch.change(FrameRate::_50_00, 1750).unwrap();
+    assert_eq!(change.effective_time, Duration::from_secs(40));
+  }
+
+  #[test]
+  fn zero_frame_rate() {
+    let watch = FrameRateWatch::new(FrameRate::new(0, 1));
+    let change = watch.change(FrameRate::_25_00, 10).unwrap();
+    assert_eq!(change.effective_time, Duration::MAX);
+    let change = watch.change(FrameRate::new(0, 1), 20).unwrap();
+    assert_eq!(change.effective_time, Duration::MAX);
+
+    let watch = FrameRateWatch::new(FrameRate::_25_00);
+    watch.change(FrameRate::new(0, 1), 25);
+    let change = watch.change(FrameRate::_25_00, 25).unwrap();
+    assert_eq!(change.effective_time, Duration::from_secs(1));
+  }
+
+  #[test]
+  fn same_rate_is_not_broadcast() {
+    let watch = FrameRateWatch::new(FrameRate::_29_97);
+    let mut receiver = watch.subscribe();
+
+    assert_eq!(watch.change(FrameRate::_29_97, 10), None);
+    assert!(!receiver.has_changed().unwrap());
+    assert_eq!(receiver.borrow_and_update().effective_frame, 0);
+  }
+}
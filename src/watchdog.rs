@@ -0,0 +1,160 @@
+use crate::{FrameRate, GracePeriod};
+use std::time::{Duration, Instant};
+
+/// Trips when no frame arrived for a number of frame periods, for ingest
+/// health checks.
+///
+/// ```
+/// use frame_rate::{FrameRate, FrameWatchdog};
+/// use std::time::{Duration, Instant};
+///
+/// let start = Instant::now();
+/// let mut watchdog = FrameWatchdog::new(FrameRate::_25_00, 5, start);
+/// watchdog.feed(start + Duration::from_millis(40));
+/// assert!(!watchdog.is_tripped(start + Duration::from_millis(200)));
+/// assert!(watchdog.is_tripped(start + Duration::from_millis(240)));
+/// ```
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct FrameWatchdog {
+  grace_period: GracePeriod,
+  last_frame: Instant,
+}
+
+impl FrameWatchdog {
+  /// Watchdog tripping after `missed_frames` frame periods at `frame_rate`
+  /// without a frame, counting from `start`.
+  pub fn new(frame_rate: FrameRate, missed_frames: u64, start: Instant) -> Self {
+    Self {
+      grace_period: GracePeriod::new(frame_rate, missed_frames),
+      last_frame: start,
+    }
+  }
+
+  pub fn grace_period(&self) -> GracePeriod {
+    self.grace_period
+  }
+
+  /// Records a frame arrival.
+  pub fn feed(&mut self, now: Instant) {
+    self.last_frame = self.last_frame.max(now);
+  }
+
+  pub fn last_frame(&self) -> Instant {
+    self.last_frame
+  }
+
+  /// Whole frame periods elapsed since the last frame.
+  pub fn missed_frames(&self, now: Instant) -> u64 {
+    self
+      .grace_period
+      .missed_frames(now.saturating_duration_since(self.last_frame))
+  }
+
+  pub fn is_tripped(&self, now: Instant) -> bool {
+    now >= self.deadline()
+  }
+
+  /// Instant the watchdog trips at unless fed before.
+  pub fn deadline(&self) -> Instant {
+    self.last_frame + self.grace_period.timeout()
+  }
+
+  /// Time left before tripping, zero once tripped.
+  pub fn remaining(&self, now: Instant) -> Duration {
+    self.deadline().saturating_duration_since(now)
+  }
+}
+
+/// [`FrameWatchdog`] fed from any task, with a future resolving when it
+/// trips.
+#[cfg(feature = "tokio")]
+#[derive(Debug)]
+pub struct AsyncFrameWatchdog {
+  grace_period: GracePeriod,
+  last_frame: tokio::sync::watch::Sender<tokio::time::Instant>,
+}
+
+#[cfg(feature = "tokio")]
+impl AsyncFrameWatchdog {
+  /// Watchdog tripping after `missed_frames` frame periods at `frame_rate`
+  /// without a frame, counting from now.
+  pub fn new(frame_rate: FrameRate, missed_frames: u64) -> Self {
+    let (last_frame, _) = tokio::sync::watch::channel(tokio::time::Instant::now());
+    Self {
+      grace_period: GracePeriod::new(frame_rate, missed_frames),
+      last_frame,
+    }
+  }
+
+  pub fn grace_period(&self) -> GracePeriod {
+    self.grace_period
+  }
+
+  /// Records a frame arrival now.
+  pub fn feed(&self) {
+    self.last_frame.send_replace(tokio::time::Instant::now());
+  }
+
+  pub fn is_tripped(&self) -> bool {
+    tokio::time::Instant::now() >= self.deadline()
+  }
+
+  /// Resolves once `grace_period` elapsed without any frame.
+  pub async fn tripped(&self) {
+    loop {
+      let deadline = self.deadline();
+      tokio::time::sleep_until(deadline).await;
+      if self.deadline() <= deadline {
+        return;
+      }
+    }
+  }
+
+  fn deadline(&self) -> tokio::time::Instant {
+    *self.last_frame.borrow() + self.grace_period.timeout()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn trips_after_missed_frames() {
+    let start = Instant::now();
+    let mut watchdog = FrameWatchdog::new(FrameRate::_29_97, 3, start);
+    // 3 × 1001/30000 s = 100.1 ms
+    assert_eq!(watchdog.deadline() - start, Duration::from_micros(100_100));
+    assert_eq!(watchdog.missed_frames(start + Duration::from_millis(70)), 2);
+    assert!(!watchdog.is_tripped(start + Duration::from_millis(100)));
+    assert!(watchdog.is_tripped(start + Duration::from_micros(100_100)));
+
+    watchdog.feed(start + Duration::from_millis(90));
+    assert!(!watchdog.is_tripped(start + Duration::from_millis(150)));
+    assert_eq!(
+      watchdog.remaining(start + Duration::from_millis(150)),
+      Duration::from_micros(40_100)
+    );
+    assert_eq!(watchdog.missed_frames(start), 0);
+
+    // Late delivered events never move the last frame backwards.
+    watchdog.feed(start);
+    assert_eq!(watchdog.last_frame(), start + Duration::from_millis(90));
+  }
+
+  #[cfg(feature = "tokio")]
+  #[tokio::test(start_paused = true)]
+  async fn async_watchdog() {
+    let watchdog = AsyncFrameWatchdog::new(FrameRate::_25_00, 5);
+    let start = tokio::time::Instant::now();
+    let tripped = watchdog.tripped();
+    tokio::pin!(tripped);
+
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    watchdog.feed();
+    assert!(!watchdog.is_tripped());
+    tripped.as_mut().await;
+    assert_eq!(start.elapsed(), Duration::from_millis(350));
+    assert!(watchdog.is_tripped());
+  }
+}